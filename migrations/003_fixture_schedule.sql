@@ -0,0 +1,12 @@
+-- last polled schedule of every fixture, diffed to detect reschedules
+create table fixture_schedule (
+  fixture_id   integer primary key,
+  team_h       integer not null,
+  team_a       integer not null,
+  event        integer,          -- null while postponed / unscheduled
+  kickoff_time text
+);
+
+create table schedule_alert_channels (
+  channel_id integer primary key
+);
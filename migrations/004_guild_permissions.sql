@@ -0,0 +1,28 @@
+-- roles whose members count as bot admins in a guild, on top of discord's
+-- administrator / manage server permissions
+create table guild_admin_roles (
+  guild_id integer not null,
+  role_id  integer not null,
+  primary key (guild_id, role_id)
+);
+
+-- per-guild overrides of who may run a command
+create table command_permissions (
+  guild_id integer not null,
+  command  text not null,
+  access   text not null,          -- everyone | managers | admins
+  primary key (guild_id, command)
+);
+
+-- who changed which setting, and when
+create table audit_log (
+  id         integer primary key autoincrement,
+  guild_id   integer,              -- null for changes made in DMs
+  channel_id integer not null,
+  user_id    integer not null,
+  command    text not null,
+  change     text not null,
+  created_at text not null
+);
+
+create index audit_log_guild on audit_log (guild_id, id);
@@ -0,0 +1,21 @@
+-- named leagues per channel, one of which is the channel's primary league
+create table channel_leagues (
+  channel_id integer not null,
+  name       text not null,
+  league_id  integer not null,
+  is_primary integer not null default 0,
+  primary key (channel_id, name)
+);
+
+-- league used by channels in a guild that have no leagues of their own
+create table guild_settings (
+  guild_id          integer primary key,
+  default_league_id integer
+);
+
+insert into channel_leagues (channel_id, name, league_id, is_primary)
+select channel_id, 'main', default_league_id, 1
+from channels
+where default_league_id is not null;
+
+drop table channels;
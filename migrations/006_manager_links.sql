@@ -0,0 +1,11 @@
+-- cached from the FPL entry when a manager id is linked, for display without
+-- an api call
+alter table users add column team_name text;
+alter table users add column manager_name text;
+
+-- guilds a user's link was made or confirmed in, for per-guild listings
+create table guild_links (
+  guild_id   integer not null,
+  discord_id integer not null,
+  primary key (guild_id, discord_id)
+);
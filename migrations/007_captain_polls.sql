@@ -0,0 +1,28 @@
+-- one captain poll per channel and gameweek, scored once the gameweek is
+-- finished
+create table captain_polls (
+  id         integer primary key autoincrement,
+  channel_id integer not null,
+  season     text not null,             -- e.g. 2026/27
+  gameweek   integer not null,
+  deadline   text not null,             -- votes close at the gameweek deadline
+  created_by integer not null,
+  scored     integer not null default 0,
+  unique (channel_id, season, gameweek)
+);
+
+-- the players a poll offers, with their live points once scored
+create table captain_poll_options (
+  poll_id integer not null references captain_polls (id) on delete cascade,
+  element integer not null,
+  points  integer,
+  primary key (poll_id, element)
+);
+
+-- each member's current vote; voting again replaces it
+create table captain_votes (
+  poll_id integer not null references captain_polls (id) on delete cascade,
+  user_id integer not null,
+  element integer not null,
+  primary key (poll_id, user_id)
+);
@@ -0,0 +1,15 @@
+-- fixture score predictions per guild, scored once the fixture is finished
+create table predictions (
+  id           integer primary key autoincrement,
+  guild_id     integer not null,
+  season       text not null,             -- e.g. 2026/27
+  fixture_id   integer not null,
+  user_id      integer not null,
+  home_goals   integer not null,
+  away_goals   integer not null,
+  predicted_at text not null,
+  points       integer,                   -- null until the fixture is scored
+  unique (guild_id, season, fixture_id, user_id)
+);
+
+create index predictions_unscored on predictions (points) where points is null;
@@ -0,0 +1,301 @@
+//! Build squad command implementation
+//!
+//! Provides the `/build_squad` slash command, which builds the squad with the
+//! most projected points for a wildcard or free hit from the whole player
+//! pool, shows its best XI in formation and attaches the squad as a CSV list.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use log::info;
+use serenity::all::{CommandInteraction, Context, CreateAttachment, ResolvedValue};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter};
+use serenity::model::application::CommandOptionType;
+
+use crate::bot::commands::standings::format_money;
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::fpl::models::fixtures::fetch_all_fixtures;
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::teams::get_team_name;
+use crate::fpl::projection::project;
+use crate::fpl::squad::Position;
+use crate::fpl::squad_builder::{build_squad, BuiltSquad};
+use crate::fpl::transfers::TransferPlayer;
+
+/// Gameweeks a wildcard squad is built for.
+const WILDCARD_HORIZON: i32 = 5;
+
+/// Lowest and highest budgets accepted, in millions.
+const BUDGET_RANGE: (f64, f64) = (80.0, 120.0);
+
+/// Registers the build squad command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("build_squad")
+        .description("Build the best projected squad for a wildcard or free hit")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Number,
+                "budget",
+                "Most the squad may cost, in millions (e.g. 100.0)",
+            )
+            .min_number_value(BUDGET_RANGE.0)
+            .max_number_value(BUDGET_RANGE.1)
+            .required(true),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "chip",
+                "Wildcard builds for the next 5 gameweeks, free hit for the next one",
+            )
+            .add_string_choice("Wildcard", "wildcard")
+            .add_string_choice("Free hit", "freehit")
+            .required(false),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "locks",
+                "Players who must be in the squad, by name or ID, separated by commas",
+            )
+            .required(false),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "excludes",
+                "Players to leave out, by name or ID, separated by commas",
+            )
+            .required(false),
+        )
+}
+
+/// Main handler for the `/build_squad` slash command
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing user input
+/// * `progress` - Progress handle updated while the squad is searched for
+///
+/// # Returns
+/// * `Result<Reply>` - The squad in formation with a CSV export attached
+///
+/// # Errors
+/// Returns error if:
+/// - A locked or excluded player cannot be found, or matches several players
+/// - No legal squad fits the budget around the locked players
+/// - There are no gameweeks left or FPL API requests fail
+///
+/// # Example Usage
+/// `/build_squad budget:100.0`
+/// `/build_squad budget:101.5 chip:freehit locks:Salah, Haaland excludes:Palmer`
+pub async fn run(
+    _ctx: &Context,
+    command: &CommandInteraction,
+    progress: &Progress,
+) -> Result<Reply> {
+    let mut budget = 100.0;
+    let mut free_hit = false;
+    let mut locks = "";
+    let mut excludes = "";
+    for option in command.data.options() {
+        match (option.name, option.value) {
+            ("budget", ResolvedValue::Number(value)) => budget = value,
+            ("chip", ResolvedValue::String(chip)) => free_hit = chip == "freehit",
+            ("locks", ResolvedValue::String(names)) => locks = names,
+            ("excludes", ResolvedValue::String(names)) => excludes = names,
+            _ => {}
+        }
+    }
+    let budget = (budget.clamp(BUDGET_RANGE.0, BUDGET_RANGE.1) * 10.0).round() as i32;
+
+    let general = GeneralData::fetch().await?;
+    let locks = resolve_players(&general, locks)?;
+    let excludes = resolve_players(&general, excludes)?;
+    let first = general
+        .next_event()
+        .ok_or_else(|| anyhow!("There are no gameweeks left to build a squad for"))?
+        .id;
+    let horizon = if free_hit { 1 } else { WILDCARD_HORIZON };
+    let last =
+        (first + horizon - 1).min(general.events.iter().map(|e| e.id).max().unwrap_or(first));
+    info!(
+        "Building {} squad for GW{}-{} with budget {} (user {})",
+        if free_hit { "free hit" } else { "wildcard" },
+        first,
+        last,
+        budget,
+        command.user.name
+    );
+
+    let fixtures = fetch_all_fixtures().await?;
+    let pool: Vec<TransferPlayer> = project(&general, &fixtures, first..=last)
+        .into_iter()
+        .filter_map(|projection| {
+            let player = general.player(projection.element)?;
+            Some(TransferPlayer {
+                element: player.id,
+                position: Position::from_element_type(player.element_type)?,
+                team: player.team,
+                price: player.now_cost,
+                points: projection.gameweeks.iter().map(|(_, p)| *p).collect(),
+            })
+        })
+        .collect();
+
+    progress.update("Searching for the best squad…").await;
+    let squad = tokio::task::spawn_blocking(move || build_squad(&pool, budget, &locks, &excludes))
+        .await??;
+
+    let range = if first == last {
+        format!("GW{}", first)
+    } else {
+        format!("GW{}-{}", first, last)
+    };
+    let title = if free_hit {
+        format!("🃏 Free hit squad for {}", range)
+    } else {
+        format!("🃏 Wildcard squad for {}", range)
+    };
+    let embed = build_embed(&squad, &general, title, budget, first);
+    let export = CreateAttachment::bytes(export_csv(&squad, &general), "squad.csv");
+    Ok(Reply::new().embed(embed).add_file(export))
+}
+
+/// Resolves a comma separated list of player names or IDs
+///
+/// # Errors
+/// Returns error if a name matches no player or several players
+fn resolve_players(general: &GeneralData, names: &str) -> Result<Vec<i32>> {
+    names
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| match general.find_players(name).as_slice() {
+            [player] => Ok(player.id),
+            [] => Err(anyhow!("No player called {}", name)),
+            players => Err(anyhow!(
+                "{} matches {} players, use one of their IDs: {}",
+                name,
+                players.len(),
+                players
+                    .iter()
+                    .map(|p| format!("{} ({})", p.id, get_team_name(p.team).short_name))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        })
+        .collect()
+}
+
+/// Builds the embed: the first gameweek's XI row by row, then the bench
+fn build_embed(
+    squad: &BuiltSquad,
+    general: &GeneralData,
+    title: String,
+    budget: i32,
+    first: i32,
+) -> CreateEmbed {
+    let points: HashMap<i32, f64> = squad
+        .players
+        .iter()
+        .map(|p| (p.element, p.total()))
+        .collect();
+    let name = |element: i32| {
+        let captain = if element == squad.captain { " (C)" } else { "" };
+        format!(
+            "{}{} {:.1}",
+            general.player_name(element),
+            captain,
+            points.get(&element).copied().unwrap_or(0.0)
+        )
+    };
+
+    let mut rows: Vec<String> = Position::ALL
+        .into_iter()
+        .map(|position| {
+            let players: Vec<String> = squad
+                .lineup
+                .starters_in(position)
+                .map(|c| name(c.id))
+                .collect();
+            format!("**{}** {}", position, players.join(" • "))
+        })
+        .collect();
+    let bench: Vec<String> = squad.lineup.bench.iter().map(|c| name(c.id)).collect();
+    rows.push(String::new());
+    rows.push(format!("**Bench** {}", bench.join(" • ")));
+
+    CreateEmbed::new()
+        .title(title)
+        .description(rows.join("\n"))
+        .field("Formation", squad.lineup.formation(), true)
+        .field(
+            "Cost",
+            format!(
+                "{} of {}",
+                format_money(squad.cost),
+                format_money(budget)
+            ),
+            true,
+        )
+        .field("Projected", format!("{:.1} pts", squad.points), true)
+        .color(0x00ff87) // green
+        .footer(CreateEmbedFooter::new(format!(
+            "XI and captain for GW{} • Points are projected over the whole horizon • squad.csv lists the squad",
+            first
+        )))
+}
+
+/// The squad as CSV: position, ID, name, team, price and projected points
+fn export_csv(squad: &BuiltSquad, general: &GeneralData) -> Vec<u8> {
+    let mut csv = String::from("position,id,name,team,price,projected_points,role\n");
+    for player in &squad.players {
+        let role = if player.element == squad.captain {
+            "captain"
+        } else if squad.lineup.starters.iter().any(|c| c.id == player.element) {
+            "starter"
+        } else {
+            "bench"
+        };
+        csv.push_str(&format!(
+            "{},{},{},{},{:.1},{:.1},{}\n",
+            player.position,
+            player.element,
+            general.player_name(player.element).replace(',', " "),
+            get_team_name(player.team).short_name,
+            f64::from(player.price) / 10.0,
+            player.total(),
+            role
+        ));
+    }
+    csv.into_bytes()
+}
+
+/// The `/build_squad` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct BuildSquadCommand;
+
+#[async_trait]
+impl SlashCommand for BuildSquadCommand {
+    fn name(&self) -> &'static str {
+        "build_squad"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command, progress).await
+    }
+}
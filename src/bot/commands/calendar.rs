@@ -0,0 +1,118 @@
+//! Season calendar command implementation
+//!
+//! Provides the `/calendar` slash command, listing every blank and double
+//! gameweek of the season along with fixtures still waiting to be rescheduled.
+
+use anyhow::Result;
+use log::info;
+use serenity::all::{CommandInteraction, Context};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateEmbed};
+
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::fpl::calendar::SeasonCalendar;
+use crate::fpl::models::teams::get_team_name;
+
+/// Registers the calendar command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("calendar").description("Show blank and double gameweeks for the season")
+}
+
+/// Main handler for the `/calendar` slash command
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction
+///
+/// # Returns
+/// * `Result<Reply>` - Discord response with the calendar embed
+///
+/// # Errors
+/// Returns error if the FPL API request fails or fixture data cannot be parsed
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    info!("Processing calendar command for user {}", command.user.name);
+
+    let calendar = SeasonCalendar::fetch().await?;
+    let embed = build_calendar_embed(&calendar);
+
+    Ok(Reply::new().embed(embed))
+}
+
+fn short_names(teams: &[i32]) -> String {
+    teams
+        .iter()
+        .map(|id| get_team_name(*id).short_name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Builds the calendar embed
+///
+/// One line per irregular gameweek, followed by the list of fixtures that
+/// currently have no gameweek.
+fn build_calendar_embed(calendar: &SeasonCalendar) -> CreateEmbed {
+    let mut description = String::new();
+
+    for summary in calendar.irregular_gameweeks() {
+        description.push_str(&format!("**GW{}**", summary.gameweek));
+        if !summary.doubles.is_empty() {
+            description.push_str(&format!(" · double: {}", short_names(&summary.doubles)));
+        }
+        if !summary.blanks.is_empty() {
+            description.push_str(&format!(" · blank: {}", short_names(&summary.blanks)));
+        }
+        description.push('\n');
+    }
+
+    if description.is_empty() {
+        description.push_str("No blank or double gameweeks scheduled.");
+    }
+
+    let unscheduled: Vec<String> = calendar
+        .unscheduled()
+        .map(|f| {
+            format!(
+                "{} v {}",
+                get_team_name(f.team_h).short_name,
+                get_team_name(f.team_a).short_name
+            )
+        })
+        .collect();
+
+    let mut embed = CreateEmbed::new()
+        .title("Season calendar")
+        .description(description)
+        .color(0x37003c);
+
+    if !unscheduled.is_empty() {
+        embed = embed.field("Awaiting a new date", unscheduled.join("\n"), false);
+    }
+    embed
+}
+
+/// The `/calendar` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct CalendarCommand;
+
+#[async_trait]
+impl SlashCommand for CalendarCommand {
+    fn name(&self) -> &'static str {
+        "calendar"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+}
@@ -0,0 +1,379 @@
+//! Captain poll command implementation
+//!
+//! Provides the `/captain_poll` slash command. `start` posts the most owned
+//! players with the kindest fixtures for the next gameweek as buttons, and
+//! records each member's vote until the deadline. Once the gameweek is
+//! finished the [`captain_scoring`](crate::bot::tasks::captain_scoring) task
+//! scores the options, and `leaderboard` ranks members by how often they
+//! picked the best captain this season.
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use log::info;
+use serenity::all::{
+    ActionRowComponent, Button, ButtonKind, ButtonStyle, CommandInteraction, CommandOptionType,
+    ComponentInteraction, Context, CreateButton, CreateInteractionResponse,
+    CreateInteractionResponseMessage, ResolvedOption, ResolvedValue,
+};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter};
+
+use crate::bot::custom_id::ComponentId;
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::database::models::{DBCaptainPoll, DBCaptainVote};
+use crate::database::service::db_service;
+use crate::fpl::captaincy::{
+    gameweek_candidates, leaderboard, top_candidates, CaptainCandidate, PollResult, POLL_OPTIONS,
+};
+use crate::fpl::models::fixtures::{fetch_fixtures, Fixture};
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::teams::get_team_name;
+
+/// Version of the vote buttons, see [`ComponentId`].
+const COMPONENT_VERSION: u32 = 1;
+
+/// Voters listed on the leaderboard.
+const LEADERBOARD_SIZE: usize = 15;
+
+/// Registers the captain poll command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("captain_poll")
+        .description("Vote on the captain for the next gameweek")
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "start",
+            "Start a captain poll for the next gameweek in this channel",
+        ))
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "leaderboard",
+            "Who picked the best captain most often this season",
+        ))
+}
+
+/// Main handler for the `/captain_poll` slash command
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing the subcommand
+///
+/// # Returns
+/// * `Result<Reply>` - The poll with its vote buttons, or the leaderboard
+///
+/// # Errors
+/// Returns error if:
+/// - There is no upcoming gameweek
+/// - The channel already has a poll for the gameweek
+/// - FPL API requests or database operations fail
+///
+/// # Example Usage
+/// `/captain_poll start`
+/// `/captain_poll leaderboard`
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    let options = command.data.options();
+    let Some(ResolvedOption {
+        name,
+        value: ResolvedValue::SubCommand(_),
+        ..
+    }) = options.first()
+    else {
+        return Err(anyhow!("Please choose a subcommand"));
+    };
+
+    let general = GeneralData::fetch().await?;
+    match *name {
+        "start" => start_poll(command, &general).await,
+        "leaderboard" => show_leaderboard(command, &general).await,
+        other => Err(anyhow!("Unknown subcommand {}", other)),
+    }
+}
+
+/// Creates a poll for the next gameweek and builds its message
+async fn start_poll(command: &CommandInteraction, general: &GeneralData) -> Result<Reply> {
+    let event = general
+        .next_event()
+        .ok_or_else(|| anyhow!("There is no upcoming gameweek to vote on"))?;
+    let season = general.season();
+    let db = db_service();
+    if db
+        .find_captain_poll(command.channel_id, &season, event.id)
+        .await?
+        .is_some()
+    {
+        return Err(anyhow!(
+            "This channel already has a captain poll for GW{}",
+            event.id
+        ));
+    }
+
+    let fixtures = fetch_fixtures(event.id).await?.fixtures;
+    let candidates = top_candidates(gameweek_candidates(general, &fixtures), POLL_OPTIONS);
+    if candidates.is_empty() {
+        return Err(anyhow!("No players have a fixture in GW{}", event.id));
+    }
+
+    let elements: Vec<i32> = candidates.iter().map(|c| c.element).collect();
+    let poll_id = db
+        .create_captain_poll(
+            &DBCaptainPoll {
+                id: 0,
+                channel_id: command.channel_id.into(),
+                season,
+                gameweek: event.id,
+                deadline: event.deadline_time,
+                created_by: command.user.id.into(),
+                scored: false,
+            },
+            &elements,
+        )
+        .await?;
+    info!(
+        "Started captain poll {} for GW{} in channel {} (user {})",
+        poll_id, event.id, command.channel_id, command.user.name
+    );
+
+    let lines: Vec<String> = candidates
+        .iter()
+        .map(|candidate| candidate_line(candidate, general, &fixtures))
+        .collect();
+    let embed = CreateEmbed::new()
+        .title(format!("🎖️ GW{} captain poll", event.id))
+        .description(format!(
+            "{}\n\nVoting closes at the deadline, <t:{}:R>. Vote again to change your pick.",
+            lines.join("\n"),
+            event.deadline_time.timestamp()
+        ))
+        .color(0x37003c) // purple
+        .footer(CreateEmbedFooter::new(
+            "Picked by ownership and fixture difficulty • Scored once the gameweek is finished",
+        ));
+
+    let mut reply = Reply::new().embed(embed);
+    for candidate in &candidates {
+        let custom_id = ComponentId::new("captain_poll", COMPONENT_VERSION, "vote")
+            .record(poll_id)
+            .player(candidate.element)
+            .encode()?;
+        reply = reply.button(
+            CreateButton::new(custom_id)
+                .label(general.player_name(candidate.element))
+                .style(ButtonStyle::Primary),
+        );
+    }
+    Ok(reply)
+}
+
+/// Formats a candidate, e.g. "**Haaland** MCI: BUR (H) 2 • 52.1% owned"
+fn candidate_line(
+    candidate: &CaptainCandidate,
+    general: &GeneralData,
+    fixtures: &[Fixture],
+) -> String {
+    let team = general.player(candidate.element).map_or(0, |p| p.team);
+    let opponents: Vec<String> = fixtures
+        .iter()
+        .filter_map(|fixture| {
+            if fixture.team_h == team {
+                Some((fixture.team_a, "H", fixture.team_h_difficulty))
+            } else if fixture.team_a == team {
+                Some((fixture.team_h, "A", fixture.team_a_difficulty))
+            } else {
+                None
+            }
+        })
+        .map(|(opponent, venue, difficulty)| {
+            format!(
+                "{} ({}) {}",
+                get_team_name(opponent).short_name,
+                venue,
+                difficulty
+            )
+        })
+        .collect();
+    format!(
+        "**{}** {}: {} • {:.1}% owned",
+        general.player_name(candidate.element),
+        get_team_name(team).short_name,
+        opponents.join(" + "),
+        candidate.ownership
+    )
+}
+
+/// Builds the season leaderboard of the channel's scored polls
+async fn show_leaderboard(command: &CommandInteraction, general: &GeneralData) -> Result<Reply> {
+    let season = general.season();
+    let db = db_service();
+    let polls = db
+        .get_scored_captain_polls(command.channel_id, &season)
+        .await?;
+
+    let mut results = Vec::new();
+    for poll in &polls {
+        results.push(poll_result(poll.id).await?);
+    }
+    let entries = leaderboard(&results);
+
+    let description = if entries.is_empty() {
+        "No captain polls have been scored in this channel this season.".to_string()
+    } else {
+        entries
+            .iter()
+            .take(LEADERBOARD_SIZE)
+            .enumerate()
+            .map(|(i, entry)| {
+                format!(
+                    "{}. <@{}> {} best of {} • {} pts",
+                    i + 1,
+                    entry.user_id,
+                    entry.best_picks,
+                    entry.polls,
+                    entry.points
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    Ok(Reply::new().embed(
+        CreateEmbed::new()
+            .title(format!("🎖️ Captain poll leaderboard {}", season))
+            .description(description)
+            .color(0x37003c) // purple
+            .footer(CreateEmbedFooter::new(format!(
+                "{} scored polls • Points are the picked player's, before doubling",
+                polls.len()
+            ))),
+    ))
+}
+
+/// Loads a poll's options and votes for scoring
+///
+/// Options that have not been scored count as 0 points.
+pub async fn poll_result(poll_id: i64) -> Result<PollResult> {
+    let db = db_service();
+    let options = db.get_captain_options(poll_id).await?;
+    let votes = db.get_captain_votes(poll_id).await?;
+    Ok(PollResult {
+        options: options
+            .iter()
+            .map(|option| (option.element, option.points.unwrap_or(0)))
+            .collect(),
+        votes: votes
+            .iter()
+            .map(|vote| (vote.user_id as u64, vote.element))
+            .collect(),
+    })
+}
+
+/// Handles the vote buttons of a captain poll
+///
+/// # Custom ID Format
+/// A [`ComponentId`] for `captain_poll` with action `vote`, carrying the poll
+/// as its record ID and the voted player as its player ID.
+pub async fn handle_component(ctx: &Context, component: &ComponentInteraction) {
+    let content = match record_vote(component).await {
+        Ok(content) => content,
+        Err(e) => format!("Error: {}", e),
+    };
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    );
+    if let Err(why) = component.create_response(&ctx.http, response).await {
+        info!("Cannot respond to captain vote: {}", why);
+    }
+}
+
+async fn record_vote(component: &ComponentInteraction) -> Result<String> {
+    let id = ComponentId::parse(&component.data.custom_id, "captain_poll", COMPONENT_VERSION)?;
+    let element = id
+        .state
+        .player_id
+        .ok_or_else(|| anyhow!("Could not determine which player was voted for"))?;
+    let db = db_service();
+    let poll = db.get_captain_poll(id.record_id()?).await?;
+
+    if Utc::now() >= poll.deadline {
+        return Err(anyhow!(
+            "Voting for GW{} closed at the deadline",
+            poll.gameweek
+        ));
+    }
+    let options = db.get_captain_options(poll.id).await?;
+    if !options.iter().any(|option| option.element == element) {
+        return Err(anyhow!("That player is not an option in this poll"));
+    }
+
+    db.set_captain_vote(&DBCaptainVote {
+        poll_id: poll.id,
+        user_id: component.user.id.into(),
+        element,
+    })
+    .await?;
+    let votes = db.get_captain_votes(poll.id).await?.len();
+    info!(
+        "User {} voted for element {} in captain poll {}",
+        component.user.name, element, poll.id
+    );
+
+    Ok(format!(
+        "You picked **{}** as your GW{} captain. {} vote{} so far.",
+        button_label(component).unwrap_or("that player"),
+        poll.gameweek,
+        votes,
+        if votes == 1 { "" } else { "s" }
+    ))
+}
+
+/// The label of the button that was pressed, i.e. the player's name
+fn button_label(component: &ComponentInteraction) -> Option<&str> {
+    component
+        .message
+        .components
+        .iter()
+        .flat_map(|row| &row.components)
+        .find_map(|c| match c {
+            ActionRowComponent::Button(Button {
+                data: ButtonKind::NonLink { custom_id, .. },
+                label,
+                ..
+            }) if *custom_id == component.data.custom_id => label.as_deref(),
+            _ => None,
+        })
+}
+
+/// The `/captain_poll` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct CaptainPollCommand;
+
+#[async_trait]
+impl SlashCommand for CaptainPollCommand {
+    fn name(&self) -> &'static str {
+        "captain_poll"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+
+    fn component_prefixes(&self) -> &'static [&'static str] {
+        &["captain_poll:"]
+    }
+
+    async fn handle_component(&self, ctx: &Context, component: &ComponentInteraction) {
+        handle_component(ctx, component).await
+    }
+}
@@ -1,32 +1,39 @@
 use anyhow::Result;
-use serenity::all::{
-    CommandInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
-};
+use serenity::all::{CommandInteraction, Context};
+use serenity::async_trait;
 use serenity::builder::CreateCommand;
 
-use crate::database::models::DBChannel;
-use crate::database::service::db_service;
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
 
 pub fn register() -> CreateCommand {
     CreateCommand::new("check_channel_league_id")
         .description("Check your channels default league id")
 }
 
-pub async fn run(
-    _ctx: &Context,
-    command: &CommandInteraction,
-) -> Result<CreateInteractionResponse> {
-    let channel_id = command.channel_id;
-    let user: DBChannel = db_service().get_channel(channel_id).await?;
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    Ok(Reply::new().content(super::leagues::describe_channel(command).await?))
+}
+
+/// The `/check_channel_league_id` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct CheckChannelLeagueIdCommand;
+
+#[async_trait]
+impl SlashCommand for CheckChannelLeagueIdCommand {
+    fn name(&self) -> &'static str {
+        "check_channel_league_id"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
 
-    match user.default_league_id {
-        Some(id) => Ok(CreateInteractionResponse::Message(
-            CreateInteractionResponseMessage::new()
-                .content(format!("Your current default league id is: {}", id)),
-        )),
-        Option::None => Ok(CreateInteractionResponse::Message(
-            CreateInteractionResponseMessage::new()
-                .content("You do not currently have a set manager id"),
-        )),
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
     }
 }
@@ -1,9 +1,10 @@
 use anyhow::Result;
-use serenity::all::{
-    CommandInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
-};
+use serenity::all::{CommandInteraction, Context};
+use serenity::async_trait;
 use serenity::builder::CreateCommand;
 
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
 use crate::database::models::DBUser;
 use crate::database::service::db_service;
 
@@ -11,21 +12,38 @@ pub fn register() -> CreateCommand {
     CreateCommand::new("check_manager_id").description("Check your own manager id")
 }
 
-pub async fn run(
-    _ctx: &Context,
-    command: &CommandInteraction,
-) -> Result<CreateInteractionResponse> {
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
     let user_id = command.user.id;
     let user: DBUser = db_service().get_user(user_id).await?;
 
-    match user.manager_id {
-        Some(id) => Ok(CreateInteractionResponse::Message(
-            CreateInteractionResponseMessage::new()
-                .content(format!("Your current manager id is: {}", id)),
-        )),
-        Option::None => Ok(CreateInteractionResponse::Message(
-            CreateInteractionResponseMessage::new()
-                .content("You do not currently have a set manager id"),
-        )),
+    match (user.manager_id, user.team_name) {
+        (Some(id), Some(team)) => {
+            Ok(Reply::new().content(format!("Your current manager id is: {} ({})", id, team)))
+        }
+        (Some(id), None) => Ok(Reply::new().content(format!("Your current manager id is: {}", id))),
+        (None, _) => Ok(Reply::new().content("You do not currently have a set manager id")),
+    }
+}
+
+/// The `/check_manager_id` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct CheckManagerIdCommand;
+
+#[async_trait]
+impl SlashCommand for CheckManagerIdCommand {
+    fn name(&self) -> &'static str {
+        "check_manager_id"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
     }
 }
@@ -5,13 +5,22 @@ use serenity::all::{
     ChannelId, CommandInteraction, Context, CreateInteractionResponse,
     CreateInteractionResponseMessage, ResolvedOption, ResolvedValue,
 };
+use serenity::async_trait;
 use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed};
 use serenity::model::application::CommandOptionType;
+use std::collections::HashMap;
 
-use crate::database::models::DBChannel;
+use crate::bot::commands::standings::format_rank;
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
 use crate::database::{models::DBUser, service::db_service};
 use crate::fpl::fpl_client;
+use crate::fpl::live_rank::{live_points, RankEstimator, StartingPosition};
+use crate::fpl::models::gameweek::update_gameweek_info;
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::history::ManagerHistory;
 use crate::fpl::models::manager;
+use crate::fpl::models::picks::ManagerPicks;
 use crate::utils::type_conversion::r_option_to_i32;
 
 pub fn register() -> CreateCommand {
@@ -27,10 +36,7 @@ pub fn register() -> CreateCommand {
         )
 }
 
-pub async fn run(
-    _ctx: &Context,
-    command: &CommandInteraction,
-) -> Result<CreateInteractionResponse> {
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
     let db = db_service();
 
     // Parse options first to see which one was provided
@@ -57,12 +63,10 @@ pub async fn run(
             match db_user.manager_id {
                 Some(id) => id,
                 None => {
-                    return Ok(CreateInteractionResponse::Message(
-                        CreateInteractionResponseMessage::new().content(format!(
-                            "User {} has not linked their FPL manager ID.",
-                            user.name
-                        )),
-                    ));
+                    return Ok(Reply::new().content(format!(
+                        "User {} has not linked their FPL manager ID.",
+                        user.name
+                    )));
                 }
             }
         }
@@ -71,10 +75,8 @@ pub async fn run(
             match user.manager_id {
                 Some(id) => id,
                 None => {
-                    return Ok(CreateInteractionResponse::Message(
-                        CreateInteractionResponseMessage::new().content(
-                            "No manager_id configured for you. Use /update_manager_id please!",
-                        ),
+                    return Ok(Reply::new().content(
+                        "No manager_id configured for you. Use /update_manager_id please!",
                     ));
                 }
             }
@@ -102,6 +104,16 @@ pub async fn run(
         .to_string();
 
     let general_data = fpl_client().get_general().await?;
+    let live_rank = match live_rank(&team_picks, &general_data, manager_id, current_gw).await {
+        Ok(rank) => rank,
+        Err(e) => {
+            error!(
+                "Failed to estimate live rank of manager {}: {}",
+                manager_id, e
+            );
+            None
+        }
+    };
     let embed = build_team_embed(
         &team_picks,
         &manager_name,
@@ -109,11 +121,62 @@ pub async fn run(
         &general_data,
         current_gw,
         manager_id,
+        live_rank,
     );
 
-    Ok(CreateInteractionResponse::Message(
-        CreateInteractionResponseMessage::new().embed(embed),
-    ))
+    Ok(Reply::new().embed(embed))
+}
+
+/// Estimates the manager's overall rank from their live points while the
+/// gameweek's ranks are not final, e.g. "~45.6k (▲ 12.3k)"
+///
+/// Live points come from the gameweek's live data (see [`live_points`]), so
+/// automatic substitutions FPL has not made yet are left out.
+///
+/// # Returns
+/// * `Result<Option<String>>` - The estimate, or `None` once FPL's overall
+///   rank is final
+///
+/// # Errors
+/// Returns error if the picks or bootstrap data cannot be parsed or the
+/// manager's history or the gameweek's live data cannot be fetched
+async fn live_rank(
+    picks_data: &serde_json::Value,
+    general_data: &serde_json::Value,
+    manager_id: i32,
+    gameweek: i32,
+) -> Result<Option<String>> {
+    let general: GeneralData = serde_json::from_value(general_data.clone())?;
+    if general
+        .event(gameweek)
+        .is_none_or(|event| event.data_checked)
+    {
+        return Ok(None);
+    }
+    let picks: ManagerPicks = serde_json::from_value(picks_data.clone())?;
+    let history = ManagerHistory::fetch_cached(manager_id).await?;
+
+    let live = update_gameweek_info(gameweek).await?;
+    let points: HashMap<i32, i32> = live
+        .elements
+        .iter()
+        .map(|e| (e.id, e.stats.total_points))
+        .collect();
+    let live_points = live_points(&picks, &points);
+    let start = StartingPosition::before(&history.current, gameweek);
+    let estimator = RankEstimator::new(&general.events, gameweek, general.total_players);
+    let rank = estimator.estimate(start, live_points);
+
+    let movement = match start {
+        Some(start) if i64::from(start.rank) > rank => {
+            format!(" (▲ {})", format_rank(i64::from(start.rank) - rank))
+        }
+        Some(start) if i64::from(start.rank) < rank => {
+            format!(" (▼ {})", format_rank(rank - i64::from(start.rank)))
+        }
+        _ => String::new(),
+    };
+    Ok(Some(format!("~{}{}", format_rank(rank), movement)))
 }
 
 fn build_team_embed(
@@ -123,6 +186,7 @@ fn build_team_embed(
     general_data: &serde_json::Value,
     gameweek: i32,
     manager_id: i32,
+    live_rank: Option<String>,
 ) -> CreateEmbed {
     let picks = picks_data["picks"].as_array().unwrap();
     let entry_history = &picks_data["entry_history"];
@@ -221,16 +285,42 @@ fn build_team_embed(
         description.push_str("```");
     }
 
-    CreateEmbed::new()
+    let mut embed = CreateEmbed::new()
         .title(format!("{} - GW{}", team_name, gameweek))
         .description(description)
         .color(0x37003c)
         .field("GW Points", gw_points.to_string(), true)
         .field("Total Points", total_points.to_string(), true)
         .field("Overall Rank", overall_rank.to_string(), true)
-        .field("GW Rank", rank.to_string(), true)
-        .footer(serenity::builder::CreateEmbedFooter::new(format!(
-            "Manager: {} • ID: {}",
-            manager_name, manager_id
-        )))
+        .field("GW Rank", rank.to_string(), true);
+    if let Some(live_rank) = live_rank {
+        embed = embed.field("Live Rank (est., before auto-subs)", live_rank, true);
+    }
+    embed.footer(serenity::builder::CreateEmbedFooter::new(format!(
+        "Manager: {} • ID: {}",
+        manager_name, manager_id
+    )))
+}
+
+/// The `/check_team` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct CheckTeamCommand;
+
+#[async_trait]
+impl SlashCommand for CheckTeamCommand {
+    fn name(&self) -> &'static str {
+        "check_team"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
 }
@@ -0,0 +1,360 @@
+//! Chips command implementation
+//!
+//! Provides the `/chips` slash command, which shows the chips each manager in
+//! the channel's league has played and has left, and what the chips played in
+//! a gameweek earned.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+use log::{error, info, warn};
+use serenity::all::{CommandInteraction, Context, ResolvedOption, ResolvedValue};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter};
+use serenity::model::application::CommandOptionType;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::bot::commands::standings::fetch_histories;
+use crate::bot::leagues::{league_option, requested_league, resolve_league};
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::fpl::chips::{
+    chip_return, remaining_chips, ChipReturn, PlayedChip, SeasonHalf, SECOND_HALF_START,
+};
+use crate::fpl::client::MAX_CONCURRENT_REQUESTS;
+use crate::fpl::models::gameweek::update_gameweek_info;
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::history::ManagerHistory;
+use crate::fpl::models::league::StandingsManager;
+use crate::fpl::models::picks::ManagerPicks;
+use crate::fpl::standings::top_entries;
+
+/// Upper bound on league members whose histories are fetched, to keep the
+/// number of API requests per command reasonable.
+const MAX_LEAGUE_MANAGERS: usize = 100;
+
+/// Characters of each list shown before it is cut short. Two lists share a
+/// message, and Discord allows 6000 characters across all of its embeds.
+const MAX_SECTION_LENGTH: usize = 2800;
+
+/// Registers the chips command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("chips")
+        .description("Chips played and left by each manager in this channel's league")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "gameweek",
+                "The gameweek whose chip returns are shown (defaults to the current gameweek)",
+            )
+            .required(false),
+        )
+        .add_option(league_option())
+}
+
+/// Main handler for the `/chips` slash command
+///
+/// Shows two embeds: the chips played in a gameweek with the points they
+/// earned, and every manager's chips used and remaining for the season.
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing user input
+/// * `progress` - Progress handle updated while manager histories are fetched
+///
+/// # Returns
+/// * `Result<Reply>` - Discord response with the chip embeds
+///
+/// # Errors
+/// Returns error if:
+/// - No league is set for the channel
+/// - FPL API request fails
+///
+/// # Example Usage
+/// `/chips`
+/// `/chips gameweek:12 league:cup`
+pub async fn run(
+    _ctx: &Context,
+    command: &CommandInteraction,
+    progress: &Progress,
+) -> Result<Reply> {
+    let user_id = &command.user.name;
+    info!("Processing chips command for user {}", user_id);
+
+    let options = command.data.options();
+    let league_id = resolve_league(command, requested_league(&options)).await?;
+
+    let general = GeneralData::fetch().await?;
+    let gameweek = match options.iter().find(|o| o.name == "gameweek") {
+        Some(ResolvedOption {
+            value: ResolvedValue::Integer(gw),
+            ..
+        }) => *gw as i32,
+        _ => general.current_event().map_or(1, |event| event.id),
+    };
+    let next_gameweek = general.next_event().map(|event| event.id);
+
+    let (standings, entries, truncated) = top_entries(league_id, MAX_LEAGUE_MANAGERS).await?;
+    let league_name = &standings.league_info.league_name;
+    info!(
+        "Building chips for league {} gameweek {} (user {})",
+        league_id, gameweek, user_id
+    );
+    progress
+        .update(format!(
+            "Fetching {} manager histories from {}…",
+            entries.len(),
+            league_name
+        ))
+        .await;
+    let histories = fetch_histories(&entries, Some(progress)).await;
+
+    let returns = gameweek_returns(&entries, &histories, gameweek, &general).await?;
+
+    let mut usage_lines = Vec::new();
+    for entry in &entries {
+        let Some(history) = histories.get(&entry.manager_id) else {
+            continue;
+        };
+        usage_lines.push(usage_line(entry, history, next_gameweek));
+    }
+
+    let return_lines: Vec<String> = returns
+        .iter()
+        .map(|(entry, chip_return)| return_line(entry, chip_return, &general))
+        .collect();
+
+    let mut footer = format!(
+        "League ID: {} • Chips reset for the second half from GW{}",
+        league_id, SECOND_HALF_START
+    );
+    if truncated {
+        footer.push_str(&format!(" • Top {} managers only", MAX_LEAGUE_MANAGERS));
+    }
+
+    Ok(Reply::new().embeds(vec![
+        CreateEmbed::new()
+            .title(format!("{} chips - GW{}", league_name, gameweek))
+            .description(section(
+                &return_lines,
+                "No chips were played this gameweek.",
+            ))
+            .color(0x00ff87), // green
+        CreateEmbed::new()
+            .title(format!("{} chips used and left", league_name))
+            .description(section(
+                &usage_lines,
+                "No manager histories could be fetched.",
+            ))
+            .color(0x37003c) // purple
+            .footer(CreateEmbedFooter::new(footer)),
+    ]))
+}
+
+/// Works out the returns of every chip played in a gameweek
+///
+/// # Returns
+/// * `Result<Vec<(&StandingsManager, ChipReturn)>>` - Managers who played a
+///   chip in the gameweek and what it earned, in standings order
+///
+/// # Errors
+/// Returns error if the live gameweek data cannot be fetched
+async fn gameweek_returns<'a>(
+    entries: &'a [StandingsManager],
+    histories: &HashMap<i32, Arc<ManagerHistory>>,
+    gameweek: i32,
+    general: &GeneralData,
+) -> Result<Vec<(&'a StandingsManager, ChipReturn)>> {
+    let players: Vec<&StandingsManager> = entries
+        .iter()
+        .filter(|entry| {
+            histories
+                .get(&entry.manager_id)
+                .is_some_and(|history| history.chips.iter().any(|chip| chip.event == gameweek))
+        })
+        .collect();
+    if players.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // gameweeks that have not started have no live data or chip returns
+    if general.event(gameweek).is_some_and(|event| event.is_next) {
+        return Ok(Vec::new());
+    }
+    let live = update_gameweek_info(gameweek).await?;
+    let points: HashMap<i32, i32> = live
+        .elements
+        .iter()
+        .map(|e| (e.id, e.stats.total_points))
+        .collect();
+
+    let limit = Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS));
+    let mut requests = JoinSet::new();
+    for manager_id in players.iter().map(|entry| entry.manager_id) {
+        let limit = limit.clone();
+        requests.spawn(async move {
+            let _permit = limit.acquire_owned().await;
+            (manager_id, ManagerPicks::fetch(manager_id, gameweek).await)
+        });
+    }
+
+    let mut returns = HashMap::new();
+    while let Some(joined) = requests.join_next().await {
+        match joined {
+            Ok((manager_id, Ok(picks))) => {
+                if let Some(chip_return) = chip_return(&picks, &points) {
+                    returns.insert(manager_id, chip_return);
+                }
+            }
+            Ok((manager_id, Err(e))) => {
+                warn!(
+                    "Skipping chip return of manager {} in gameweek {}: {}",
+                    manager_id, gameweek, e
+                );
+            }
+            Err(e) => error!("Chip picks request panicked: {}", e),
+        }
+    }
+
+    Ok(players
+        .into_iter()
+        .filter_map(|entry| Some((entry, returns.remove(&entry.manager_id)?)))
+        .collect())
+}
+
+/// Formats what a chip earned, e.g. "**Team** Triple Captain: Haaland 13×3 (+13), 78 pts"
+fn return_line(
+    entry: &StandingsManager,
+    chip_return: &ChipReturn,
+    general: &GeneralData,
+) -> String {
+    let detail = match (chip_return.captain, chip_return.chip_points) {
+        (Some(captain), Some(points)) => {
+            format!(
+                " {} {}×3 (+{})",
+                general.player_name(captain),
+                points,
+                points
+            )
+        }
+        (None, Some(points)) => format!(" bench {} pts", points),
+        _ => String::new(),
+    };
+    format!(
+        "**{}** {}:{}, {} pts",
+        entry.team_name, chip_return.chip, detail, chip_return.gameweek_points
+    )
+}
+
+/// Formats a manager's chips, e.g. "**Team** used WC GW3, TC GW7 • left BB FH | GW20+ all"
+///
+/// # Arguments
+/// * `entry` - The manager's league entry
+/// * `history` - The manager's season history
+/// * `next_gameweek` - The next gameweek to be played, or `None` once the
+///   season is over
+fn usage_line(
+    entry: &StandingsManager,
+    history: &ManagerHistory,
+    next_gameweek: Option<i32>,
+) -> String {
+    let played: Vec<PlayedChip> = history
+        .chips
+        .iter()
+        .filter_map(PlayedChip::from_play)
+        .collect();
+    let used = if played.is_empty() {
+        "none".to_string()
+    } else {
+        played
+            .iter()
+            .map(|p| format!("{} GW{}", p.chip.short_name(), p.gameweek))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let Some(next_gameweek) = next_gameweek else {
+        return format!("**{}** used {}", entry.team_name, used);
+    };
+    let remaining = remaining_chips(&played, next_gameweek);
+    let half = |half: SeasonHalf| -> String {
+        let chips: Vec<&str> = remaining
+            .iter()
+            .filter(|(_, h)| *h == half)
+            .map(|(chip, _)| chip.short_name())
+            .collect();
+        if chips.is_empty() {
+            "none".to_string()
+        } else {
+            chips.join(" ")
+        }
+    };
+
+    if SeasonHalf::of(next_gameweek) == SeasonHalf::First {
+        format!(
+            "**{}** used {} • left {} | GW{}+ {}",
+            entry.team_name,
+            used,
+            half(SeasonHalf::First),
+            SECOND_HALF_START,
+            half(SeasonHalf::Second)
+        )
+    } else {
+        format!(
+            "**{}** used {} • left {}",
+            entry.team_name,
+            used,
+            half(SeasonHalf::Second)
+        )
+    }
+}
+
+/// Joins list lines, cutting the list short once it gets too long
+fn section(lines: &[String], empty: &str) -> String {
+    if lines.is_empty() {
+        return empty.to_string();
+    }
+    let mut shown = Vec::new();
+    let mut length = 0;
+    for (i, line) in lines.iter().enumerate() {
+        length += line.len() + 1;
+        if length > MAX_SECTION_LENGTH {
+            shown.push(format!("…and {} more", lines.len() - i));
+            break;
+        }
+        shown.push(line.clone());
+    }
+    shown.join("\n")
+}
+
+/// The `/chips` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct ChipsCommand;
+
+#[async_trait]
+impl SlashCommand for ChipsCommand {
+    fn name(&self) -> &'static str {
+        "chips"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command, progress).await
+    }
+
+    async fn autocomplete(&self, ctx: &Context, interaction: &CommandInteraction) {
+        crate::bot::leagues::autocomplete(ctx, interaction).await
+    }
+}
@@ -0,0 +1,149 @@
+//! Command administration
+//!
+//! Provides the admin-only `/commands` slash command for re-syncing the bot's
+//! slash commands with Discord or purging them from a scope.
+
+use anyhow::{anyhow, Result};
+use log::info;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, Permissions, ResolvedOption, ResolvedValue,
+};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption};
+
+use crate::bot::permissions::{audit, Access};
+use crate::bot::registry::{CommandRegistry, SlashCommand};
+use crate::bot::response::{Progress, Reply};
+use crate::bot::sync::{purge_scope, scope_label, sync_commands};
+use crate::config::config;
+
+/// Registers the commands admin command with Discord
+///
+/// Hidden from members without the Administrator permission by default; the
+/// dispatch layer checks [`Access::Admins`] again in case a server overrides that.
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("commands")
+        .description("Re-sync or purge the bot's slash commands (admin only)")
+        .default_member_permissions(Permissions::ADMINISTRATOR)
+        .dm_permission(false)
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "sync",
+            "Re-register every command, even if nothing changed",
+        ))
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "purge",
+                "Remove all of the bot's commands from a scope",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(CommandOptionType::String, "scope", "Where to purge")
+                    .add_string_choice("This server", "guild")
+                    .add_string_choice("Global", "global")
+                    .required(true),
+            ),
+        )
+}
+
+/// Main handler for the `/commands` slash command
+///
+/// # Arguments
+/// * `_ctx` - Discord context used for the registration requests
+/// * `command` - The slash command interaction containing the subcommand
+///
+/// # Returns
+/// * `Result<Reply>` - Ephemeral summary of what was synced or purged
+///
+/// # Errors
+/// Returns error if:
+/// - The registration mode is misconfigured
+/// - Discord rejects the registration request
+///
+/// # Example Usage
+/// `/commands sync`
+/// `/commands purge scope:global`
+pub async fn run(ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    let options = command.data.options();
+    let Some(ResolvedOption {
+        name,
+        value: ResolvedValue::SubCommand(sub_options),
+        ..
+    }) = options.first()
+    else {
+        return Err(anyhow!("Please choose sync or purge"));
+    };
+
+    let content = match *name {
+        "sync" => {
+            info!("Forced command sync requested by {}", command.user.name);
+            let mode = config().discord.registration_mode()?;
+            let definitions = CommandRegistry::new(super::all())?.definitions();
+            let report = sync_commands(&ctx.http, &mode, definitions, true).await?;
+            audit(command, "commands re-synced").await;
+            format!("Commands re-registered.\n{}", report.join("\n"))
+        }
+        "purge" => {
+            let scope = match sub_options.first().map(|o| &o.value) {
+                Some(ResolvedValue::String("global")) => None,
+                _ => Some(
+                    command
+                        .guild_id
+                        .ok_or_else(|| anyhow!("Guild purges must be run from a server"))?,
+                ),
+            };
+            info!(
+                "Command purge of {} requested by {}",
+                scope_label(scope),
+                command.user.name
+            );
+            purge_scope(&ctx.http, scope).await?;
+            audit(
+                command,
+                format!("commands purged from {}", scope_label(scope)),
+            )
+            .await;
+            format!(
+                "Removed all commands from {}. They will be registered again the next time the bot starts.",
+                scope_label(scope)
+            )
+        }
+        other => return Err(anyhow!("Unknown subcommand {}", other)),
+    };
+
+    Ok(Reply::new().content(content).ephemeral(true))
+}
+
+/// The `/commands` command as served by the [`CommandRegistry`]
+pub struct CommandAdminCommand;
+
+#[async_trait]
+impl SlashCommand for CommandAdminCommand {
+    fn name(&self) -> &'static str {
+        "commands"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    fn access(&self) -> Access {
+        Access::Admins
+    }
+
+    fn ephemeral(&self) -> bool {
+        true
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+}
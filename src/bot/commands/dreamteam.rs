@@ -0,0 +1,372 @@
+//! Dream team command implementation
+//!
+//! Provides the `/dreamteam` slash command, which shows FPL's official team of
+//! the week alongside the best XI that could have been picked using only players
+//! owned by managers in the channel's league.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use log::{error, info, warn};
+use serenity::all::{CommandInteraction, Context, ResolvedOption, ResolvedValue};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter};
+use serenity::model::application::CommandOptionType;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::bot::leagues::{league_option, requested_league, resolve_league};
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::fpl::client::MAX_CONCURRENT_REQUESTS;
+use crate::fpl::fpl_client;
+use crate::fpl::models::dream_team::DreamTeam;
+use crate::fpl::models::gameweek::{update_gameweek_info, GameweekResponse};
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::league::LeagueStandings;
+use crate::fpl::models::picks::ManagerPicks;
+use crate::fpl::squad::{best_xi, formation, Candidate, Lineup, Position};
+
+/// Upper bound on league members whose squads are fetched, to keep the
+/// number of API requests per command reasonable.
+const MAX_LEAGUE_MANAGERS: usize = 100;
+
+/// Registers the dreamteam command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("dreamteam")
+        .description("Team of the week, plus the best XI owned in this channel's league")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "gameweek",
+                "The gameweek number (defaults to the current gameweek)",
+            )
+            .required(false),
+        )
+        .add_option(league_option())
+}
+
+/// Main handler for the `/dreamteam` slash command
+///
+/// Shows two embeds: the official team of the week (falling back to the live
+/// `in_dreamteam` flags if FPL has not published it yet), and the highest
+/// scoring legal XI built from every player owned by a manager in one of the
+/// channel's leagues (the primary league unless `league` is given).
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing user input
+/// * `progress` - Progress handle updated while league squads are fetched
+///
+/// # Returns
+/// * `Result<Reply>` - Discord response with the dream team embeds
+///
+/// # Errors
+/// Returns error if:
+/// - FPL API request fails
+/// - The gameweek has no live data yet
+///
+/// # Example Usage
+/// `/dreamteam gameweek:10`
+/// `/dreamteam league:cup`
+pub async fn run(
+    _ctx: &Context,
+    command: &CommandInteraction,
+    progress: &Progress,
+) -> Result<Reply> {
+    let user_id = &command.user.name;
+    info!("Processing dreamteam command for user {}", user_id);
+
+    let options = command.data.options();
+    let gameweek = match options.iter().find(|o| o.name == "gameweek") {
+        Some(ResolvedOption {
+            value: ResolvedValue::Integer(gw),
+            ..
+        }) => *gw as i32,
+        _ => fpl_client().get_current_gameweek_id().await?,
+    };
+    let requested = requested_league(&options);
+
+    let general = GeneralData::fetch().await?;
+    let live = update_gameweek_info(gameweek).await?;
+
+    let official = match DreamTeam::fetch(gameweek).await {
+        Ok(dream_team) if !dream_team.team.is_empty() => dream_team
+            .team
+            .iter()
+            .filter_map(|pick| candidate(&general, pick.element, pick.points))
+            .collect(),
+        Ok(_) => live_dream_team(&general, &live),
+        Err(e) => {
+            warn!(
+                "Failed to fetch dream team for gameweek {}, using live flags: {}",
+                gameweek, e
+            );
+            live_dream_team(&general, &live)
+        }
+    };
+
+    let mut embeds = vec![build_official_embed(gameweek, &official, &general)];
+
+    match resolve_league(command, requested).await {
+        Ok(league_id) => {
+            info!(
+                "Building league dream team for league {} gameweek {} (user {})",
+                league_id, gameweek, user_id
+            );
+            match league_dream_team(league_id, gameweek, &general, &live, progress).await {
+                Ok((league_name, lineup, owners)) => embeds.push(build_league_embed(
+                    &league_name,
+                    gameweek,
+                    lineup.as_ref(),
+                    &owners,
+                    &general,
+                )),
+                Err(e) => {
+                    error!(
+                        "Failed to build league dream team for league {}: {}",
+                        league_id, e
+                    );
+                    return Err(e);
+                }
+            }
+        }
+        Err(e) if requested.is_some() => return Err(e),
+        Err(e) => embeds.push(
+            CreateEmbed::new()
+                .title("League dream team")
+                .description(e.to_string())
+                .color(0x37003c), // purple
+        ),
+    }
+
+    Ok(Reply::new().embeds(embeds))
+}
+
+/// Builds a lineup candidate for a player, scored by the given points
+fn candidate(general: &GeneralData, element: i32, points: i32) -> Option<Candidate> {
+    let player = general.player(element)?;
+    Some(Candidate {
+        id: element,
+        position: Position::from_element_type(player.element_type)?,
+        score: f64::from(points),
+    })
+}
+
+/// The team of the week according to the live `in_dreamteam` flags
+fn live_dream_team(general: &GeneralData, live: &GameweekResponse) -> Vec<Candidate> {
+    live.elements
+        .iter()
+        .filter(|element| element.stats.in_dreamteam)
+        .filter_map(|element| candidate(general, element.id, element.stats.total_points))
+        .collect()
+}
+
+/// Finds the best XI from every player owned in a league
+///
+/// # Arguments
+/// * `league_id` - The classic league to read squads from
+/// * `gameweek` - The gameweek whose squads and points are used
+/// * `general` - Bootstrap data for player positions
+/// * `live` - Live gameweek data for player points
+/// * `progress` - Progress handle for reporting how many squads have been fetched
+///
+/// # Returns
+/// * `Result<(String, Option<Lineup>, HashMap<i32, usize>)>` - League name, the best
+///   XI (if a legal one exists) and how many managers own each player
+///
+/// # Errors
+/// Returns error if the league standings cannot be fetched
+async fn league_dream_team(
+    league_id: i32,
+    gameweek: i32,
+    general: &GeneralData,
+    live: &GameweekResponse,
+    progress: &Progress,
+) -> Result<(String, Option<Lineup>, HashMap<i32, usize>)> {
+    let mut page = 1;
+    let mut manager_ids = Vec::new();
+    let league_name = loop {
+        let standings = LeagueStandings::fetch_page(league_id, page).await?;
+        manager_ids.extend(standings.standings.managers.iter().map(|m| m.manager_id));
+        if !standings.standings.has_next || manager_ids.len() >= MAX_LEAGUE_MANAGERS {
+            break standings.league_info.league_name;
+        }
+        page += 1;
+    };
+    manager_ids.truncate(MAX_LEAGUE_MANAGERS);
+
+    let total = manager_ids.len();
+    progress
+        .update(format!("Fetching {} squads from {}…", total, league_name))
+        .await;
+
+    let limit = Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS));
+    let mut requests = JoinSet::new();
+    for manager_id in manager_ids {
+        let limit = limit.clone();
+        requests.spawn(async move {
+            let _permit = limit.acquire_owned().await;
+            (manager_id, ManagerPicks::fetch(manager_id, gameweek).await)
+        });
+    }
+
+    let mut owners: HashMap<i32, usize> = HashMap::new();
+    let mut fetched = 0;
+    while let Some(joined) = requests.join_next().await {
+        fetched += 1;
+        progress
+            .update(format!(
+                "Fetched {}/{} squads from {}…",
+                fetched, total, league_name
+            ))
+            .await;
+        match joined {
+            Ok((_, Ok(picks))) => {
+                let squad: HashSet<i32> = picks.picks.iter().map(|p| p.element).collect();
+                for element in squad {
+                    *owners.entry(element).or_default() += 1;
+                }
+            }
+            Ok((manager_id, Err(e))) => {
+                warn!(
+                    "Skipping manager {} in league dream team: {}",
+                    manager_id, e
+                );
+            }
+            Err(e) => error!("League dream team request panicked: {}", e),
+        }
+    }
+
+    if owners.is_empty() {
+        return Err(anyhow!("No squads found for league {}", league_id));
+    }
+
+    let points: HashMap<i32, i32> = live
+        .elements
+        .iter()
+        .map(|e| (e.id, e.stats.total_points))
+        .collect();
+    let candidates: Vec<Candidate> = owners
+        .keys()
+        .filter_map(|&id| candidate(general, id, points.get(&id).copied().unwrap_or(0)))
+        .collect();
+
+    Ok((league_name, best_xi(&candidates), owners))
+}
+
+/// Formats starters as one line per position, e.g. "**MID** Saka 12 · Palmer 9"
+///
+/// # Arguments
+/// * `starters` - The players to list
+/// * `general` - Bootstrap data used to resolve player names
+/// * `suffix` - Extra text appended after each player's points
+fn format_rows<F>(starters: &[Candidate], general: &GeneralData, suffix: F) -> String
+where
+    F: Fn(&Candidate) -> String,
+{
+    Position::ALL
+        .iter()
+        .filter_map(|&position| {
+            let mut players: Vec<&Candidate> =
+                starters.iter().filter(|c| c.position == position).collect();
+            if players.is_empty() {
+                return None;
+            }
+            players.sort_by(|a, b| b.score.total_cmp(&a.score));
+            let names: Vec<String> = players
+                .iter()
+                .map(|c| format!("{} {}{}", general.player_name(c.id), c.score, suffix(c)))
+                .collect();
+            Some(format!("**{}** {}", position, names.join(" · ")))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Builds the embed for the official team of the week
+fn build_official_embed(gameweek: i32, team: &[Candidate], general: &GeneralData) -> CreateEmbed {
+    let embed = CreateEmbed::new()
+        .title(format!("Gameweek {} Team of the Week", gameweek))
+        .color(0x37003c); // purple
+
+    if team.is_empty() {
+        return embed.description("The team of the week has not been published yet.");
+    }
+
+    let total: f64 = team.iter().map(|c| c.score).sum();
+    let top = team.iter().max_by(|a, b| a.score.total_cmp(&b.score));
+
+    let mut embed = embed
+        .description(format_rows(team, general, |_| String::new()))
+        .field("Formation", formation(team), true)
+        .field("Total", format!("{} pts", total), true);
+    if let Some(top) = top {
+        embed = embed.field(
+            "Player of the week",
+            format!("{} ({} pts)", general.player_name(top.id), top.score),
+            true,
+        );
+    }
+    embed
+}
+
+/// Builds the embed for the best XI owned within a league
+fn build_league_embed(
+    league_name: &str,
+    gameweek: i32,
+    lineup: Option<&Lineup>,
+    owners: &HashMap<i32, usize>,
+    general: &GeneralData,
+) -> CreateEmbed {
+    let embed = CreateEmbed::new()
+        .title(format!("{} dream team - GW{}", league_name, gameweek))
+        .color(0x00ff87); // green
+
+    let Some(lineup) = lineup else {
+        return embed.description("Not enough players owned in this league to form a legal XI.");
+    };
+
+    embed
+        .description(format_rows(&lineup.starters, general, |c| {
+            let count = owners.get(&c.id).copied().unwrap_or(0);
+            format!(" ({}×)", count)
+        }))
+        .field("Formation", lineup.formation(), true)
+        .field("Total", format!("{} pts", lineup.total_score()), true)
+        .footer(CreateEmbedFooter::new(format!(
+            "Built from {} players owned by league members, (n×) = owners",
+            owners.len()
+        )))
+}
+
+/// The `/dreamteam` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct DreamteamCommand;
+
+#[async_trait]
+impl SlashCommand for DreamteamCommand {
+    fn name(&self) -> &'static str {
+        "dreamteam"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command, progress).await
+    }
+
+    async fn autocomplete(&self, ctx: &Context, interaction: &CommandInteraction) {
+        crate::bot::leagues::autocomplete(ctx, interaction).await
+    }
+}
@@ -0,0 +1,433 @@
+//! Fixture difficulty ticker command implementation
+//!
+//! Provides the `/fdr` slash command, which renders a colour-coded grid of every
+//! Premier League team against their next few gameweeks. The grid is sent as a
+//! PNG image, with a monospace text table as a fallback.
+
+use anyhow::Result;
+use log::{error, info};
+use serenity::all::{CommandInteraction, Context, CreateAttachment};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed};
+use serenity::model::application::{CommandOptionType, ResolvedOption, ResolvedValue};
+
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::fpl::calendar::{SeasonCalendar, SEASON_GAMEWEEKS};
+use crate::fpl::fpl_client;
+use crate::fpl::models::fixtures::Fixture;
+use crate::fpl::models::teams::get_team_name;
+use crate::utils::render::{contrasting_text, Canvas, Rect, Rgb};
+
+const DEFAULT_SPAN: i32 = 5;
+const MAX_SPAN: i32 = 10;
+
+const TEAM_COL_WIDTH: u32 = 64;
+const CELL_WIDTH: u32 = 72;
+const CELL_HEIGHT: u32 = 30;
+const HEADER_HEIGHT: u32 = 30;
+const GRID_GAP: u32 = 2;
+const BACKGROUND: Rgb = [0x37, 0x00, 0x3c];
+const BLANK_COLOUR: Rgb = [0x2b, 0x2b, 0x2b];
+
+/// One fixture as seen from a single team's point of view.
+#[derive(Debug, Clone)]
+pub struct TickerFixture {
+    pub opponent: i32,
+    pub is_home: bool,
+    pub difficulty: u8,
+}
+
+/// A team's fixtures across the requested gameweek window.
+///
+/// `gameweeks[i]` holds the fixtures in gameweek `from_gw + i`; an empty entry
+/// is a blank gameweek and more than one entry is a double.
+#[derive(Debug, Clone)]
+pub struct TeamTicker {
+    pub team_id: i32,
+    pub gameweeks: Vec<Vec<TickerFixture>>,
+}
+
+impl TeamTicker {
+    /// Average difficulty across the window, counting each blank as a 5.
+    pub fn average_difficulty(&self) -> f64 {
+        let scores: Vec<f64> = self
+            .gameweeks
+            .iter()
+            .flat_map(|gw| {
+                if gw.is_empty() {
+                    vec![5.0]
+                } else {
+                    gw.iter().map(|f| f64::from(f.difficulty)).collect()
+                }
+            })
+            .collect();
+        if scores.is_empty() {
+            return 0.0;
+        }
+        scores.iter().sum::<f64>() / scores.len() as f64
+    }
+}
+
+/// Registers the fdr command with Discord
+///
+/// Creates the command definition for the `/fdr` slash command with optional
+/// starting gameweek, window length and output format.
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("fdr")
+        .description("Fixture difficulty ticker for the upcoming gameweeks")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "from_gw",
+                "First gameweek to show (defaults to the next gameweek)",
+            )
+            .min_int_value(1)
+            .max_int_value(SEASON_GAMEWEEKS as u64)
+            .required(false),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::Integer, "span", "Number of gameweeks")
+                .min_int_value(1)
+                .max_int_value(MAX_SPAN as u64)
+                .required(false),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::String, "format", "Output format")
+                .add_string_choice("Image", "image")
+                .add_string_choice("Text", "text")
+                .required(false),
+        )
+}
+
+/// Main handler for the `/fdr` slash command
+///
+/// Fetches the season calendar, builds a per-team ticker for the
+/// requested window, sorts it by average difficulty and responds with a PNG
+/// grid. Falls back to a text table if rendering fails or text was requested.
+/// The window starts at the next gameweek by default, or at the last one once
+/// its deadline has passed.
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing user input
+///
+/// # Returns
+/// * `Result<Reply>` - Discord response with the ticker
+///
+/// # Errors
+/// Returns error if the FPL API request fails or fixture data cannot be parsed
+///
+/// # Example Usage
+/// `/fdr from_gw:10 span:6`
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    let user_id = &command.user.name;
+    info!("Processing fdr command for user {}", user_id);
+
+    let options = command.data.options();
+    let from_gw = match find_integer(&options, "from_gw") {
+        Some(gw) => gw,
+        None => fpl_client().get_upcoming_gameweek_id().await?,
+    };
+    let span = find_integer(&options, "span")
+        .unwrap_or(DEFAULT_SPAN)
+        .clamp(1, MAX_SPAN)
+        .min(SEASON_GAMEWEEKS - from_gw + 1)
+        .max(1);
+    let text_only = options
+        .iter()
+        .any(|opt| opt.name == "format" && matches!(opt.value, ResolvedValue::String("text")));
+
+    let calendar = SeasonCalendar::fetch().await?;
+    let ticker = build_ticker(&calendar, from_gw, span);
+
+    let title = format!("Fixture difficulty: GW{}-GW{}", from_gw, from_gw + span - 1);
+    let mut embed = CreateEmbed::new().title(title).color(0x37003c);
+    if let Some(flags) = describe_blanks_and_doubles(&calendar, from_gw, span) {
+        embed = embed.field("Blank & double gameweeks", flags, false);
+    }
+
+    if !text_only {
+        match render_ticker_png(&ticker, from_gw) {
+            Ok(png) => {
+                info!("Rendered fdr image for user {}", user_id);
+                let attachment = CreateAttachment::bytes(png, "fdr.png");
+                return Ok(Reply::new()
+                    .add_file(attachment)
+                    .embed(embed.image("attachment://fdr.png")));
+            }
+            Err(e) => error!("Failed to render fdr image, using text fallback: {}", e),
+        }
+    }
+
+    Ok(Reply::new().embed(embed.description(render_ticker_text(&ticker, from_gw))))
+}
+
+fn find_integer(options: &[ResolvedOption], name: &str) -> Option<i32> {
+    options.iter().find_map(|opt| match opt.value {
+        ResolvedValue::Integer(value) if opt.name == name => Some(value as i32),
+        _ => None,
+    })
+}
+
+/// Builds a ticker for every team over `span` gameweeks starting at `from_gw`
+///
+/// Teams are taken from the fixture list itself, so the result always covers
+/// the whole league. The output is sorted easiest run first.
+///
+/// # Arguments
+/// * `calendar` - The season calendar built from the full fixture list
+/// * `from_gw` - First gameweek of the window
+/// * `span` - Number of gameweeks in the window
+pub fn build_ticker(calendar: &SeasonCalendar, from_gw: i32, span: i32) -> Vec<TeamTicker> {
+    let mut ticker: Vec<TeamTicker> = calendar
+        .teams()
+        .map(|team_id| TeamTicker {
+            team_id,
+            gameweeks: (from_gw..from_gw + span)
+                .map(|gw| {
+                    calendar
+                        .team_fixtures(team_id, gw)
+                        .into_iter()
+                        .map(|fixture| ticker_fixture(fixture, team_id))
+                        .collect()
+                })
+                .collect(),
+        })
+        .collect();
+
+    ticker.sort_by(|a, b| {
+        a.average_difficulty()
+            .total_cmp(&b.average_difficulty())
+            .then(a.team_id.cmp(&b.team_id))
+    });
+    ticker
+}
+
+fn ticker_fixture(fixture: &Fixture, team_id: i32) -> TickerFixture {
+    if fixture.team_h == team_id {
+        TickerFixture {
+            opponent: fixture.team_a,
+            is_home: true,
+            difficulty: fixture.team_h_difficulty,
+        }
+    } else {
+        TickerFixture {
+            opponent: fixture.team_h,
+            is_home: false,
+            difficulty: fixture.team_a_difficulty,
+        }
+    }
+}
+
+/// Summarises which teams blank or double in each gameweek of the window
+///
+/// Returns `None` if every team plays exactly once in every gameweek.
+fn describe_blanks_and_doubles(
+    calendar: &SeasonCalendar,
+    from_gw: i32,
+    span: i32,
+) -> Option<String> {
+    let short_names = |teams: &[i32]| {
+        teams
+            .iter()
+            .map(|id| get_team_name(*id).short_name)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let lines: Vec<String> = (from_gw..from_gw + span)
+        .map(|gw| calendar.summary(gw))
+        .flat_map(|summary| {
+            let mut lines = Vec::new();
+            if !summary.blanks.is_empty() {
+                lines.push(format!(
+                    "**GW{} blank:** {}",
+                    summary.gameweek,
+                    short_names(&summary.blanks)
+                ));
+            }
+            if !summary.doubles.is_empty() {
+                lines.push(format!(
+                    "**GW{} double:** {}",
+                    summary.gameweek,
+                    short_names(&summary.doubles)
+                ));
+            }
+            lines
+        })
+        .collect();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// Official FPL difficulty colours, from 1 (easiest) to 5 (hardest)
+fn difficulty_colour(difficulty: u8) -> Rgb {
+    match difficulty {
+        1 => [0x37, 0x55, 0x23],
+        2 => [0x01, 0xfc, 0x7a],
+        3 => [0xe7, 0xe7, 0xe7],
+        4 => [0xff, 0x17, 0x51],
+        _ => [0x80, 0x07, 0x2d],
+    }
+}
+
+fn fixture_label(fixture: &TickerFixture) -> String {
+    format!(
+        "{} {}",
+        get_team_name(fixture.opponent).short_name,
+        if fixture.is_home { "H" } else { "A" }
+    )
+}
+
+/// Renders the ticker as a PNG grid
+///
+/// One row per team and one column per gameweek. Doubles split their cell
+/// vertically, blanks are drawn in dark grey.
+fn render_ticker_png(ticker: &[TeamTicker], from_gw: i32) -> Result<Vec<u8>> {
+    let span = ticker.first().map(|t| t.gameweeks.len()).unwrap_or(0) as u32;
+    let width = TEAM_COL_WIDTH + span * (CELL_WIDTH + GRID_GAP) + GRID_GAP;
+    let height = HEADER_HEIGHT + ticker.len() as u32 * (CELL_HEIGHT + GRID_GAP) + GRID_GAP;
+    let mut canvas = Canvas::new(width, height, BACKGROUND);
+    let white = [255, 255, 255];
+
+    for col in 0..span {
+        let x = TEAM_COL_WIDTH + GRID_GAP + col * (CELL_WIDTH + GRID_GAP);
+        let header = format!("GW{}", from_gw + col as i32);
+        canvas.draw_text_centered(
+            Rect::new(x, 0, CELL_WIDTH, HEADER_HEIGHT),
+            &header,
+            2,
+            white,
+        );
+    }
+
+    for (row_idx, row) in ticker.iter().enumerate() {
+        let y = HEADER_HEIGHT + GRID_GAP + row_idx as u32 * (CELL_HEIGHT + GRID_GAP);
+        let team = get_team_name(row.team_id).short_name;
+        canvas.draw_text_centered(Rect::new(0, y, TEAM_COL_WIDTH, CELL_HEIGHT), team, 2, white);
+
+        for (col, gw_fixtures) in row.gameweeks.iter().enumerate() {
+            let x = TEAM_COL_WIDTH + GRID_GAP + col as u32 * (CELL_WIDTH + GRID_GAP);
+            let cell = Rect::new(x, y, CELL_WIDTH, CELL_HEIGHT);
+
+            match gw_fixtures.as_slice() {
+                [] => {
+                    canvas.fill_rect(cell, BLANK_COLOUR);
+                    canvas.draw_text_centered(cell, "-", 2, white);
+                }
+                [single] => {
+                    let colour = difficulty_colour(single.difficulty);
+                    canvas.fill_rect(cell, colour);
+                    let label = fixture_label(single);
+                    canvas.draw_text_centered(cell, &label, 2, contrasting_text(colour));
+                }
+                multiple => {
+                    let part_height = CELL_HEIGHT / multiple.len() as u32;
+                    for (i, fixture) in multiple.iter().enumerate() {
+                        let part =
+                            Rect::new(x, y + i as u32 * part_height, CELL_WIDTH, part_height);
+                        let colour = difficulty_colour(fixture.difficulty);
+                        canvas.fill_rect(part, colour);
+                        let label = fixture_label(fixture);
+                        canvas.draw_text_centered(part, &label, 1, contrasting_text(colour));
+                    }
+                }
+            }
+        }
+    }
+
+    canvas.encode_png()
+}
+
+/// Renders the ticker as a monospace table
+///
+/// Home fixtures are upper case and away fixtures lower case, each followed by
+/// its difficulty. Doubles are joined with `+` and blanks shown as `-`.
+fn render_ticker_text(ticker: &[TeamTicker], from_gw: i32) -> String {
+    let cells: Vec<Vec<String>> = ticker
+        .iter()
+        .map(|row| {
+            row.gameweeks
+                .iter()
+                .map(|gw| {
+                    if gw.is_empty() {
+                        return "-".to_string();
+                    }
+                    gw.iter()
+                        .map(|f| {
+                            let short = get_team_name(f.opponent).short_name;
+                            let short = if f.is_home {
+                                short.to_string()
+                            } else {
+                                short.to_lowercase()
+                            };
+                            format!("{}{}", short, f.difficulty)
+                        })
+                        .collect::<Vec<_>>()
+                        .join("+")
+                })
+                .collect()
+        })
+        .collect();
+
+    let span = ticker.first().map(|t| t.gameweeks.len()).unwrap_or(0);
+    let widths: Vec<usize> = (0..span)
+        .map(|col| {
+            cells
+                .iter()
+                .map(|row| row[col].len())
+                .max()
+                .unwrap_or(0)
+                .max(format!("GW{}", from_gw + col as i32).len())
+        })
+        .collect();
+
+    let mut description = String::new();
+    description.push_str("```\n    ");
+    for (col, width) in widths.iter().enumerate() {
+        let header = format!("GW{}", from_gw + col as i32);
+        description.push_str(&format!(" {:<width$}", header, width = width));
+    }
+    description.push('\n');
+
+    for (row, row_cells) in ticker.iter().zip(cells.iter()) {
+        description.push_str(&format!("{:<4}", get_team_name(row.team_id).short_name));
+        for (cell, width) in row_cells.iter().zip(widths.iter()) {
+            description.push_str(&format!(" {:<width$}", cell, width = width));
+        }
+        description.push('\n');
+    }
+    description.push_str("```");
+    description
+}
+
+/// The `/fdr` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct FdrCommand;
+
+#[async_trait]
+impl SlashCommand for FdrCommand {
+    fn name(&self) -> &'static str {
+        "fdr"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+}
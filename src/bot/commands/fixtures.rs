@@ -3,65 +3,100 @@
 //! Provides Discord slash command functionality for displaying FPL gameweek fixtures
 //! with match details, scores, and team information.
 
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::fpl::fpl_client;
+use crate::fpl::models::general::GeneralData;
 use crate::fpl::models::teams::get_team_name;
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use log::{error, info};
-use serenity::all::{
-    CommandInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
-};
+use serenity::all::{CommandInteraction, Context};
+use serenity::async_trait;
 use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed};
 use serenity::model::application::{CommandOptionType, ResolvedOption, ResolvedValue};
 
-use crate::fpl::models::fixtures::{fetch_fixtures, GameweekFixtures};
+use crate::fpl::models::fixtures::{fetch_all_fixtures, fetch_fixtures, Fixture, GameweekFixtures};
 
 /// Registers the fixtures command with Discord
 ///
-/// Creates the command definition for the `/fixtures` slash command with required
-/// gameweek parameter.
+/// Creates the command definition for the `/fixtures` slash command with optional
+/// gameweek and team parameters.
 ///
 /// # Returns
 /// * `CreateCommand` - Discord command definition ready for registration
 pub fn register() -> CreateCommand {
+    let mut team_option = CreateCommandOption::new(
+        CommandOptionType::Integer,
+        "team",
+        "Show one club's fixtures",
+    )
+    .required(false);
+    for id in 1..=20 {
+        team_option = team_option.add_int_choice(get_team_name(id).name, id);
+    }
+
     CreateCommand::new("fixtures")
         .description("Get a given weeks fixtures")
         .add_option(
             CreateCommandOption::new(
                 CommandOptionType::Integer,
                 "gameweek",
-                "The gameweek number",
+                "The gameweek number (defaults to the current gameweek)",
             )
-            .required(true),
+            .required(false),
         )
+        .add_option(team_option)
 }
 
 /// Main handler for the `/fixtures` slash command
 ///
-/// Fetches and displays FPL fixtures for a specific gameweek with match details,
-/// scores, and kickoff times in an embed format.
+/// Without a team, fetches and displays FPL fixtures for a gameweek (the current
+/// one by default) with live or final scores and goal involvements. With a team,
+/// lists that club's fixtures and results for the whole season, or only the
+/// given gameweek if one was also provided.
 ///
 /// # Arguments
 /// * `_ctx` - Discord context (unused in current implementation)
 /// * `command` - The slash command interaction containing user input
 ///
 /// # Returns
-/// * `Result<CreateInteractionResponse>` - Discord response with fixtures embed
+/// * `Result<Reply>` - Discord response with fixtures embed
 ///
 /// # Errors
 /// Returns error if:
-/// - Gameweek number is not provided or invalid
 /// - FPL API request fails
 /// - Fixture data cannot be processed
 ///
 /// # Example Usage
 /// `/fixtures gameweek:1`
-pub async fn run(
-    _ctx: &Context,
-    command: &CommandInteraction,
-) -> Result<CreateInteractionResponse> {
+/// `/fixtures team:Arsenal`
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
     let user_id = &command.user.name;
     info!("Processing fixtures command for user {}", user_id);
 
-    let week = extract_gameweek(command)?;
+    let options = command.data.options();
+    let gameweek = extract_integer(&options, "gameweek");
+    let team = extract_integer(&options, "team");
+
+    if let Some(team) = team {
+        info!(
+            "Fetching season fixtures for team {} requested by user {}",
+            team, user_id
+        );
+        let fixtures: Vec<Fixture> = fetch_all_fixtures()
+            .await?
+            .into_iter()
+            .filter(|f| f.team_h == team || f.team_a == team)
+            .filter(|f| gameweek.is_none() || f.event.map(i32::from) == gameweek)
+            .collect();
+
+        return Ok(Reply::new().embed(build_team_fixtures_embed(team, &fixtures)));
+    }
+
+    let week = match gameweek {
+        Some(week) => week,
+        None => fpl_client().get_current_gameweek_id().await?,
+    };
     info!(
         "Fetching fixtures for gameweek {} requested by user {}",
         week, user_id
@@ -86,81 +121,226 @@ pub async fn run(
         }
     };
 
-    let embed = build_fixtures_embed(&fixtures);
+    // player names are only needed once goals can have been scored
+    let general = if fixtures.fixtures.iter().any(|f| f.started) {
+        Some(GeneralData::fetch().await?)
+    } else {
+        None
+    };
+
+    let embed = build_fixtures_embed(&fixtures, general.as_ref());
 
     info!(
         "Successfully built fixtures response for gameweek {} (user {})",
         week, user_id
     );
-    Ok(CreateInteractionResponse::Message(
-        CreateInteractionResponseMessage::new().embed(embed),
-    ))
+    Ok(Reply::new().embed(embed))
 }
 
-/// Extracts gameweek number from Discord command options
-///
-/// Parses the first command option to extract the gameweek integer value.
+/// Extracts an integer option by name from Discord command options
 ///
 /// # Arguments
-/// * `command` - The Discord command interaction containing options
+/// * `options` - Resolved command options
+/// * `name` - The option name to look for
 ///
 /// # Returns
-/// * `Result<i32>` - The gameweek number as a 32-bit integer
+/// * `Option<i32>` - The option value, if it was provided
+fn extract_integer(options: &[ResolvedOption], name: &str) -> Option<i32> {
+    options.iter().find_map(|opt| match opt.value {
+        ResolvedValue::Integer(value) if opt.name == name => Some(value as i32),
+        _ => None,
+    })
+}
+
+/// Formats the kickoff time, or "TBC" if the fixture has no date yet
+fn format_kickoff(fixture: &Fixture) -> String {
+    fixture
+        .kickoff_time
+        .map(|t| t.format("%d.%m %H:%M").to_string())
+        .unwrap_or_else(|| "TBC".to_string())
+}
+
+/// Short status of a fixture: kickoff time, minutes played, or full time
+fn fixture_status(fixture: &Fixture) -> String {
+    if fixture.is_over() {
+        "FT".to_string()
+    } else if fixture.started {
+        format!("🔴 {}'", fixture.minutes)
+    } else {
+        format_kickoff(fixture)
+    }
+}
+
+/// Home and away score, if the match has started
+fn score(fixture: &Fixture) -> Option<(u8, u8)> {
+    if !fixture.started {
+        return None;
+    }
+    Some((
+        fixture.team_h_score.unwrap_or(0),
+        fixture.team_a_score.unwrap_or(0),
+    ))
+}
+
+/// Lists the players credited with a stat in a fixture, e.g. "Saka (2), Rice"
 ///
-/// # Errors
-/// Returns error if no valid integer option is provided
-fn extract_gameweek(command: &CommandInteraction) -> Result<i32> {
-    let resolved = command.data.options();
-    match resolved.first() {
-        Some(ResolvedOption {
-            value: ResolvedValue::Integer(id),
-            ..
-        }) => {
-            // info!("Extracted gameweek: {}", id);
-            Ok(*id as i32)
-        }
-        _ => {
-            error!("No valid gameweek provided in command options");
-            Err(anyhow!("Please provide a valid gameweek"))
-        }
+/// # Arguments
+/// * `fixture` - The fixture to read stats from
+/// * `identifier` - Stat identifier such as `goals_scored` or `assists`
+/// * `general` - Bootstrap data used to resolve player names
+fn stat_players(fixture: &Fixture, identifier: &str, general: &GeneralData) -> Option<String> {
+    let stat = fixture.stat(identifier)?;
+    let names: Vec<String> = stat
+        .h
+        .iter()
+        .chain(stat.a.iter())
+        .map(|entry| {
+            let name = general.player_name(entry.element);
+            if entry.value > 1 {
+                format!("{} ({})", name, entry.value)
+            } else {
+                name.to_string()
+            }
+        })
+        .collect();
+
+    if names.is_empty() {
+        None
+    } else {
+        Some(names.join(", "))
     }
 }
 
 /// Builds a Discord embed displaying gameweek fixtures
 ///
-/// Creates a formatted embed with fixture data, including team names and kickoff times.
-/// Uses fixed-width formatting for consistent alignment in a code block.
+/// Creates one embed field per fixture. Fields are titled with the scoreline
+/// (or just the teams before kickoff) and list the match status followed by
+/// goal scorers, own goals and assisters once the match is underway.
 ///
 /// # Arguments
 /// * `fixtures` - The gameweek fixtures data from FPL API
+/// * `general` - Bootstrap data for resolving player names, if any match has started
 ///
 /// # Returns
 /// * `CreateEmbed` - Discord embed with formatted fixtures list
-///
-/// # Display Format
-/// Shows each fixture with kickoff time centered and team names aligned
-/// in the format: "Date Time\nHome Team - Away Team"
-fn build_fixtures_embed(fixtures: &GameweekFixtures) -> CreateEmbed {
-    let mut description = String::new();
-    description.push_str("```");
+pub fn build_fixtures_embed(
+    fixtures: &GameweekFixtures,
+    general: Option<&GeneralData>,
+) -> CreateEmbed {
+    let mut embed = CreateEmbed::new()
+        .title(format!("Gameweek {}", fixtures.gameweek))
+        .color(0x37003c); // purple
+
     for fixture in fixtures.fixtures.iter() {
         let home_team = get_team_name(fixture.team_h).name;
         let away_team = get_team_name(fixture.team_a).name;
 
-        description.push_str(
-            format!(
-                "{:^38}\n{:>17} - {:<18}\n\n",
-                fixture.kickoff_time.format("%d.%m %H:%M"),
-                home_team,
-                away_team,
-            )
-            .as_str(),
-        );
+        let title = match score(fixture) {
+            Some((h, a)) => format!("{} {} - {} {}", home_team, h, a, away_team),
+            None => format!("{} v {}", home_team, away_team),
+        };
+
+        let mut value = fixture_status(fixture);
+        if let Some(general) = general {
+            let lines = [
+                ("⚽", "goals_scored"),
+                ("🥅 OG", "own_goals"),
+                ("🅰️", "assists"),
+            ];
+            for (icon, identifier) in lines {
+                if let Some(players) = stat_players(fixture, identifier, general) {
+                    value.push_str(&format!("\n{} {}", icon, players));
+                }
+            }
+        }
+
+        embed = embed.field(title, value, false);
+    }
+
+    if fixtures.fixtures.is_empty() {
+        embed = embed.description("No fixtures scheduled.");
+    }
+    embed
+}
+
+/// Builds an embed listing one club's fixtures and results
+///
+/// Each line shows the gameweek, venue, opponent and either the result from
+/// the club's point of view or the kickoff time.
+///
+/// # Arguments
+/// * `team` - The FPL team ID the list is for
+/// * `fixtures` - That team's fixtures, in any order
+///
+/// # Returns
+/// * `CreateEmbed` - Discord embed with the club's fixture list
+pub fn build_team_fixtures_embed(team: i32, fixtures: &[Fixture]) -> CreateEmbed {
+    let mut sorted: Vec<&Fixture> = fixtures.iter().collect();
+    sorted.sort_by_key(|f| (f.event.unwrap_or(u8::MAX), f.kickoff_time));
+
+    let mut description = String::new();
+    description.push_str("```");
+    for fixture in sorted {
+        let is_home = fixture.team_h == team;
+        let opponent = if is_home {
+            fixture.team_a
+        } else {
+            fixture.team_h
+        };
+        let gameweek = fixture
+            .event
+            .map(|gw| format!("GW{}", gw))
+            .unwrap_or_else(|| "TBC".to_string());
+
+        let outcome = match score(fixture) {
+            Some((h, a)) => {
+                let (scored, conceded) = if is_home { (h, a) } else { (a, h) };
+                let result = match scored.cmp(&conceded) {
+                    std::cmp::Ordering::Greater => "W",
+                    std::cmp::Ordering::Less => "L",
+                    std::cmp::Ordering::Equal => "D",
+                };
+                let live = if fixture.is_live() { " (live)" } else { "" };
+                format!("{} {}-{}{}", result, scored, conceded, live)
+            }
+            None => format_kickoff(fixture),
+        };
+
+        description.push_str(&format!(
+            "\n{:<5} {} {:<3} {}",
+            gameweek,
+            if is_home { "H" } else { "A" },
+            get_team_name(opponent).short_name,
+            outcome
+        ));
     }
     description.push_str("```");
 
     CreateEmbed::new()
-        .title(format!("Gameweek {}", fixtures.gameweek))
+        .title(format!("{} fixtures", get_team_name(team).name))
         .description(description)
         .color(0x37003c) // purple
 }
+
+/// The `/fixtures` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct FixturesCommand;
+
+#[async_trait]
+impl SlashCommand for FixturesCommand {
+    fn name(&self) -> &'static str {
+        "fixtures"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+}
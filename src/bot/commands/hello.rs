@@ -0,0 +1,50 @@
+//! Hello command implementation
+//!
+//! A trivial command for checking that the bot is online and responding.
+
+use anyhow::Result;
+use serenity::all::{CommandInteraction, Context};
+use serenity::async_trait;
+use serenity::builder::CreateCommand;
+
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+
+/// Registers the hello command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("hello").description("Say hello to the bot")
+}
+
+/// Main handler for the `/hello` slash command
+///
+/// # Example Usage
+/// `/hello`
+pub async fn run(_ctx: &Context, _command: &CommandInteraction) -> Result<Reply> {
+    Ok(Reply::new().content("Hey!"))
+}
+
+/// The `/hello` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct HelloCommand;
+
+#[async_trait]
+impl SlashCommand for HelloCommand {
+    fn name(&self) -> &'static str {
+        "hello"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+}
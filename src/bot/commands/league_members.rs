@@ -0,0 +1,397 @@
+//! League membership overview
+//!
+//! Provides the `/league_members` slash command, which walks every page of a
+//! league's standings and shows which entries are linked to Discord members
+//! through their manager ID. Admins get select menus to match unlinked entries
+//! to members, so league-wide features can mention the right people.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use log::info;
+use serenity::all::{
+    CommandInteraction, ComponentInteraction, ComponentInteractionDataKind, Context,
+    CreateActionRow, CreateInteractionResponse, CreateInteractionResponseMessage, CreateSelectMenu,
+    CreateSelectMenuKind, CreateSelectMenuOption, UserId,
+};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateEmbed};
+
+use super::managers::{fetch_manager, guild_users};
+use crate::bot::custom_id::ComponentId;
+use crate::bot::leagues::{autocomplete, league_option, requested_league, resolve_league};
+use crate::bot::permissions::{audit_component, component_has_access, has_access, Access};
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::database::models::DBUser;
+use crate::database::service::db_service;
+use crate::fpl::models::league::{LeagueStandings, StandingsManager};
+
+/// Upper bound on standings entries fetched, to keep the number of API
+/// requests per command reasonable.
+const MAX_LEAGUE_MANAGERS: usize = 1000;
+
+/// Characters of each list shown before it is cut short. Two lists share a
+/// message, and Discord allows 6000 characters across all of its embeds.
+const MAX_SECTION_LENGTH: usize = 2800;
+
+/// Discord's limit on options in a select menu.
+const MAX_SELECT_OPTIONS: usize = 25;
+
+/// Discord's limit on the length of a select option label.
+const MAX_LABEL_LENGTH: usize = 100;
+
+/// Version of the entry and member select menus, see [`ComponentId`].
+const COMPONENT_VERSION: u32 = 1;
+
+/// Registers the league members command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("league_members")
+        .description("Show which league entries are linked to members of this server")
+        .add_option(league_option())
+}
+
+/// Main handler for the `/league_members` slash command
+///
+/// # Arguments
+/// * `ctx` - Discord context, used to check server membership of links
+/// * `command` - The slash command interaction
+/// * `progress` - Progress handle updated while standings pages are fetched
+///
+/// # Returns
+/// * `Result<Reply>` - Linked and unlinked entries, with select menus for
+///   matching unlinked entries when the invoker is an admin
+///
+/// # Errors
+/// Returns error if:
+/// - The command is used outside a server
+/// - No league is configured for the channel
+/// - FPL API or database operations fail
+///
+/// # Example Usage
+/// `/league_members`
+/// `/league_members league:cup`
+pub async fn run(
+    ctx: &Context,
+    command: &CommandInteraction,
+    progress: &Progress,
+) -> Result<Reply> {
+    let guild_id = command
+        .guild_id
+        .ok_or_else(|| anyhow!("League members can only be listed in a server"))?;
+    let options = command.data.options();
+    let league_id = resolve_league(command, requested_league(&options)).await?;
+
+    let mut page = 1;
+    let mut entries = Vec::new();
+    let league_name = loop {
+        let standings = LeagueStandings::fetch_page(league_id, page).await?;
+        entries.extend(standings.standings.managers);
+        if !standings.standings.has_next || entries.len() >= MAX_LEAGUE_MANAGERS {
+            break standings.league_info.league_name;
+        }
+        page += 1;
+        progress
+            .update(format!(
+                "Fetched {} entries from league {}…",
+                entries.len(),
+                league_id
+            ))
+            .await;
+    };
+    entries.truncate(MAX_LEAGUE_MANAGERS);
+
+    let users = guild_users(&ctx.http, guild_id).await?;
+    let (linked, unlinked) = partition_members(&entries, &users);
+    info!(
+        "League {} has {} linked and {} unlinked entries",
+        league_id,
+        linked.len(),
+        unlinked.len()
+    );
+
+    let linked_lines: Vec<String> = linked
+        .iter()
+        .map(|(entry, discord_id)| {
+            format!(
+                "<@{}> - {} ({})",
+                discord_id, entry.team_name, entry.manager_name
+            )
+        })
+        .collect();
+    let unlinked_lines: Vec<String> = unlinked
+        .iter()
+        .map(|entry| {
+            format!(
+                "{} ({}) • {}",
+                entry.team_name, entry.manager_name, entry.manager_id
+            )
+        })
+        .collect();
+
+    let mut reply = Reply::new()
+        .embed(
+            CreateEmbed::new()
+                .title(format!("{}: linked ({})", league_name, linked.len()))
+                .description(section(
+                    &linked_lines,
+                    "Nobody in this league is linked yet.",
+                ))
+                .color(0x00ff87), // green
+        )
+        .embed(
+            CreateEmbed::new()
+                .title(format!("{}: unlinked ({})", league_name, unlinked.len()))
+                .description(section(&unlinked_lines, "Every entry is linked."))
+                .color(0x37003c), // purple
+        );
+
+    if !unlinked.is_empty() && has_access(command, Access::Admins).await? {
+        reply = reply.components(vec![entry_menu(league_id, &unlinked)?]);
+    }
+    Ok(reply)
+}
+
+/// Splits league entries into those linked to a Discord user and those not
+///
+/// # Arguments
+/// * `entries` - Standings entries, in standings order
+/// * `users` - The server's users with a linked manager ID
+///
+/// # Returns
+/// * Linked entries with the Discord ID of the user linked to them, and the
+///   remaining unlinked entries, both in standings order
+pub fn partition_members<'a>(
+    entries: &'a [StandingsManager],
+    users: &[DBUser],
+) -> (Vec<(&'a StandingsManager, i64)>, Vec<&'a StandingsManager>) {
+    let by_manager: HashMap<i32, i64> = users
+        .iter()
+        .filter_map(|u| u.manager_id.map(|id| (id, u.discord_id)))
+        .collect();
+
+    let mut linked = Vec::new();
+    let mut unlinked = Vec::new();
+    for entry in entries {
+        match by_manager.get(&entry.manager_id) {
+            Some(discord_id) => linked.push((entry, *discord_id)),
+            None => unlinked.push(entry),
+        }
+    }
+    (linked, unlinked)
+}
+
+/// Joins list lines, cutting the list short once it gets too long
+fn section(lines: &[String], empty: &str) -> String {
+    if lines.is_empty() {
+        return empty.to_string();
+    }
+    let mut shown = Vec::new();
+    let mut length = 0;
+    for (i, line) in lines.iter().enumerate() {
+        length += line.len() + 1;
+        if length > MAX_SECTION_LENGTH {
+            shown.push(format!("…and {} more", lines.len() - i));
+            break;
+        }
+        shown.push(line.clone());
+    }
+    shown.join("\n")
+}
+
+/// Select menu of unlinked entries for admins to match to members
+fn entry_menu(league_id: i32, unlinked: &[&StandingsManager]) -> Result<CreateActionRow> {
+    let options = unlinked
+        .iter()
+        .take(MAX_SELECT_OPTIONS)
+        .map(|entry| {
+            let label: String = entry.team_name.chars().take(MAX_LABEL_LENGTH).collect();
+            let description: String = entry.manager_name.chars().take(MAX_LABEL_LENGTH).collect();
+            CreateSelectMenuOption::new(label, entry.manager_id.to_string())
+                .description(description)
+        })
+        .collect();
+
+    let placeholder = if unlinked.len() > MAX_SELECT_OPTIONS {
+        format!(
+            "Link an entry to a member (first {} shown)",
+            MAX_SELECT_OPTIONS
+        )
+    } else {
+        "Link an entry to a member".to_string()
+    };
+    let custom_id = ComponentId::new("league_members", COMPONENT_VERSION, "pick")
+        .league(league_id)
+        .encode()?;
+    Ok(CreateActionRow::SelectMenu(
+        CreateSelectMenu::new(custom_id, CreateSelectMenuKind::String { options })
+            .placeholder(placeholder),
+    ))
+}
+
+/// Handles the entry and member select menus
+///
+/// # Custom ID Format
+/// A [`ComponentId`] for `league_members` with action:
+/// * `pick` - Choosing an unlinked entry opens a private member picker
+/// * `user` - Choosing a member links them to the entry in the manager ID
+pub async fn handle_component(ctx: &Context, component: &ComponentInteraction) {
+    let response = match handle_selection(component).await {
+        Ok(response) => response,
+        Err(e) => CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new()
+                .content(format!("Error: {}", e))
+                .ephemeral(true),
+        ),
+    };
+    if let Err(why) = component.create_response(&ctx.http, response).await {
+        info!("Cannot respond to league members menu: {}", why);
+    }
+}
+
+async fn handle_selection(component: &ComponentInteraction) -> Result<CreateInteractionResponse> {
+    if !component_has_access(component, Access::Admins).await? {
+        return Err(anyhow!(
+            "Only {} can link league entries to members",
+            Access::Admins.describe()
+        ));
+    }
+
+    let id = ComponentId::parse(
+        &component.data.custom_id,
+        "league_members",
+        COMPONENT_VERSION,
+    )?;
+    match (
+        &component.data.kind,
+        id.action.as_str(),
+        id.state.manager_id,
+    ) {
+        (ComponentInteractionDataKind::StringSelect { values }, "pick", _) => {
+            let manager_id: i32 = values
+                .first()
+                .ok_or_else(|| anyhow!("Please choose an entry"))?
+                .parse()?;
+            let manager = fetch_manager(manager_id).await?;
+            let picker = CreateSelectMenu::new(
+                ComponentId::new("league_members", COMPONENT_VERSION, "user")
+                    .manager(manager_id)
+                    .encode()?,
+                CreateSelectMenuKind::User {
+                    default_users: None,
+                },
+            )
+            .placeholder("Choose the member");
+            Ok(CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(format!(
+                        "Who manages **{}** ({})?",
+                        manager.team_name,
+                        manager.full_name()
+                    ))
+                    .components(vec![CreateActionRow::SelectMenu(picker)])
+                    .ephemeral(true),
+            ))
+        }
+        (ComponentInteractionDataKind::UserSelect { values }, "user", Some(manager_id)) => {
+            let target = *values
+                .first()
+                .ok_or_else(|| anyhow!("Please choose a member"))?;
+            let content = link_member(component, target, manager_id).await?;
+            Ok(CreateInteractionResponse::UpdateMessage(
+                CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .components(vec![]),
+            ))
+        }
+        _ => Err(anyhow!("Unknown league members action")),
+    }
+}
+
+/// Links a member to a league entry, returning the message to show
+async fn link_member(
+    component: &ComponentInteraction,
+    target: UserId,
+    manager_id: i32,
+) -> Result<String> {
+    let manager = fetch_manager(manager_id).await?;
+    let previous = db_service().get_user(target).await.ok();
+
+    db_service()
+        .update_user(&DBUser {
+            discord_id: i64::from(target),
+            manager_id: Some(manager_id),
+            team_name: Some(manager.team_name.clone()),
+            manager_name: Some(manager.full_name()),
+        })
+        .await?;
+    if let Some(guild_id) = component.guild_id {
+        db_service().add_guild_link(guild_id, target).await?;
+    }
+
+    info!(
+        "Linked user {} to manager {} from league members (by {})",
+        target, manager_id, component.user.name
+    );
+    audit_component(
+        component,
+        "league_members",
+        format!("linked <@{}> to manager {}", target, manager_id),
+    )
+    .await;
+
+    let replaced = previous
+        .and_then(|user| user.manager_id.filter(|id| *id != manager_id).map(|_| user))
+        .map(|user| {
+            format!(
+                ", replacing their link to {}",
+                user.team_name.as_deref().unwrap_or("another team")
+            )
+        })
+        .unwrap_or_default();
+    Ok(format!(
+        "Linked <@{}> to **{}** ({}){}. Run /league_members again to see the updated list.",
+        target,
+        manager.team_name,
+        manager.full_name(),
+        replaced
+    ))
+}
+
+/// The `/league_members` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct LeagueMembersCommand;
+
+#[async_trait]
+impl SlashCommand for LeagueMembersCommand {
+    fn name(&self) -> &'static str {
+        "league_members"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command, progress).await
+    }
+
+    async fn autocomplete(&self, ctx: &Context, interaction: &CommandInteraction) {
+        autocomplete(ctx, interaction).await
+    }
+
+    fn component_prefixes(&self) -> &'static [&'static str] {
+        &["league_members:"]
+    }
+
+    async fn handle_component(&self, ctx: &Context, component: &ComponentInteraction) {
+        handle_component(ctx, component).await
+    }
+}
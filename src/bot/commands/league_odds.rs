@@ -0,0 +1,335 @@
+//! League odds command implementation
+//!
+//! Provides the `/league_odds` slash command, which simulates the rest of the
+//! season thousands of times for the channel's league and shows each
+//! member's chance of winning it, finishing in the top three and coming last.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use log::{error, info, warn};
+use serenity::all::{CommandInteraction, Context, ResolvedValue};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter};
+use serenity::model::application::CommandOptionType;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::bot::commands::standings::{fetch_histories, format_name};
+use crate::bot::leagues::{league_option, requested_league, resolve_league};
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::fpl::client::MAX_CONCURRENT_REQUESTS;
+use crate::fpl::models::fixtures::fetch_all_fixtures;
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::picks::ManagerPicks;
+use crate::fpl::projection::{project, Projection};
+use crate::fpl::simulation::{simulate, Contender, Simulation};
+use crate::fpl::squad::{best_xi, captained_score, Candidate, Position};
+use crate::fpl::standings::top_entries;
+
+/// Upper bound on league members simulated, to keep the number of API
+/// requests per command reasonable.
+const MAX_LEAGUE_MANAGERS: usize = 50;
+
+/// Runs used when none are asked for.
+const DEFAULT_RUNS: i64 = 10_000;
+
+/// Fewest and most runs that may be asked for.
+const RUNS_RANGE: (i64, i64) = (1_000, 50_000);
+
+/// Time after which the simulation stops starting new runs.
+const TIME_BUDGET: Duration = Duration::from_secs(3);
+
+/// Characters of a manager's name shown in the table.
+const NAME_WIDTH: usize = 14;
+
+/// Registers the league odds command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("league_odds")
+        .description("Simulated chances of winning, top 3 and last place in this channel's league")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "runs",
+                "Seasons to simulate (defaults to 10000)",
+            )
+            .min_int_value(RUNS_RANGE.0 as u64)
+            .max_int_value(RUNS_RANGE.1 as u64)
+            .required(false),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "seed",
+                "Seed of the random draws, to repeat a simulation exactly",
+            )
+            .min_int_value(0)
+            .required(false),
+        )
+        .add_option(league_option())
+}
+
+/// Main handler for the `/league_odds` slash command
+///
+/// Seeds every member from their current squad's projected points in each
+/// remaining gameweek and the spread of their scores so far, then simulates
+/// the remaining gameweeks. Without a seed, the league and next gameweek seed
+/// the draws, so the odds only change when the data does.
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing user input
+/// * `progress` - Progress handle updated while squads are fetched
+///
+/// # Returns
+/// * `Result<Reply>` - Discord response with each member's odds
+///
+/// # Errors
+/// Returns error if:
+/// - No league is set for the channel
+/// - The season has not started or has no gameweeks left
+/// - FPL API requests fail
+///
+/// # Example Usage
+/// `/league_odds`
+/// `/league_odds runs:20000 seed:42 league:cup`
+pub async fn run(
+    _ctx: &Context,
+    command: &CommandInteraction,
+    progress: &Progress,
+) -> Result<Reply> {
+    let options = command.data.options();
+    let league_id = resolve_league(command, requested_league(&options)).await?;
+    let mut runs = DEFAULT_RUNS;
+    let mut seed = None;
+    for option in &options {
+        match (option.name, &option.value) {
+            ("runs", ResolvedValue::Integer(n)) => runs = (*n).clamp(RUNS_RANGE.0, RUNS_RANGE.1),
+            ("seed", ResolvedValue::Integer(n)) => seed = Some(*n as u64),
+            _ => {}
+        }
+    }
+
+    let general = GeneralData::fetch().await?;
+    let current = general
+        .current_event()
+        .ok_or_else(|| anyhow!("The season has not started yet"))?
+        .id;
+    let first = general
+        .next_event()
+        .ok_or_else(|| anyhow!("The season is over, there is nothing left to simulate"))?
+        .id;
+    let last = general.events.iter().map(|e| e.id).max().unwrap_or(first);
+    let seed = seed.unwrap_or(league_id as u64 * 100 + first as u64);
+
+    let (standings, entries, truncated) = top_entries(league_id, MAX_LEAGUE_MANAGERS).await?;
+    let league_name = standings.league_info.league_name.clone();
+    info!(
+        "Simulating GW{}-{} of league {} {} times with seed {} (user {})",
+        first, last, league_id, runs, seed, command.user.name
+    );
+
+    progress
+        .update(format!(
+            "Fetching {} manager histories from {}…",
+            entries.len(),
+            league_name
+        ))
+        .await;
+    let (histories, fixtures) = tokio::join!(fetch_histories(&entries, None), fetch_all_fixtures());
+    let fixtures = fixtures?;
+
+    let limit = Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS));
+    let mut requests = JoinSet::new();
+    for manager_id in entries.iter().map(|e| e.manager_id) {
+        let limit = limit.clone();
+        requests.spawn(async move {
+            let _permit = limit.acquire_owned().await;
+            (
+                manager_id,
+                ManagerPicks::fetch_upcoming(manager_id, current).await,
+            )
+        });
+    }
+    let mut squads: HashMap<i32, ManagerPicks> = HashMap::new();
+    let mut fetched = 0;
+    while let Some(joined) = requests.join_next().await {
+        fetched += 1;
+        progress
+            .update(format!(
+                "Fetched {}/{} squads from {}…",
+                fetched,
+                entries.len(),
+                league_name
+            ))
+            .await;
+        match joined {
+            Ok((manager_id, Ok(picks))) => {
+                squads.insert(manager_id, picks);
+            }
+            Ok((manager_id, Err(e))) => {
+                warn!("Skipping manager {} in league odds: {}", manager_id, e);
+            }
+            Err(e) => error!("League odds request panicked: {}", e),
+        }
+    }
+
+    let projections: HashMap<i32, Projection> = project(&general, &fixtures, first..=last)
+        .into_iter()
+        .map(|projection| (projection.element, projection))
+        .collect();
+    let contenders: Vec<Contender> = entries
+        .iter()
+        .filter_map(|entry| {
+            let history = histories.get(&entry.manager_id)?;
+            let picks = squads.get(&entry.manager_id)?;
+            let points = squad_points(picks, &projections, &general, first..=last);
+            Some(Contender::new(
+                entry.manager_id,
+                entry.total_points,
+                &points,
+                &history.current,
+            ))
+        })
+        .collect();
+    let skipped = entries.len() - contenders.len();
+    if contenders.is_empty() {
+        return Err(anyhow!("No squads could be fetched from {}", league_name));
+    }
+
+    progress
+        .update(format!("Simulating {} seasons…", runs))
+        .await;
+    let simulated = contenders.clone();
+    let simulation =
+        tokio::task::spawn_blocking(move || simulate(&simulated, runs as usize, seed, TIME_BUDGET))
+            .await?;
+
+    let names: HashMap<i32, &str> = entries
+        .iter()
+        .map(|e| (e.manager_id, e.manager_name.as_str()))
+        .collect();
+    let mut footer = format!(
+        "{} runs • seed {} • GW{}-{} • League ID: {}",
+        simulation.runs, seed, first, last, league_id
+    );
+    if truncated {
+        footer.push_str(&format!(" • Top {} managers only", MAX_LEAGUE_MANAGERS));
+    }
+    if skipped > 0 {
+        footer.push_str(&format!(" • {} without data left out", skipped));
+    }
+
+    Ok(Reply::new().embed(
+        CreateEmbed::new()
+            .title(format!("🎲 {} season odds", league_name))
+            .description(odds_table(&contenders, &simulation, &names))
+            .color(0x37003c) // purple
+            .footer(CreateEmbedFooter::new(footer)),
+    ))
+}
+
+/// Projected points of a squad's best XI and captain in each gameweek
+fn squad_points(
+    picks: &ManagerPicks,
+    projections: &HashMap<i32, Projection>,
+    general: &GeneralData,
+    gameweeks: std::ops::RangeInclusive<i32>,
+) -> Vec<f64> {
+    gameweeks
+        .map(|gameweek| {
+            let candidates: Vec<Candidate> = picks
+                .picks
+                .iter()
+                .filter_map(|pick| {
+                    let player = general.player(pick.element)?;
+                    Some(Candidate {
+                        id: player.id,
+                        position: Position::from_element_type(player.element_type)?,
+                        score: projections
+                            .get(&player.id)
+                            .map_or(0.0, |p| p.points(gameweek)),
+                    })
+                })
+                .collect();
+            best_xi(&candidates).map_or(0.0, |lineup| {
+                captained_score(&lineup.starters, lineup.captains().0)
+            })
+        })
+        .collect()
+}
+
+/// The odds as a fixed-width table, in standings order
+fn odds_table(
+    contenders: &[Contender],
+    simulation: &Simulation,
+    names: &HashMap<i32, &str>,
+) -> String {
+    let percent = |share: f64| {
+        if share > 0.0 && share < 0.001 {
+            "<0.1".to_string()
+        } else {
+            format!("{:.1}", share * 100.0)
+        }
+    };
+
+    let mut table = String::from("```\n");
+    table.push_str(&format!(
+        "{:<w$} {:>5} {:>5} {:>5} {:>5} {:>5}\n",
+        "Manager",
+        "Pts",
+        "Proj",
+        "Win%",
+        "Top3%",
+        "Last%",
+        w = NAME_WIDTH
+    ));
+    for (contender, odds) in contenders.iter().zip(&simulation.odds) {
+        let name = names.get(&contender.entry).copied().unwrap_or("?");
+        table.push_str(&format!(
+            "{:<w$} {:>5} {:>5.0} {:>5} {:>5} {:>5}\n",
+            format_name(name, NAME_WIDTH),
+            contender.total,
+            contender.expected_total(),
+            percent(odds.win),
+            percent(odds.top_three),
+            percent(odds.last),
+            w = NAME_WIDTH
+        ));
+    }
+    table.push_str("```");
+    table
+}
+
+/// The `/league_odds` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct LeagueOddsCommand;
+
+#[async_trait]
+impl SlashCommand for LeagueOddsCommand {
+    fn name(&self) -> &'static str {
+        "league_odds"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command, progress).await
+    }
+
+    async fn autocomplete(&self, ctx: &Context, interaction: &CommandInteraction) {
+        crate::bot::leagues::autocomplete(ctx, interaction).await
+    }
+}
@@ -0,0 +1,346 @@
+//! Channel league configuration
+//!
+//! Provides the `/leagues` slash command for managing the named leagues a
+//! channel follows, which one is primary, and the guild-wide default league
+//! used by channels without leagues of their own.
+
+use anyhow::{anyhow, Result};
+use log::info;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, ResolvedOption, ResolvedValue,
+};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption};
+
+use crate::bot::leagues::{autocomplete, league_option, LEAGUE_OPTION};
+use crate::bot::permissions::{audit, Access};
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::database::models::{DBChannelLeague, DBGuildSettings};
+use crate::database::service::db_service;
+use crate::fpl::models::league::LeagueStandings;
+
+/// Longest league name accepted, so names fit in autocomplete labels.
+const MAX_NAME_LENGTH: usize = 50;
+
+/// Registers the leagues command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("leagues")
+        .description("Manage the leagues this channel follows")
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "list",
+            "Show this channel's leagues and the server default",
+        ))
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "add",
+                "Add a league to this channel, or replace one with the same name",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "league_id",
+                    "The FPL league ID",
+                )
+                .required(true),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "name",
+                    "Short name to pick the league by (defaults to the league's name)",
+                )
+                .max_length(MAX_NAME_LENGTH as u16),
+            )
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "primary",
+                "Make this the channel's primary league",
+            )),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "remove",
+                "Remove a league from this channel",
+            )
+            .add_sub_option(league_option().required(true)),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "primary",
+                "Make a league this channel's primary league",
+            )
+            .add_sub_option(league_option().required(true)),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "server_default",
+                "Set the league used by channels without leagues of their own",
+            )
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "league_id",
+                "The FPL league ID (leave out to clear)",
+            )),
+        )
+}
+
+/// Main handler for the `/leagues` slash command
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing the subcommand
+///
+/// # Returns
+/// * `Result<Reply>` - Summary of the channel's leagues or the change made
+///
+/// # Errors
+/// Returns error if:
+/// - The league does not exist on FPL
+/// - The named league is not configured in the channel
+/// - Database operations fail
+///
+/// # Example Usage
+/// `/leagues add league_id:123456 name:cup primary:false`
+/// `/leagues primary league:main`
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    let channel_id = command.channel_id;
+    let options = command.data.options();
+    let Some(ResolvedOption {
+        name,
+        value: ResolvedValue::SubCommand(sub_options),
+        ..
+    }) = options.first()
+    else {
+        return Err(anyhow!("Please choose a subcommand"));
+    };
+
+    let content = match *name {
+        "list" => describe_channel(command).await?,
+        "add" => {
+            let Some(ResolvedValue::Integer(league_id)) = option(sub_options, "league_id") else {
+                return Err(anyhow!("Please provide a league ID"));
+            };
+            let league_id = *league_id as i32;
+            let standings = LeagueStandings::fetch(league_id)
+                .await
+                .map_err(|_| anyhow!("Could not find FPL league {}", league_id))?;
+
+            let name = match option(sub_options, "name") {
+                Some(ResolvedValue::String(name)) => name.trim().to_string(),
+                _ => standings.league_info.league_name.clone(),
+            };
+            let name: String = name.chars().take(MAX_NAME_LENGTH).collect();
+            if name.is_empty() {
+                return Err(anyhow!("League names cannot be empty"));
+            }
+
+            let existing = db_service().get_channel_leagues(channel_id).await?;
+            // names match case insensitively, so keep the existing spelling
+            let name = existing
+                .iter()
+                .find(|l| l.name.eq_ignore_ascii_case(&name))
+                .map_or(name, |l| l.name.clone());
+            let is_primary = match option(sub_options, "primary") {
+                Some(ResolvedValue::Boolean(primary)) => *primary,
+                // the first league, or a replacement for the primary, stays primary
+                _ => existing
+                    .iter()
+                    .all(|l| !l.is_primary || l.name.eq_ignore_ascii_case(&name)),
+            };
+
+            info!(
+                "Adding league {} as {:?} to channel {} (primary: {})",
+                league_id, name, channel_id, is_primary
+            );
+            db_service()
+                .upsert_channel_league(&DBChannelLeague {
+                    channel_id: channel_id.into(),
+                    name: name.clone(),
+                    league_id,
+                    is_primary,
+                })
+                .await?;
+            audit(
+                command,
+                format!(
+                    "league {:?} set to {}{}",
+                    name,
+                    league_id,
+                    if is_primary { " (primary)" } else { "" }
+                ),
+            )
+            .await;
+            format!(
+                "Added {} ({}) to this channel as {:?}{}",
+                standings.league_info.league_name,
+                league_id,
+                name,
+                if is_primary {
+                    ", now the primary league"
+                } else {
+                    ""
+                }
+            )
+        }
+        "remove" => {
+            let league = find_league(command, sub_options).await?;
+            db_service()
+                .remove_channel_league(channel_id, &league.name)
+                .await?;
+            audit(
+                command,
+                format!("league {:?} ({}) removed", league.name, league.league_id),
+            )
+            .await;
+            format!("Removed {:?} from this channel", league.name)
+        }
+        "primary" => {
+            let league = DBChannelLeague {
+                is_primary: true,
+                ..find_league(command, sub_options).await?
+            };
+            db_service().upsert_channel_league(&league).await?;
+            audit(command, format!("primary league set to {:?}", league.name)).await;
+            format!("{:?} is now this channel's primary league", league.name)
+        }
+        "server_default" => {
+            let guild_id = command
+                .guild_id
+                .ok_or_else(|| anyhow!("Server defaults can only be set in a server"))?;
+            let league_id = match option(sub_options, "league_id") {
+                Some(ResolvedValue::Integer(id)) => Some(*id as i32),
+                _ => None,
+            };
+            if let Some(league_id) = league_id {
+                LeagueStandings::fetch(league_id)
+                    .await
+                    .map_err(|_| anyhow!("Could not find FPL league {}", league_id))?;
+            }
+
+            let previous = db_service().get_guild_settings(guild_id).await?;
+            db_service()
+                .update_guild_settings(&DBGuildSettings {
+                    default_league_id: league_id,
+                    ..previous
+                })
+                .await?;
+            let show = |id: Option<i32>| id.map_or("none".to_string(), |id| id.to_string());
+            audit(
+                command,
+                format!(
+                    "server default league {} -> {}",
+                    show(previous.default_league_id),
+                    show(league_id)
+                ),
+            )
+            .await;
+            match league_id {
+                Some(id) => format!("Channels without leagues now use league {}", id),
+                None => "Server default league cleared".to_string(),
+            }
+        }
+        other => return Err(anyhow!("Unknown subcommand {}", other)),
+    };
+
+    Ok(Reply::new().content(content))
+}
+
+/// Describes a channel's leagues and the guild's default league
+///
+/// # Arguments
+/// * `command` - The interaction whose channel and guild are described
+///
+/// # Errors
+/// Returns error if database operations fail
+pub async fn describe_channel(command: &CommandInteraction) -> Result<String> {
+    let leagues = db_service().get_channel_leagues(command.channel_id).await?;
+    let guild_default = match command.guild_id {
+        Some(guild_id) => {
+            db_service()
+                .get_guild_settings(guild_id)
+                .await?
+                .default_league_id
+        }
+        None => None,
+    };
+
+    let mut lines = Vec::new();
+    if leagues.is_empty() {
+        lines.push("This channel has no leagues. Use /leagues add to add one.".to_string());
+    } else {
+        lines.push("Leagues in this channel:".to_string());
+        for league in &leagues {
+            lines.push(format!(
+                "- {}: {}{}",
+                league.name,
+                league.league_id,
+                if league.is_primary { " (primary)" } else { "" }
+            ));
+        }
+    }
+    if let Some(id) = guild_default {
+        lines.push(format!("Server default league: {}", id));
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Finds a subcommand option by name.
+fn option<'a>(options: &'a [ResolvedOption<'a>], name: &str) -> Option<&'a ResolvedValue<'a>> {
+    options.iter().find(|o| o.name == name).map(|o| &o.value)
+}
+
+/// Looks up the channel league named by the `league` option
+async fn find_league(
+    command: &CommandInteraction,
+    options: &[ResolvedOption<'_>],
+) -> Result<DBChannelLeague> {
+    let Some(ResolvedValue::String(name)) = option(options, LEAGUE_OPTION) else {
+        return Err(anyhow!("Please choose a league"));
+    };
+    db_service()
+        .get_channel_leagues(command.channel_id)
+        .await?
+        .into_iter()
+        .find(|l| l.name.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| anyhow!("No league called {:?} in this channel", name))
+}
+
+/// The `/leagues` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct LeaguesCommand;
+
+#[async_trait]
+impl SlashCommand for LeaguesCommand {
+    fn name(&self) -> &'static str {
+        "leagues"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    fn access(&self) -> Access {
+        Access::Managers
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+
+    async fn autocomplete(&self, ctx: &Context, interaction: &CommandInteraction) {
+        autocomplete(ctx, interaction).await
+    }
+}
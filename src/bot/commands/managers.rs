@@ -0,0 +1,461 @@
+//! Manager linking commands
+//!
+//! Links Discord members to FPL managers. Every link is checked against the
+//! FPL API and confirmed with buttons showing the team and manager name, so a
+//! mistyped ID never gets stored. Server admins can link and unlink other
+//! members, and anyone can list who is linked in the server.
+
+use anyhow::{anyhow, Result};
+use log::{error, info};
+use serenity::all::{
+    ButtonStyle, CommandInteraction, CommandOptionType, ComponentInteraction, Context,
+    CreateInteractionResponse, CreateInteractionResponseMessage, GuildId, Http, ResolvedOption,
+    ResolvedValue, UserId,
+};
+use serenity::async_trait;
+use serenity::builder::{CreateButton, CreateCommand, CreateCommandOption, CreateEmbed};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::bot::custom_id::ComponentId;
+use crate::bot::permissions::{audit, audit_component, has_access, Access};
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::database::models::DBUser;
+use crate::database::service::db_service;
+use crate::fpl::client::MAX_CONCURRENT_REQUESTS;
+use crate::fpl::models::manager::Manager;
+use crate::utils::error::is_not_found;
+
+/// Characters of the linked managers list shown before it is cut short, leaving
+/// room in Discord's 4096 character embed description for the final line.
+const MAX_LIST_LENGTH: usize = 4000;
+
+/// Version of the link confirmation buttons, see [`ComponentId`].
+const COMPONENT_VERSION: u32 = 2;
+
+/// Registers the managers command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    let member = |description: &str| {
+        CreateCommandOption::new(CommandOptionType::User, "member", description)
+    };
+
+    CreateCommand::new("managers")
+        .description("Link Discord members to their FPL teams")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "link",
+                "Link an FPL manager ID to yourself, or to a member (admins)",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "manager_id",
+                    "The FPL manager ID",
+                )
+                .required(true),
+            )
+            .add_sub_option(member("The member to link (admins only)")),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "unlink",
+                "Remove your link, or a member's (admins)",
+            )
+            .add_sub_option(member("The member to unlink (admins only)")),
+        )
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "list",
+            "Show who is linked in this server",
+        ))
+}
+
+/// Main handler for the `/managers` slash command
+///
+/// Using the command in a server also records the caller's existing link
+/// there (see [`record_guild_link`]).
+///
+/// # Arguments
+/// * `ctx` - Discord context, used to check server membership when listing
+/// * `command` - The slash command interaction containing the subcommand
+///
+/// # Returns
+/// * `Result<Reply>` - A confirmation prompt, the unlink result, or the list
+///
+/// # Errors
+/// Returns error if:
+/// - A non-admin targets another member
+/// - The manager ID does not exist
+/// - FPL API or database operations fail
+///
+/// # Example Usage
+/// `/managers link manager_id:123456`
+/// `/managers link manager_id:123456 member:@Sam`
+/// `/managers list`
+pub async fn run(ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    record_guild_link(command.guild_id, command.user.id).await;
+
+    let options = command.data.options();
+    let Some(ResolvedOption {
+        name,
+        value: ResolvedValue::SubCommand(sub_options),
+        ..
+    }) = options.first()
+    else {
+        return Err(anyhow!("Please choose a subcommand"));
+    };
+
+    let target = match sub_options.iter().find(|o| o.name == "member") {
+        Some(ResolvedOption {
+            value: ResolvedValue::User(user, _),
+            ..
+        }) => user.id,
+        _ => command.user.id,
+    };
+    if target != command.user.id && !has_access(command, Access::Admins).await? {
+        return Err(anyhow!(
+            "Only {} can link or unlink other members",
+            Access::Admins.describe()
+        ));
+    }
+
+    match *name {
+        "link" => {
+            let manager_id = match sub_options.iter().find(|o| o.name == "manager_id") {
+                Some(ResolvedOption {
+                    value: ResolvedValue::Integer(id),
+                    ..
+                }) => *id as i32,
+                _ => return Err(anyhow!("Please provide a manager ID")),
+            };
+            link_prompt(command.user.id, target, manager_id).await
+        }
+        "unlink" => {
+            let removed = db_service().remove_user(target).await?;
+            if !removed {
+                return Ok(Reply::new()
+                    .content(format!("<@{}> is not linked to an FPL team", target))
+                    .ephemeral(true));
+            }
+            info!("Unlinked user {} (by {})", target, command.user.name);
+            if target != command.user.id {
+                audit(command, format!("unlinked <@{}>", target)).await;
+            }
+            Ok(Reply::new()
+                .content(format!("Unlinked <@{}> from their FPL team", target))
+                .ephemeral(true))
+        }
+        "list" => {
+            let guild_id = command
+                .guild_id
+                .ok_or_else(|| anyhow!("Linked managers can only be listed in a server"))?;
+            let users = guild_users(&ctx.http, guild_id).await?;
+            Ok(Reply::new().embed(build_list_embed(&users)))
+        }
+        other => Err(anyhow!("Unknown subcommand {}", other)),
+    }
+}
+
+/// Records an existing link in the guild the user is active in
+///
+/// Links made before they were recorded per guild only show in a server's
+/// listings once recorded, so linking commands call this for their caller.
+/// Failures are logged rather than returned.
+///
+/// # Arguments
+/// * `guild_id` - The guild the user is active in, if any
+/// * `user_id` - The Discord user, recorded only if they are linked
+pub async fn record_guild_link(guild_id: Option<GuildId>, user_id: UserId) {
+    let Some(guild_id) = guild_id else {
+        return;
+    };
+    let linked = db_service()
+        .get_user(user_id)
+        .await
+        .is_ok_and(|user| user.manager_id.is_some());
+    if !linked {
+        return;
+    }
+    if let Err(e) = db_service().add_guild_link(guild_id, user_id).await {
+        error!(
+            "Failed to record link of user {} in guild {}: {}",
+            user_id, guild_id, e
+        );
+    }
+}
+
+/// Lists the users linked in a guild
+///
+/// Users linked before links were recorded per guild are included if they
+/// are members of the guild, and their link is recorded there so they are
+/// found directly next time. At most [`MAX_CONCURRENT_REQUESTS`] memberships
+/// are checked at once.
+///
+/// # Arguments
+/// * `http` - Discord HTTP client, for membership checks
+/// * `guild_id` - The guild to list
+///
+/// # Returns
+/// * `Result<Vec<DBUser>>` - The guild's linked users, ordered by team name
+///
+/// # Errors
+/// Returns error if database operations fail
+pub async fn guild_users(http: &Arc<Http>, guild_id: GuildId) -> Result<Vec<DBUser>> {
+    let mut users = db_service().get_guild_users(guild_id).await?;
+    let unscoped = db_service().get_unscoped_users().await?;
+    if unscoped.is_empty() {
+        return Ok(users);
+    }
+
+    let limit = Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS));
+    let mut requests = JoinSet::new();
+    for user in unscoped {
+        let limit = limit.clone();
+        let http = http.clone();
+        requests.spawn(async move {
+            let _permit = limit.acquire_owned().await;
+            let user_id = UserId::new(user.discord_id as u64);
+            let is_member = guild_id.member(http.as_ref(), user_id).await.is_ok();
+            (user, is_member)
+        });
+    }
+    while let Some(joined) = requests.join_next().await {
+        match joined {
+            Ok((user, true)) => {
+                let user_id = UserId::new(user.discord_id as u64);
+                db_service().add_guild_link(guild_id, user_id).await?;
+                users.push(user);
+            }
+            Ok((_, false)) => {}
+            Err(e) => error!("Guild membership check panicked: {}", e),
+        }
+    }
+    users.sort_by(|a, b| a.team_name.cmp(&b.team_name));
+    Ok(users)
+}
+
+/// Looks up a manager and asks the invoking user to confirm the link
+///
+/// # Arguments
+/// * `requester` - The Discord user asking for the link, the only one whose
+///   confirmation is accepted
+/// * `target` - The Discord user the manager will be linked to
+/// * `manager_id` - The FPL manager ID to link
+///
+/// # Returns
+/// * `Result<Reply>` - Ephemeral prompt with the team details and
+///   confirm/cancel buttons
+///
+/// # Errors
+/// Returns error if the manager does not exist or the FPL API request fails
+pub async fn link_prompt(requester: UserId, target: UserId, manager_id: i32) -> Result<Reply> {
+    let manager = fetch_manager(manager_id).await?;
+
+    let embed = CreateEmbed::new()
+        .title("Link this FPL team?")
+        .description(format!("For <@{}>", target))
+        .field("Team", &manager.team_name, true)
+        .field("Manager", manager.full_name(), true)
+        .field("Manager ID", manager_id.to_string(), true)
+        .color(0x37003c); // purple
+
+    Ok(Reply::new()
+        .embed(embed)
+        .button(
+            CreateButton::new(
+                ComponentId::new("managers", COMPONENT_VERSION, "confirm")
+                    .user(target.get())
+                    .requester(requester.get())
+                    .manager(manager_id)
+                    .encode()?,
+            )
+            .label("Link")
+            .style(ButtonStyle::Success),
+        )
+        .button(
+            CreateButton::new(
+                ComponentId::new("managers", COMPONENT_VERSION, "cancel")
+                    .requester(requester.get())
+                    .encode()?,
+            )
+            .label("Cancel")
+            .style(ButtonStyle::Secondary),
+        )
+        .ephemeral(true))
+}
+
+/// Fetches a manager, turning a 404 into a message the user can act on
+pub async fn fetch_manager(manager_id: i32) -> Result<Manager> {
+    Manager::fetch(manager_id).await.map_err(|e| {
+        if is_not_found(&e) {
+            anyhow!(
+                "There is no FPL team with ID {}. Your ID is the number after /entry/ in the address of your Points page.",
+                manager_id
+            )
+        } else {
+            error!("Failed to fetch manager {}: {}", manager_id, e);
+            anyhow!("Could not check manager {} with FPL, try again later", manager_id)
+        }
+    })
+}
+
+/// Handles the confirm and cancel buttons of a link prompt
+///
+/// Only the member who asked for the link may use the buttons; anyone else is
+/// told so privately and the prompt is left as it is.
+///
+/// # Custom ID Format
+/// A [`ComponentId`] for `managers` with action `confirm`, carrying the
+/// Discord user and manager IDs, or `cancel`, both carrying the requester
+pub async fn handle_component(ctx: &Context, component: &ComponentInteraction) {
+    let id = match ComponentId::parse(&component.data.custom_id, "managers", COMPONENT_VERSION) {
+        Ok(id) => id,
+        Err(e) => return reject(ctx, component, e.to_string()).await,
+    };
+    match id.state.requester_id {
+        Some(requester) if requester == component.user.id.get() => {}
+        Some(requester) => {
+            let message = format!("Only <@{}> can answer this link prompt", requester);
+            return reject(ctx, component, message).await;
+        }
+        None => return reject(ctx, component, "Unknown link prompt".to_string()).await,
+    }
+
+    let reply = match confirm_link(component, &id).await {
+        Ok(content) => content,
+        Err(e) => format!("Error: {}", e),
+    };
+
+    let response = CreateInteractionResponse::UpdateMessage(
+        CreateInteractionResponseMessage::new()
+            .content(reply)
+            .embeds(vec![])
+            .components(vec![]),
+    );
+    if let Err(why) = component.create_response(&ctx.http, response).await {
+        info!("Cannot update link prompt: {}", why);
+    }
+}
+
+/// Tells the member who pressed a button privately why it was not handled
+async fn reject(ctx: &Context, component: &ComponentInteraction, message: String) {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(format!("Error: {}", message))
+            .ephemeral(true),
+    );
+    if let Err(why) = component.create_response(&ctx.http, response).await {
+        info!("Cannot reject link prompt button: {}", why);
+    }
+}
+
+/// Stores a confirmed link, returning the message to show
+async fn confirm_link(component: &ComponentInteraction, id: &ComponentId) -> Result<String> {
+    let (target, manager_id) = match (id.action.as_str(), id.state.user_id, id.state.manager_id) {
+        ("cancel", _, _) => return Ok("Link cancelled".to_string()),
+        ("confirm", Some(target), Some(manager_id)) => (UserId::new(target), manager_id),
+        _ => return Err(anyhow!("Unknown link action")),
+    };
+
+    let manager = fetch_manager(manager_id).await?;
+    db_service()
+        .update_user(&DBUser {
+            discord_id: i64::from(target),
+            manager_id: Some(manager_id),
+            team_name: Some(manager.team_name.clone()),
+            manager_name: Some(manager.full_name()),
+        })
+        .await?;
+    if let Some(guild_id) = component.guild_id {
+        db_service().add_guild_link(guild_id, target).await?;
+    }
+
+    info!(
+        "Linked user {} to manager {} (by {})",
+        target, manager_id, component.user.name
+    );
+    if target != component.user.id {
+        audit_component(
+            component,
+            "managers",
+            format!("linked <@{}> to manager {}", target, manager_id),
+        )
+        .await;
+    }
+
+    Ok(format!(
+        "Linked <@{}> to **{}** ({})",
+        target,
+        manager.team_name,
+        manager.full_name()
+    ))
+}
+
+/// Lists the members linked in a server
+fn build_list_embed(users: &[DBUser]) -> CreateEmbed {
+    let description = if users.is_empty() {
+        "Nobody is linked yet. Use /managers link to link your FPL team.".to_string()
+    } else {
+        let mut lines = Vec::new();
+        let mut length = 0;
+        for (i, user) in users.iter().enumerate() {
+            let line = format!(
+                "<@{}> - {} ({}) • {}",
+                user.discord_id,
+                user.team_name.as_deref().unwrap_or("Unknown team"),
+                user.manager_name.as_deref().unwrap_or("unknown manager"),
+                user.manager_id.unwrap_or_default()
+            );
+            length += line.len() + 1;
+            if length > MAX_LIST_LENGTH {
+                lines.push(format!("…and {} more", users.len() - i));
+                break;
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    };
+
+    CreateEmbed::new()
+        .title(format!("Linked managers ({})", users.len()))
+        .description(description)
+        .color(0x37003c) // purple
+}
+
+/// The `/managers` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct ManagersCommand;
+
+#[async_trait]
+impl SlashCommand for ManagersCommand {
+    fn name(&self) -> &'static str {
+        "managers"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+
+    fn component_prefixes(&self) -> &'static [&'static str] {
+        &["managers:"]
+    }
+
+    async fn handle_component(&self, ctx: &Context, component: &ComponentInteraction) {
+        handle_component(ctx, component).await
+    }
+}
@@ -0,0 +1,473 @@
+//! Match report command implementation
+//!
+//! Provides the `/match` slash command, which renders a detailed report for a
+//! single fixture: the scoreline, every stat FPL records for the match, the top
+//! BPS performers and the FPL points each participant earned from it.
+
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use log::{error, info};
+use serenity::all::{
+    ButtonStyle, CommandInteraction, ComponentInteraction, Context, CreateAutocompleteResponse,
+    CreateInteractionResponse, CreateInteractionResponseFollowup, CreateInteractionResponseMessage,
+    ResolvedOption, ResolvedValue,
+};
+use serenity::async_trait;
+use serenity::builder::{CreateButton, CreateCommand, CreateCommandOption, CreateEmbed};
+use serenity::model::application::CommandOptionType;
+
+use crate::bot::custom_id::ComponentId;
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::fpl::models::fixtures::{fetch_all_fixtures, Fixture, StatEntry};
+use crate::fpl::models::gameweek::{update_gameweek_info, GameweekResponse};
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::teams::get_team_name;
+use crate::utils::cache::TtlCache;
+
+const MAX_AUTOCOMPLETE_CHOICES: usize = 25;
+const TOP_BPS_COUNT: usize = 6;
+
+/// Version of the refresh button, see [`ComponentId`].
+const COMPONENT_VERSION: u32 = 1;
+
+/// How long the fixture list is reused for autocomplete suggestions, so
+/// typing a team name does not fetch it on every keystroke.
+const AUTOCOMPLETE_TTL: Duration = Duration::from_secs(60);
+
+/// The fixture list last fetched for autocomplete.
+static AUTOCOMPLETE_FIXTURES: OnceLock<TtlCache<(), Arc<Vec<Fixture>>>> = OnceLock::new();
+
+/// The full fixture list for autocomplete, reusing a recent fetch
+async fn autocomplete_fixtures() -> Result<Arc<Vec<Fixture>>> {
+    let cache = AUTOCOMPLETE_FIXTURES.get_or_init(|| TtlCache::new(AUTOCOMPLETE_TTL, 1));
+    if let Some(fixtures) = cache.get(&()) {
+        return Ok(fixtures);
+    }
+
+    let fixtures = Arc::new(fetch_all_fixtures().await?);
+    cache.insert((), fixtures.clone());
+    Ok(fixtures)
+}
+
+/// Registers the match command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("match")
+        .description("Detailed report for a single fixture")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "fixture",
+                "Fixture ID, or start typing a team name",
+            )
+            .set_autocomplete(true)
+            .required(true),
+        )
+}
+
+/// Main handler for the `/match` slash command
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing the fixture ID
+///
+/// # Returns
+/// * `Result<Reply>` - Discord response with the match report,
+///   plus a refresh button while the match is in progress
+///
+/// # Errors
+/// Returns error if the fixture does not exist or the FPL API request fails
+///
+/// # Example Usage
+/// `/match fixture:123`
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    let fixture_id = match command.data.options().first() {
+        Some(ResolvedOption {
+            value: ResolvedValue::Integer(id),
+            ..
+        }) => *id as u32,
+        _ => return Err(anyhow!("Please provide a fixture")),
+    };
+    info!(
+        "Processing match command for fixture {} (user {})",
+        fixture_id, command.user.name
+    );
+
+    build_match_message(fixture_id).await
+}
+
+/// Suggests fixtures while the user is typing the `fixture` option
+///
+/// Matches on fixture ID prefix or team name. With no input, suggests the
+/// fixtures closest to kickoff, live matches first. Scores in the suggestions
+/// may be up to [`AUTOCOMPLETE_TTL`] old.
+///
+/// # Arguments
+/// * `ctx` - Discord context for sending the response
+/// * `interaction` - The autocomplete interaction
+pub async fn autocomplete(ctx: &Context, interaction: &CommandInteraction) {
+    let query = interaction
+        .data
+        .autocomplete()
+        .map(|opt| opt.value.trim().to_lowercase())
+        .unwrap_or_default();
+
+    let fixtures = match autocomplete_fixtures().await {
+        Ok(fixtures) => fixtures,
+        Err(e) => {
+            error!("Failed to fetch fixtures for match autocomplete: {}", e);
+            return;
+        }
+    };
+
+    let now = Utc::now();
+    let mut candidates: Vec<&Fixture> = fixtures
+        .iter()
+        .filter(|f| f.event.is_some() && matches_query(f, &query))
+        .collect();
+    candidates.sort_by_key(|f| {
+        let distance = f
+            .kickoff_time
+            .map(|t| (t - now).num_minutes().abs())
+            .unwrap_or(i64::MAX);
+        (!f.is_live(), distance)
+    });
+
+    let mut response = CreateAutocompleteResponse::new();
+    for fixture in candidates.into_iter().take(MAX_AUTOCOMPLETE_CHOICES) {
+        response = response.add_int_choice(choice_label(fixture), i64::from(fixture.id));
+    }
+
+    if let Err(why) = interaction
+        .create_response(&ctx.http, CreateInteractionResponse::Autocomplete(response))
+        .await
+    {
+        info!("Cannot respond to match autocomplete: {}", why);
+    }
+}
+
+/// Handles the refresh button on a match report
+///
+/// The interaction is deferred before the report is rebuilt, since that takes
+/// several FPL requests, and the message is then edited in place.
+///
+/// # Custom ID Format
+/// A [`ComponentId`] for `match` with action `refresh`, carrying the fixture ID
+pub async fn handle_component(ctx: &Context, component: &ComponentInteraction) {
+    let fixture_id = match ComponentId::parse(&component.data.custom_id, "match", COMPONENT_VERSION)
+        .and_then(|id| {
+            id.state
+                .fixture_id
+                .ok_or_else(|| anyhow!("Could not determine fixture"))
+        }) {
+        Ok(id) => id,
+        Err(e) => {
+            let response = CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(format!("Error: {}", e))
+                    .ephemeral(true),
+            );
+            if let Err(why) = component.create_response(&ctx.http, response).await {
+                info!("Cannot report match report error: {}", why);
+            }
+            return;
+        }
+    };
+
+    if let Err(why) = component.defer(&ctx.http).await {
+        info!("Cannot defer match report refresh: {}", why);
+        return;
+    }
+    match build_match_message(fixture_id).await {
+        Ok(reply) => {
+            if let Err(why) = component.edit_response(&ctx.http, reply.into_edit()).await {
+                info!("Cannot update match report: {}", why);
+            }
+        }
+        Err(e) => {
+            let followup = CreateInteractionResponseFollowup::new()
+                .content(format!("Error refreshing match: {}", e))
+                .ephemeral(true);
+            if let Err(why) = component.create_followup(&ctx.http, followup).await {
+                info!("Cannot report match report error: {}", why);
+            }
+        }
+    }
+}
+
+fn matches_query(fixture: &Fixture, query: &str) -> bool {
+    if query.is_empty() {
+        return true;
+    }
+    if query.chars().all(|c| c.is_ascii_digit()) {
+        return fixture.id.to_string().starts_with(query);
+    }
+    [fixture.team_h, fixture.team_a].iter().any(|team| {
+        let name = get_team_name(*team);
+        name.name.to_lowercase().contains(query) || name.short_name.to_lowercase() == query
+    })
+}
+
+fn choice_label(fixture: &Fixture) -> String {
+    let home = get_team_name(fixture.team_h).name;
+    let away = get_team_name(fixture.team_a).name;
+    let gw = fixture
+        .event
+        .map(|e| format!("GW{}", e))
+        .unwrap_or_default();
+    match (fixture.started, fixture.team_h_score, fixture.team_a_score) {
+        (true, Some(h), Some(a)) => format!("{} {} {}-{} {}", gw, home, h, a, away),
+        _ => format!("{} {} v {}", gw, home, away),
+    }
+}
+
+/// Fetches everything needed for a report and builds the message
+async fn build_match_message(fixture_id: u32) -> Result<Reply> {
+    let fixture = fetch_all_fixtures()
+        .await?
+        .into_iter()
+        .find(|f| f.id == fixture_id)
+        .ok_or_else(|| anyhow!("Fixture {} not found", fixture_id))?;
+
+    let general = GeneralData::fetch().await?;
+    let live = match fixture.event {
+        Some(gw) if fixture.started => Some(update_gameweek_info(i32::from(gw)).await?),
+        _ => None,
+    };
+
+    let mut message = Reply::new().embed(build_match_embed(&fixture, live.as_ref(), &general));
+    if fixture.is_live() {
+        message = message.button(
+            CreateButton::new(
+                ComponentId::new("match", COMPONENT_VERSION, "refresh")
+                    .fixture(fixture.id)
+                    .encode()?,
+            )
+            .label("🔄 Refresh")
+            .style(ButtonStyle::Primary),
+        );
+    } else {
+        message = message.components(vec![]);
+    }
+    Ok(message)
+}
+
+/// Human readable label for a fixture stat identifier
+fn stat_label(identifier: &str) -> String {
+    match identifier {
+        "goals_scored" => "⚽ Goals".to_string(),
+        "assists" => "🅰️ Assists".to_string(),
+        "own_goals" => "🥅 Own goals".to_string(),
+        "penalties_saved" => "🧤 Penalties saved".to_string(),
+        "penalties_missed" => "❌ Penalties missed".to_string(),
+        "yellow_cards" => "🟨 Yellow cards".to_string(),
+        "red_cards" => "🟥 Red cards".to_string(),
+        "saves" => "🧤 Saves".to_string(),
+        "bonus" => "⭐ Bonus".to_string(),
+        "bps" => "📊 BPS".to_string(),
+        other => {
+            let mut label = other.replace('_', " ");
+            if let Some(first) = label.get_mut(0..1) {
+                first.make_ascii_uppercase();
+            }
+            label
+        }
+    }
+}
+
+fn format_entries(entries: &[StatEntry], general: &GeneralData) -> String {
+    entries
+        .iter()
+        .map(|entry| {
+            let name = general.player_name(entry.element);
+            if entry.value > 1 {
+                format!("{} ({})", name, entry.value)
+            } else {
+                name.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Builds the match report embed
+///
+/// # Arguments
+/// * `fixture` - The fixture to report on
+/// * `live` - Live gameweek data, if the match has started
+/// * `general` - Bootstrap data for resolving player names and teams
+pub fn build_match_embed(
+    fixture: &Fixture,
+    live: Option<&GameweekResponse>,
+    general: &GeneralData,
+) -> CreateEmbed {
+    let home = get_team_name(fixture.team_h);
+    let away = get_team_name(fixture.team_a);
+
+    let title = match (fixture.team_h_score, fixture.team_a_score) {
+        (Some(h), Some(a)) if fixture.started => {
+            format!("{} {} - {} {}", home.name, h, a, away.name)
+        }
+        _ => format!("{} v {}", home.name, away.name),
+    };
+    let status = if fixture.is_over() {
+        "Full time".to_string()
+    } else if fixture.started {
+        format!("🔴 Live · {}'", fixture.minutes)
+    } else {
+        fixture
+            .kickoff_time
+            .map(|t| format!("Kickoff {}", t.format("%d.%m %H:%M")))
+            .unwrap_or_else(|| "Kickoff TBC".to_string())
+    };
+
+    let mut embed = CreateEmbed::new()
+        .title(title)
+        .description(status)
+        .color(0x37003c)
+        .footer(serenity::builder::CreateEmbedFooter::new(format!(
+            "Fixture ID: {} • GW{}",
+            fixture.id,
+            fixture
+                .event
+                .map(|e| e.to_string())
+                .unwrap_or_else(|| "?".to_string())
+        )));
+
+    for stat in fixture.stats.iter().filter(|s| s.identifier != "bps") {
+        if stat.h.is_empty() && stat.a.is_empty() {
+            continue;
+        }
+        let mut value = String::new();
+        if !stat.h.is_empty() {
+            value.push_str(&format!(
+                "**{}:** {}\n",
+                home.short_name,
+                format_entries(&stat.h, general)
+            ));
+        }
+        if !stat.a.is_empty() {
+            value.push_str(&format!(
+                "**{}:** {}",
+                away.short_name,
+                format_entries(&stat.a, general)
+            ));
+        }
+        embed = embed.field(stat_label(&stat.identifier), value, false);
+    }
+
+    if let Some(bps) = fixture.stat("bps") {
+        let mut top: Vec<&StatEntry> = bps.h.iter().chain(bps.a.iter()).collect();
+        top.sort_by_key(|entry| std::cmp::Reverse(entry.value));
+        let value = top
+            .iter()
+            .take(TOP_BPS_COUNT)
+            .map(|entry| format!("{} {}", general.player_name(entry.element), entry.value))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if !value.is_empty() {
+            embed = embed.field(stat_label("bps"), value, false);
+        }
+    }
+
+    if let Some(live) = live {
+        let points = fixture_points(fixture, live, general);
+        for (team, short_name) in [
+            (fixture.team_h, home.short_name),
+            (fixture.team_a, away.short_name),
+        ] {
+            let rows: Vec<String> = points
+                .iter()
+                .filter(|(_, player_team, _)| *player_team == team)
+                .map(|(name, _, pts)| format!("{:<14} {:>3}", name, pts))
+                .collect();
+            if !rows.is_empty() {
+                embed = embed.field(
+                    format!("{} FPL points", short_name),
+                    format!("```\n{}```", rows.join("\n")),
+                    true,
+                );
+            }
+        }
+    }
+
+    embed
+}
+
+/// FPL points each participant earned from this fixture
+///
+/// Sums the `explain` breakdown entries belonging to the fixture, so players in
+/// a double gameweek are only credited with the points from this match.
+///
+/// # Returns
+/// `(name, team_id, points)` for every player who appeared, highest first
+fn fixture_points(
+    fixture: &Fixture,
+    live: &GameweekResponse,
+    general: &GeneralData,
+) -> Vec<(String, i32, i32)> {
+    let mut points: Vec<(String, i32, i32)> = live
+        .elements
+        .iter()
+        .filter_map(|element| {
+            let explain = element
+                .explain
+                .iter()
+                .find(|e| e.fixture == fixture.id as i32)?;
+            let played = explain
+                .stats
+                .iter()
+                .any(|s| s.identifier == "minutes" && s.value > 0);
+            if !played {
+                return None;
+            }
+            let player = general.player(element.id)?;
+            let total = explain.stats.iter().map(|s| s.points).sum();
+            let name: String = player.web_name.chars().take(14).collect();
+            Some((name, player.team, total))
+        })
+        .collect();
+    points.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+    points
+}
+
+/// The `/match` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct MatchReportCommand;
+
+#[async_trait]
+impl SlashCommand for MatchReportCommand {
+    fn name(&self) -> &'static str {
+        "match"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+
+    async fn autocomplete(&self, ctx: &Context, interaction: &CommandInteraction) {
+        autocomplete(ctx, interaction).await
+    }
+
+    fn component_prefixes(&self) -> &'static [&'static str] {
+        &["match:"]
+    }
+
+    async fn handle_component(&self, ctx: &Context, component: &ComponentInteraction) {
+        handle_component(ctx, component).await
+    }
+}
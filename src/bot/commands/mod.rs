@@ -5,23 +5,98 @@
 //!
 //! # Available Commands
 //!
+//! * [`hello`] - Check that the bot is responding
 //! * [`standings`] - Display league standings with interactive pagination
-//! * [`manager`] - Manager-related commands for viewing and updating manager information
+//! * [`managers`] - Verified linking of Discord members to FPL managers
 //! * [`player`] - Player statistics and information commands
-//! * [`track_fixture`] - Track and get notifications for specific fixtures
+//! * [`track_fixture`] - Track and get notifications for specific fixtures (not yet served)
 //! * [`fixtures`] - Display gameweek fixtures with scores and details
+//! * [`fdr`] - Fixture difficulty ticker across upcoming gameweeks
+//! * [`calendar`] - Blank and double gameweeks for the season
+//! * [`leagues`] - Named leagues per channel and the server default league
+//! * [`league_members`] - Linked and unlinked league entries, matched to members by admins
+//! * [`schedule_alerts`] - Opt a channel in to fixture reschedule alerts
+//! * [`match_report`] - Detailed report and FPL points for a single fixture
+//! * [`dreamteam`] - Team of the week and the best XI owned within a league
+//! * [`chips`] - Chips played and remaining per league manager, with gameweek chip returns
+//! * [`captain_poll`] - Captain votes before the deadline, scored into a season leaderboard
+//! * [`predictions`] - Fixture score prediction game with a season leaderboard
+//! * [`projections`] - Transparent projected points per player, with a backtest
+//! * [`suggest_transfers`] - Transfers that add the most projected points to a linked squad
+//! * [`build_squad`] - Best projected wildcard or free hit squad within a budget
+//! * [`optimize_lineup`] - Best projected XI, bench order and captaincy for a manager's squad
+//! * [`league_odds`] - Simulated chances of winning, top 3 and last place in a league
+//! * [`command_admin`] - Admin-only re-sync and purge of the bot's slash commands
+//! * [`permissions`] - Who may change settings, bot admin roles and the audit log
 //!
 //! Each command module provides:
 //! - Command registration function for Discord
 //! - Main command execution handler
 //! - Helper functions for data processing and response formatting
+//! - A [`SlashCommand`] implementation, listed in [`all`] to be served by the bot
 
+pub mod build_squad;
+pub mod calendar;
+pub mod captain_poll;
 pub mod check_channel_league_id;
 pub mod check_manager_id;
 pub mod check_team;
+pub mod chips;
+pub mod command_admin;
+pub mod dreamteam;
+pub mod fdr;
 pub mod fixtures;
+pub mod hello;
+pub mod league_members;
+pub mod league_odds;
+pub mod leagues;
+pub mod managers;
+pub mod match_report;
+pub mod optimize_lineup;
+pub mod permissions;
 pub mod player;
+pub mod predictions;
+pub mod projections;
+pub mod schedule_alerts;
 pub mod standings;
+pub mod suggest_transfers;
 pub mod track_fixture;
 pub mod update_channel_league_id;
 pub mod update_manager_id;
+
+use crate::bot::registry::SlashCommand;
+
+/// Every command the bot serves, in the order they are registered with Discord
+///
+/// This is the single list the event handler builds its
+/// [`CommandRegistry`](crate::bot::registry::CommandRegistry) from.
+pub fn all() -> Vec<Box<dyn SlashCommand>> {
+    vec![
+        Box::new(hello::HelloCommand),
+        Box::new(standings::StandingsCommand),
+        Box::new(fixtures::FixturesCommand),
+        Box::new(update_manager_id::UpdateManagerIdCommand),
+        Box::new(check_manager_id::CheckManagerIdCommand),
+        Box::new(managers::ManagersCommand),
+        Box::new(update_channel_league_id::UpdateChannelLeagueIdCommand),
+        Box::new(check_channel_league_id::CheckChannelLeagueIdCommand),
+        Box::new(leagues::LeaguesCommand),
+        Box::new(league_members::LeagueMembersCommand),
+        Box::new(check_team::CheckTeamCommand),
+        Box::new(fdr::FdrCommand),
+        Box::new(calendar::CalendarCommand),
+        Box::new(schedule_alerts::ScheduleAlertsCommand),
+        Box::new(match_report::MatchReportCommand),
+        Box::new(dreamteam::DreamteamCommand),
+        Box::new(chips::ChipsCommand),
+        Box::new(captain_poll::CaptainPollCommand),
+        Box::new(predictions::PredictionsCommand),
+        Box::new(projections::ProjectionsCommand),
+        Box::new(suggest_transfers::SuggestTransfersCommand),
+        Box::new(build_squad::BuildSquadCommand),
+        Box::new(optimize_lineup::OptimizeLineupCommand),
+        Box::new(league_odds::LeagueOddsCommand),
+        Box::new(command_admin::CommandAdminCommand),
+        Box::new(permissions::PermissionsCommand),
+    ]
+}
@@ -0,0 +1,333 @@
+//! Optimize lineup command implementation
+//!
+//! Provides the `/optimize_lineup` slash command, which picks the best XI,
+//! bench order, captain and vice-captain from a manager's fifteen players for
+//! the next gameweek using projected points, so minutes risk, doubles and
+//! blanks are all accounted for, and shows what the changes gain over the
+//! manager's current setup.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use log::info;
+use serenity::all::{CommandInteraction, Context, ResolvedOption, ResolvedValue};
+use serenity::async_trait;
+use serenity::builder::{
+    CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter,
+};
+use serenity::model::application::CommandOptionType;
+
+use crate::bot::commands::suggest_transfers::opponents;
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::database::{models::DBUser, service::db_service};
+use crate::fpl::models::fixtures::{fetch_all_fixtures, Fixture};
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::manager::Manager;
+use crate::fpl::models::picks::ManagerPicks;
+use crate::fpl::projection::project;
+use crate::fpl::squad::{best_xi, captained_score, Candidate, Lineup, Position};
+
+/// Registers the optimize lineup command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("optimize_lineup")
+        .description("Best XI, bench order and captaincy for the next gameweek")
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::Integer, "manager_id", "FPL Manager ID")
+                .required(false),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::User, "user", "Discord User to check")
+                .required(false),
+        )
+}
+
+/// Main handler for the `/optimize_lineup` slash command
+///
+/// Projects each of the manager's fifteen players for the next gameweek,
+/// picks the best legal XI with the two highest projected starters as captain
+/// and vice, and compares it with the XI and captain currently set.
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing user input
+///
+/// # Returns
+/// * `Result<Reply>` - Discord response with the recommended lineup
+///
+/// # Errors
+/// Returns error if:
+/// - The season has not started or has no gameweeks left
+/// - The squad has no legal XI, e.g. players missing from FPL's data
+/// - FPL API requests fail
+///
+/// # Example Usage
+/// `/optimize_lineup`
+/// `/optimize_lineup user:@friend`
+/// `/optimize_lineup manager_id:123456`
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    let db = db_service();
+
+    let options = command.data.options();
+    let manager_id_opt = options.iter().find(|opt| opt.name == "manager_id");
+    let user_opt = options.iter().find(|opt| opt.name == "user");
+
+    let manager_id = match (manager_id_opt, user_opt) {
+        (
+            Some(ResolvedOption {
+                value: ResolvedValue::Integer(id),
+                ..
+            }),
+            _,
+        ) => *id as i32,
+        (
+            _,
+            Some(ResolvedOption {
+                value: ResolvedValue::User(user, _),
+                ..
+            }),
+        ) => {
+            let db_user: DBUser = db.get_user(user.id).await?;
+            match db_user.manager_id {
+                Some(id) => id,
+                None => {
+                    return Ok(Reply::new().content(format!(
+                        "User {} has not linked their FPL manager ID.",
+                        user.name
+                    )));
+                }
+            }
+        }
+        _ => {
+            let user: DBUser = db.get_user(command.user.id).await?;
+            match user.manager_id {
+                Some(id) => id,
+                None => {
+                    return Ok(Reply::new().content(
+                        "No manager_id configured for you. Use /update_manager_id please!",
+                    ));
+                }
+            }
+        }
+    };
+
+    let general = GeneralData::fetch().await?;
+    let current = general
+        .current_event()
+        .ok_or_else(|| anyhow!("The season has not started yet"))?
+        .id;
+    let next = general
+        .next_event()
+        .ok_or_else(|| anyhow!("There are no gameweeks left to pick a lineup for"))?
+        .id;
+    info!(
+        "Optimizing GW{} lineup for manager {} (user {})",
+        next, manager_id, command.user.name
+    );
+
+    let (picks, manager, fixtures) = tokio::try_join!(
+        ManagerPicks::fetch_upcoming(manager_id, current),
+        Manager::fetch(manager_id),
+        fetch_all_fixtures(),
+    )?;
+
+    let projections: HashMap<i32, f64> = project(&general, &fixtures, next..=next)
+        .into_iter()
+        .map(|projection| (projection.element, projection.points(next)))
+        .collect();
+    let squad: Vec<Candidate> = picks
+        .picks
+        .iter()
+        .filter_map(|pick| {
+            let player = general.player(pick.element)?;
+            Some(Candidate {
+                id: player.id,
+                position: Position::from_element_type(player.element_type)?,
+                score: projections.get(&player.id).copied().unwrap_or(0.0),
+            })
+        })
+        .collect();
+    let optimal =
+        best_xi(&squad).ok_or_else(|| anyhow!("No legal XI could be picked from this squad"))?;
+
+    let current_xi: Vec<Candidate> = squad
+        .iter()
+        .filter(|c| picks.is_starter(c.id))
+        .cloned()
+        .collect();
+    let setup = Setup {
+        starters: current_xi,
+        captain: picks.captain(),
+    };
+
+    let embed = build_embed(&optimal, &setup, &manager, &general, &fixtures, next);
+    Ok(Reply::new().embed(embed))
+}
+
+/// The XI and captain a manager currently has set
+struct Setup {
+    starters: Vec<Candidate>,
+
+    captain: Option<i32>,
+}
+
+/// Builds the embed: the optimal XI row by row with its bench order, the
+/// changes from the current setup and the projected gain
+fn build_embed(
+    optimal: &Lineup,
+    setup: &Setup,
+    manager: &Manager,
+    general: &GeneralData,
+    fixtures: &[Fixture],
+    gameweek: i32,
+) -> CreateEmbed {
+    let (captain, vice) = optimal.captains();
+    let name = |candidate: &Candidate| {
+        let armband = if Some(candidate.id) == captain {
+            " (C)"
+        } else if Some(candidate.id) == vice {
+            " (V)"
+        } else {
+            ""
+        };
+        format!(
+            "{}{} {:.1}{}",
+            general.player_name(candidate.id),
+            armband,
+            candidate.score,
+            flags(candidate.id, general, fixtures, gameweek)
+        )
+    };
+
+    let mut rows: Vec<String> = Position::ALL
+        .into_iter()
+        .map(|position| {
+            let players: Vec<String> = optimal.starters_in(position).map(name).collect();
+            format!("**{}** {}", position, players.join(" • "))
+        })
+        .collect();
+    let bench: Vec<String> = optimal
+        .bench
+        .iter()
+        .enumerate()
+        .map(|(i, c)| format!("{}. {}", i + 1, name(c)))
+        .collect();
+    rows.push(String::new());
+    rows.push(format!("**Bench** {}", bench.join(" • ")));
+
+    let projected = captained_score(&optimal.starters, captain);
+    let current = captained_score(&setup.starters, setup.captain);
+    let changes = changes(optimal, setup, captain, general);
+
+    CreateEmbed::new()
+        .author(CreateEmbedAuthor::new(format!(
+            "{} ({})",
+            manager.team_name,
+            manager.full_name()
+        )))
+        .title(format!("🧠 Optimal lineup for GW{}", gameweek))
+        .description(rows.join("\n"))
+        .field("Formation", optimal.formation(), true)
+        .field("Projected", format!("{:.1} pts", projected), true)
+        .field(
+            "Gain",
+            format!("{:+.1} pts on {:.1}", projected - current, current),
+            true,
+        )
+        .field(
+            "Changes",
+            if changes.is_empty() {
+                "None, the current setup is already the best".to_string()
+            } else {
+                changes.join("\n")
+            },
+            false,
+        )
+        .color(0x00ff87) // green
+        .footer(CreateEmbedFooter::new(
+            "Projections include minutes risk, doubles and blanks • Gain is before automatic substitutions",
+        ))
+}
+
+/// Warnings for a player's next gameweek, e.g. " ⚠️75%", " blank" or
+/// " 2x BUR (H) + WOL (A)"
+fn flags(element: i32, general: &GeneralData, fixtures: &[Fixture], gameweek: i32) -> String {
+    let mut flags = String::new();
+    if let Some(player) = general.player(element) {
+        if let Some(chance) = player.chance_of_playing_next_round.filter(|c| *c < 100) {
+            flags.push_str(&format!(" ⚠️{}%", chance));
+        }
+        let opponents = opponents(player.team, gameweek, fixtures);
+        match opponents.len() {
+            0 => flags.push_str(" blank"),
+            1 => {}
+            n => flags.push_str(&format!(" {}x {}", n, opponents.join(" + "))),
+        }
+    }
+    flags
+}
+
+/// The moves from the current setup to the optimal one: who comes into and
+/// drops out of the XI, and any new captain
+fn changes(
+    optimal: &Lineup,
+    setup: &Setup,
+    captain: Option<i32>,
+    general: &GeneralData,
+) -> Vec<String> {
+    let names = |candidates: Vec<&Candidate>| {
+        candidates
+            .iter()
+            .map(|c| general.player_name(c.id).to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let starting: Vec<&Candidate> = optimal
+        .starters
+        .iter()
+        .filter(|c| !setup.starters.iter().any(|s| s.id == c.id))
+        .collect();
+    let benched: Vec<&Candidate> = setup
+        .starters
+        .iter()
+        .filter(|c| !optimal.starters.iter().any(|s| s.id == c.id))
+        .collect();
+
+    let mut changes = Vec::new();
+    if !starting.is_empty() {
+        changes.push(format!("⬆️ Start {}", names(starting)));
+    }
+    if !benched.is_empty() {
+        changes.push(format!("⬇️ Bench {}", names(benched)));
+    }
+    if let Some(captain) = captain.filter(|c| Some(*c) != setup.captain) {
+        changes.push(format!("©️ Captain {}", general.player_name(captain)));
+    }
+    changes
+}
+
+/// The `/optimize_lineup` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct OptimizeLineupCommand;
+
+#[async_trait]
+impl SlashCommand for OptimizeLineupCommand {
+    fn name(&self) -> &'static str {
+        "optimize_lineup"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+}
@@ -0,0 +1,296 @@
+//! Guild permission settings
+//!
+//! Provides the `/permissions` slash command, which lets server admins choose
+//! who may run each command, nominate bot admin roles and review the audit log
+//! of settings changes.
+
+use anyhow::{anyhow, Result};
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, GuildId, ResolvedOption, ResolvedValue,
+};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed};
+
+use crate::bot::permissions::{audit, Access};
+use crate::bot::registry::{CommandRegistry, SlashCommand};
+use crate::bot::response::{Progress, Reply};
+use crate::database::service::db_service;
+
+/// Audit entries shown when no limit is given.
+const DEFAULT_AUDIT_ENTRIES: i64 = 10;
+
+/// Registers the permissions command with Discord
+///
+/// The command is not hidden behind a Discord permission, since members of
+/// the bot admin roles may use it too; [`Access::Admins`] is checked when it
+/// runs instead.
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    let mut access = CreateCommandOption::new(
+        CommandOptionType::String,
+        "access",
+        "Who may run the command",
+    )
+    .add_string_choice("Default", "default")
+    .required(true);
+    for level in Access::ALL {
+        access = access.add_string_choice(level.as_str(), level.as_str());
+    }
+
+    CreateCommand::new("permissions")
+        .description("Control who can change the bot's settings (admin only)")
+        .dm_permission(false)
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "show",
+            "Show admin roles and command access levels",
+        ))
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "admin_role",
+                "Add or remove a bot admin role",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(CommandOptionType::String, "action", "Add or remove")
+                    .add_string_choice("Add", "add")
+                    .add_string_choice("Remove", "remove")
+                    .required(true),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(CommandOptionType::Role, "role", "The role")
+                    .required(true),
+            ),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "set",
+                "Choose who may run a command",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "command",
+                    "Command name, without the slash",
+                )
+                .required(true),
+            )
+            .add_sub_option(access),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "audit",
+                "Show recent settings changes",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "limit",
+                    "Number of entries (default 10)",
+                )
+                .min_int_value(1)
+                .max_int_value(25),
+            ),
+        )
+}
+
+/// Main handler for the `/permissions` slash command
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused)
+/// * `command` - The slash command interaction containing the subcommand
+///
+/// # Returns
+/// * `Result<Reply>` - Ephemeral summary of the settings or the change made
+///
+/// # Errors
+/// Returns error if:
+/// - The command is used outside a server
+/// - The command name is unknown or protected
+/// - Database operations fail
+///
+/// # Example Usage
+/// `/permissions set command:update_channel_league_id access:everyone`
+/// `/permissions admin_role action:add role:@FPL Admins`
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    let guild_id = command
+        .guild_id
+        .ok_or_else(|| anyhow!("Permissions can only be configured in a server"))?;
+
+    let options = command.data.options();
+    let Some(ResolvedOption {
+        name,
+        value: ResolvedValue::SubCommand(sub_options),
+        ..
+    }) = options.first()
+    else {
+        return Err(anyhow!("Please choose a subcommand"));
+    };
+
+    let reply = match *name {
+        "show" => Reply::new().embed(build_settings_embed(guild_id).await?),
+        "admin_role" => {
+            let add = matches!(
+                option(sub_options, "action"),
+                Some(ResolvedValue::String("add"))
+            );
+            let Some(ResolvedValue::Role(role)) = option(sub_options, "role") else {
+                return Err(anyhow!("Please choose a role"));
+            };
+            db_service().set_admin_role(guild_id, role.id, add).await?;
+            let change = format!(
+                "admin role {} {}",
+                role.name,
+                if add { "added" } else { "removed" }
+            );
+            audit(command, change.clone()).await;
+            Reply::new().content(format!("Bot {}", change))
+        }
+        "set" => {
+            let Some(ResolvedValue::String(name)) = option(sub_options, "command") else {
+                return Err(anyhow!("Please name a command"));
+            };
+            let name = name.trim().trim_start_matches('/');
+            let registry = CommandRegistry::new(super::all())?;
+            let target = registry
+                .get(name)
+                .ok_or_else(|| anyhow!("There is no /{} command", name))?;
+            if target.access() == Access::Admins {
+                return Err(anyhow!("/{} is always restricted to admins", name));
+            }
+
+            let access = match option(sub_options, "access") {
+                Some(ResolvedValue::String("default")) | None => None,
+                Some(ResolvedValue::String(level)) => Some(level.parse::<Access>()?),
+                Some(_) => return Err(anyhow!("Please choose an access level")),
+            };
+            db_service()
+                .set_command_access(guild_id, name, access.map(|a| a.as_str()))
+                .await?;
+
+            let effective = access.unwrap_or(target.access());
+            audit(command, format!("/{} access set to {}", name, effective)).await;
+            Reply::new().content(format!(
+                "/{} can now be used by {}",
+                name,
+                effective.describe()
+            ))
+        }
+        "audit" => {
+            let limit = match option(sub_options, "limit") {
+                Some(ResolvedValue::Integer(limit)) => *limit,
+                _ => DEFAULT_AUDIT_ENTRIES,
+            };
+            Reply::new().embed(build_audit_embed(guild_id, limit).await?)
+        }
+        other => return Err(anyhow!("Unknown subcommand {}", other)),
+    };
+
+    Ok(reply.ephemeral(true))
+}
+
+/// Finds a subcommand option by name.
+fn option<'a>(options: &'a [ResolvedOption<'a>], name: &str) -> Option<&'a ResolvedValue<'a>> {
+    options.iter().find(|o| o.name == name).map(|o| &o.value)
+}
+
+/// Lists the guild's admin roles and every command not open to everyone
+async fn build_settings_embed(guild_id: GuildId) -> Result<CreateEmbed> {
+    let admin_roles = db_service().get_admin_roles(guild_id).await?;
+    let overrides = db_service().get_command_accesses(guild_id).await?;
+    let registry = CommandRegistry::new(super::all())?;
+
+    let roles = if admin_roles.is_empty() {
+        "None - only Manage Server and Administrator count as admin".to_string()
+    } else {
+        admin_roles
+            .iter()
+            .map(|role| format!("<@&{}>", role))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let mut lines = Vec::new();
+    for name in registry.names() {
+        let default = registry.get(name).map_or(Access::Everyone, |c| c.access());
+        let configured = overrides
+            .iter()
+            .find(|(command, _)| command == name)
+            .and_then(|(_, access)| access.parse::<Access>().ok());
+        match configured {
+            Some(access) if access != default => {
+                lines.push(format!("/{}: {} (default {})", name, access, default))
+            }
+            _ if default != Access::Everyone => lines.push(format!("/{}: {}", name, default)),
+            _ => {}
+        }
+    }
+    lines.push("Every other command: everyone".to_string());
+
+    Ok(CreateEmbed::new()
+        .title("Bot permissions")
+        .field("Admin roles", roles, false)
+        .field("Command access", lines.join("\n"), false))
+}
+
+/// Shows the most recent settings changes in the guild
+async fn build_audit_embed(guild_id: GuildId, limit: i64) -> Result<CreateEmbed> {
+    let entries = db_service().get_audit_entries(guild_id, limit).await?;
+    let description = if entries.is_empty() {
+        "No settings have been changed yet".to_string()
+    } else {
+        entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "<t:{}:R> <@{}> in <#{}> ran /{}: {}",
+                    entry.created_at.timestamp(),
+                    entry.user_id,
+                    entry.channel_id,
+                    entry.command,
+                    entry.change
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    Ok(CreateEmbed::new()
+        .title("Recent settings changes")
+        .description(description))
+}
+
+/// The `/permissions` command as served by the [`CommandRegistry`]
+pub struct PermissionsCommand;
+
+#[async_trait]
+impl SlashCommand for PermissionsCommand {
+    fn name(&self) -> &'static str {
+        "permissions"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    fn access(&self) -> Access {
+        Access::Admins
+    }
+
+    fn ephemeral(&self) -> bool {
+        true
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+}
@@ -0,0 +1,501 @@
+//! Prediction game command implementation
+//!
+//! Provides the `/predictions` slash command for the server's fixture
+//! prediction game. Members pick an upcoming fixture from a menu and enter
+//! their predicted score in a modal, which can be changed until kickoff. The
+//! [`prediction_scoring`](crate::bot::tasks::prediction_scoring) task scores
+//! predictions once their fixture is finished.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use log::info;
+use serenity::all::{
+    ActionRowComponent, CommandInteraction, CommandOptionType, ComponentInteraction,
+    ComponentInteractionDataKind, Context, CreateActionRow, CreateInputText,
+    CreateInteractionResponse, CreateInteractionResponseMessage, CreateModal, CreateSelectMenu,
+    CreateSelectMenuKind, CreateSelectMenuOption, GuildId, InputTextStyle, ModalInteraction,
+    ResolvedOption, ResolvedValue, UserId,
+};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter};
+
+use crate::bot::custom_id::ComponentId;
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::database::models::DBPrediction;
+use crate::database::service::db_service;
+use crate::fpl::calendar::season_name;
+use crate::fpl::models::fixtures::{fetch_all_fixtures, fetch_fixtures, Fixture};
+use crate::fpl::models::teams::get_team_name;
+use crate::fpl::predictions::{
+    standings, CORRECT_RESULT_POINTS, EXACT_SCORE_POINTS, MAX_PREDICTED_GOALS,
+};
+
+/// Version of the fixture menu and prediction modal, see [`ComponentId`].
+const COMPONENT_VERSION: u32 = 1;
+
+/// Discord's limit on options in a select menu.
+const MAX_SELECT_OPTIONS: usize = 25;
+
+/// Rows shown by the standings and history lists.
+const MAX_LIST_ROWS: usize = 20;
+
+/// Registers the predictions command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("predictions")
+        .description("Predict fixture scores against the rest of the server")
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "open",
+            "Show upcoming fixtures and make or change a prediction",
+        ))
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "standings",
+            "The server's prediction leaderboard this season",
+        ))
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "history",
+                "A member's predictions this season",
+            )
+            .add_sub_option(CreateCommandOption::new(
+                CommandOptionType::User,
+                "user",
+                "The member (defaults to you)",
+            )),
+        )
+}
+
+/// Main handler for the `/predictions` slash command
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing the subcommand
+///
+/// # Returns
+/// * `Result<Reply>` - Open fixtures with a menu to predict them, the
+///   leaderboard, or a member's predictions
+///
+/// # Errors
+/// Returns error if:
+/// - The command is used outside a server
+/// - FPL API requests or database operations fail
+///
+/// # Example Usage
+/// `/predictions open`
+/// `/predictions history user:@someone`
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    let guild_id = command
+        .guild_id
+        .ok_or_else(|| anyhow!("The prediction game is played in servers, not DMs"))?;
+    let options = command.data.options();
+    let Some(ResolvedOption {
+        name,
+        value: ResolvedValue::SubCommand(sub_options),
+        ..
+    }) = options.first()
+    else {
+        return Err(anyhow!("Please choose a subcommand"));
+    };
+
+    match *name {
+        "open" => open_fixtures(guild_id, command.user.id).await,
+        "standings" => show_standings(guild_id).await,
+        "history" => {
+            let user_id = sub_options
+                .iter()
+                .find_map(|option| match option.value {
+                    ResolvedValue::User(user, _) => Some(user.id),
+                    _ => None,
+                })
+                .unwrap_or(command.user.id);
+            show_history(guild_id, user_id).await
+        }
+        other => Err(anyhow!("Unknown subcommand {}", other)),
+    }
+}
+
+/// Fixtures that can still be predicted, soonest first
+fn upcoming(fixtures: Vec<Fixture>, now: DateTime<Utc>) -> Vec<Fixture> {
+    let mut upcoming: Vec<Fixture> = fixtures
+        .into_iter()
+        .filter(|fixture| fixture.event.is_some() && !fixture.started)
+        .filter(|fixture| fixture.kickoff_time.is_some_and(|kickoff| kickoff > now))
+        .collect();
+    upcoming.sort_by_key(|fixture| fixture.kickoff_time);
+    upcoming.truncate(MAX_SELECT_OPTIONS);
+    upcoming
+}
+
+/// Formats a fixture's teams, e.g. "ARS v CHE".
+fn fixture_name(fixture: &Fixture) -> String {
+    format!(
+        "{} v {}",
+        get_team_name(fixture.team_h).short_name,
+        get_team_name(fixture.team_a).short_name
+    )
+}
+
+/// Lists upcoming fixtures with the caller's predictions and a menu to predict
+async fn open_fixtures(guild_id: GuildId, user_id: UserId) -> Result<Reply> {
+    let now = Utc::now();
+    let fixtures = upcoming(fetch_all_fixtures().await?, now);
+    if fixtures.is_empty() {
+        return Ok(Reply::new().content("There are no upcoming fixtures to predict."));
+    }
+
+    // predictions are filed under the season of the fixture's kickoff
+    let season = season_name(fixtures[0].kickoff_time.unwrap_or(now));
+    let predicted: HashMap<i64, DBPrediction> = db_service()
+        .get_user_predictions(guild_id, &season, user_id)
+        .await?
+        .into_iter()
+        .map(|prediction| (prediction.fixture_id, prediction))
+        .collect();
+
+    let lines: Vec<String> = fixtures
+        .iter()
+        .map(|fixture| {
+            let kickoff = fixture.kickoff_time.map_or(0, |k| k.timestamp());
+            let pick = match predicted.get(&i64::from(fixture.id)) {
+                Some(p) => format!(" • you: {}-{}", p.home_goals, p.away_goals),
+                None => String::new(),
+            };
+            format!("<t:{}:f> **{}**{}", kickoff, fixture_name(fixture), pick)
+        })
+        .collect();
+
+    let options = fixtures
+        .iter()
+        .map(|fixture| {
+            let kickoff = fixture
+                .kickoff_time
+                .map(|k| k.format("%a %d %b %H:%M UTC").to_string())
+                .unwrap_or_default();
+            CreateSelectMenuOption::new(fixture_name(fixture), fixture.id.to_string())
+                .description(kickoff)
+        })
+        .collect();
+    let menu = CreateSelectMenu::new(
+        ComponentId::new("predictions", COMPONENT_VERSION, "pick").encode()?,
+        CreateSelectMenuKind::String { options },
+    )
+    .placeholder("Predict a fixture");
+
+    Ok(Reply::new()
+        .embed(
+            CreateEmbed::new()
+                .title("🔮 Upcoming fixtures")
+                .description(lines.join("\n"))
+                .color(0x37003c) // purple
+                .footer(CreateEmbedFooter::new(format!(
+                    "{} pts for the exact score, {} for the result • Locks at kickoff",
+                    EXACT_SCORE_POINTS, CORRECT_RESULT_POINTS
+                ))),
+        )
+        .select_menu(menu))
+}
+
+/// Builds the season leaderboard
+async fn show_standings(guild_id: GuildId) -> Result<Reply> {
+    let season = season_name(Utc::now());
+    let scored: Vec<(u64, i32)> = db_service()
+        .get_scored_predictions(guild_id, &season)
+        .await?
+        .iter()
+        .map(|p| (p.user_id as u64, p.points.unwrap_or(0)))
+        .collect();
+    let standings = standings(&scored);
+
+    let description = if standings.is_empty() {
+        "No predictions have been scored this season.".to_string()
+    } else {
+        standings
+            .iter()
+            .take(MAX_LIST_ROWS)
+            .enumerate()
+            .map(|(i, s)| {
+                format!(
+                    "{}. <@{}> **{} pts** • {} exact • {} results • {} predicted",
+                    i + 1,
+                    s.user_id,
+                    s.points,
+                    s.exact_scores,
+                    s.correct_results,
+                    s.predictions
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    Ok(Reply::new().embed(
+        CreateEmbed::new()
+            .title(format!("🔮 Prediction standings {}", season))
+            .description(description)
+            .color(0x37003c), // purple
+    ))
+}
+
+/// Lists a member's predictions this season, newest first
+async fn show_history(guild_id: GuildId, user_id: UserId) -> Result<Reply> {
+    let season = season_name(Utc::now());
+    let predictions = db_service()
+        .get_user_predictions(guild_id, &season, user_id)
+        .await?;
+    if predictions.is_empty() {
+        return Ok(Reply::new().content(format!(
+            "<@{}> has not made any predictions this season.",
+            user_id
+        )));
+    }
+
+    let fixtures: HashMap<i64, Fixture> = fetch_all_fixtures()
+        .await?
+        .into_iter()
+        .map(|fixture| (i64::from(fixture.id), fixture))
+        .collect();
+    let lines: Vec<String> = predictions
+        .iter()
+        .take(MAX_LIST_ROWS)
+        .map(|p| {
+            let Some(fixture) = fixtures.get(&p.fixture_id) else {
+                return format!(
+                    "Fixture {}: {}-{}",
+                    p.fixture_id, p.home_goals, p.away_goals
+                );
+            };
+            let outcome = match (p.points, fixture.team_h_score, fixture.team_a_score) {
+                (Some(points), Some(home), Some(away)) => {
+                    format!("final {}-{} • **+{}**", home, away, points)
+                }
+                _ if fixture.started => "in play".to_string(),
+                _ => "not started".to_string(),
+            };
+            format!(
+                "{} {}-{} {} • {}",
+                get_team_name(fixture.team_h).short_name,
+                p.home_goals,
+                p.away_goals,
+                get_team_name(fixture.team_a).short_name,
+                outcome
+            )
+        })
+        .collect();
+    let total: i32 = predictions.iter().filter_map(|p| p.points).sum();
+
+    Ok(Reply::new().embed(
+        CreateEmbed::new()
+            .title(format!("🔮 Predictions {}", season))
+            .description(format!("<@{}>\n{}", user_id, lines.join("\n")))
+            .color(0x37003c) // purple
+            .footer(CreateEmbedFooter::new(format!(
+                "{} predictions • {} pts",
+                predictions.len(),
+                total
+            ))),
+    ))
+}
+
+/// Handles the fixture menu by opening the prediction modal
+///
+/// # Custom ID Format
+/// A [`ComponentId`] for `predictions` with action `pick`. The modal it opens
+/// has action `save` and carries the fixture and its gameweek.
+pub async fn handle_component(ctx: &Context, component: &ComponentInteraction) {
+    let response = match prediction_modal(component).await {
+        Ok(response) => response,
+        Err(e) => error_response(e),
+    };
+    if let Err(why) = component.create_response(&ctx.http, response).await {
+        info!("Cannot open prediction modal: {}", why);
+    }
+}
+
+/// Handles a submitted prediction modal
+pub async fn handle_modal(ctx: &Context, modal: &ModalInteraction) {
+    let response = match save_prediction(modal).await {
+        Ok(content) => CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new()
+                .content(content)
+                .ephemeral(true),
+        ),
+        Err(e) => error_response(e),
+    };
+    if let Err(why) = modal.create_response(&ctx.http, response).await {
+        info!("Cannot respond to prediction: {}", why);
+    }
+}
+
+fn error_response(e: anyhow::Error) -> CreateInteractionResponse {
+    CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(format!("Error: {}", e))
+            .ephemeral(true),
+    )
+}
+
+async fn prediction_modal(component: &ComponentInteraction) -> Result<CreateInteractionResponse> {
+    ComponentId::parse(&component.data.custom_id, "predictions", COMPONENT_VERSION)?;
+    let ComponentInteractionDataKind::StringSelect { values } = &component.data.kind else {
+        return Err(anyhow!("Please choose a fixture"));
+    };
+    let fixture_id: u32 = values
+        .first()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| anyhow!("Please choose a fixture"))?;
+
+    let fixture = upcoming(fetch_all_fixtures().await?, Utc::now())
+        .into_iter()
+        .find(|fixture| fixture.id == fixture_id)
+        .ok_or_else(|| anyhow!("That fixture has kicked off, predictions are locked"))?;
+    let gameweek = fixture.event.map_or(0, i32::from);
+
+    let goals_input = |team: i32, custom_id: &str| {
+        CreateInputText::new(
+            InputTextStyle::Short,
+            format!("{} goals", get_team_name(team).name),
+            custom_id,
+        )
+        .placeholder("0")
+        .min_length(1)
+        .max_length(2)
+    };
+    let modal_id = ComponentId::new("predictions", COMPONENT_VERSION, "save")
+        .fixture(fixture.id)
+        .gameweek(gameweek)
+        .encode()?;
+    Ok(CreateInteractionResponse::Modal(
+        CreateModal::new(modal_id, fixture_name(&fixture)).components(vec![
+            CreateActionRow::InputText(goals_input(fixture.team_h, "home")),
+            CreateActionRow::InputText(goals_input(fixture.team_a, "away")),
+        ]),
+    ))
+}
+
+/// Stores the prediction entered in the modal
+///
+/// # Returns
+/// * `Result<String>` - Confirmation to show the member
+///
+/// # Errors
+/// Returns error if the fixture has kicked off, the goals are not numbers up
+/// to [`MAX_PREDICTED_GOALS`], or the database write fails
+async fn save_prediction(modal: &ModalInteraction) -> Result<String> {
+    let guild_id = modal
+        .guild_id
+        .ok_or_else(|| anyhow!("The prediction game is played in servers, not DMs"))?;
+    let id = ComponentId::parse(&modal.data.custom_id, "predictions", COMPONENT_VERSION)?;
+    let (Some(fixture_id), Some(gameweek)) = (id.state.fixture_id, id.state.gameweek) else {
+        return Err(anyhow!("Could not determine the fixture"));
+    };
+
+    let goals = |custom_id: &str| -> Result<u8> {
+        let input = modal
+            .data
+            .components
+            .iter()
+            .flat_map(|row| &row.components)
+            .find_map(|component| match component {
+                ActionRowComponent::InputText(text) if text.custom_id == custom_id => {
+                    text.value.as_deref()
+                }
+                _ => None,
+            })
+            .unwrap_or_default();
+        input
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|goals| *goals <= MAX_PREDICTED_GOALS)
+            .ok_or_else(|| anyhow!("{:?} is not a number of goals", input))
+    };
+    let home_goals = goals("home")?;
+    let away_goals = goals("away")?;
+
+    // checked against fresh data, since the modal may have been open for a while
+    let fixture = fetch_fixtures(gameweek)
+        .await?
+        .fixtures
+        .into_iter()
+        .find(|fixture| fixture.id == fixture_id)
+        .ok_or_else(|| anyhow!("That fixture is no longer in GW{}", gameweek))?;
+    let now = Utc::now();
+    let kickoff = fixture
+        .kickoff_time
+        .ok_or_else(|| anyhow!("That fixture has no kickoff time"))?;
+    if fixture.started || kickoff <= now {
+        return Err(anyhow!(
+            "That fixture has kicked off, predictions are locked"
+        ));
+    }
+
+    db_service()
+        .upsert_prediction(&DBPrediction {
+            id: 0,
+            guild_id: guild_id.into(),
+            season: season_name(kickoff),
+            fixture_id: i64::from(fixture_id),
+            user_id: modal.user.id.into(),
+            home_goals: i32::from(home_goals),
+            away_goals: i32::from(away_goals),
+            predicted_at: now,
+            points: None,
+        })
+        .await?;
+    info!(
+        "User {} predicted {}-{} for fixture {} in guild {}",
+        modal.user.name, home_goals, away_goals, fixture_id, guild_id
+    );
+
+    Ok(format!(
+        "Saved your prediction: {} {}-{} {}. You can change it until kickoff, <t:{}:R>.",
+        get_team_name(fixture.team_h).name,
+        home_goals,
+        away_goals,
+        get_team_name(fixture.team_a).name,
+        kickoff.timestamp()
+    ))
+}
+
+/// The `/predictions` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct PredictionsCommand;
+
+#[async_trait]
+impl SlashCommand for PredictionsCommand {
+    fn name(&self) -> &'static str {
+        "predictions"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+
+    fn component_prefixes(&self) -> &'static [&'static str] {
+        &["predictions:"]
+    }
+
+    async fn handle_component(&self, ctx: &Context, component: &ComponentInteraction) {
+        handle_component(ctx, component).await
+    }
+
+    async fn handle_modal(&self, ctx: &Context, modal: &ModalInteraction) {
+        handle_modal(ctx, modal).await
+    }
+}
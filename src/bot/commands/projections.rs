@@ -0,0 +1,372 @@
+//! Projections command implementation
+//!
+//! Provides the `/projections` slash command. `top` lists the players with
+//! the most projected points over the next few gameweeks from the
+//! [`projection`](crate::fpl::projection) model, and `backtest` stores any
+//! finished gameweeks' stats that are missing and reports how well the model
+//! would have predicted them.
+
+use anyhow::{anyhow, Result};
+use log::info;
+use serenity::all::{CommandInteraction, Context, ResolvedOption, ResolvedValue};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter};
+use serenity::model::application::CommandOptionType;
+
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::database::models::DBGameweekPlayerStats;
+use crate::database::service::db_service;
+use crate::fpl::models::fixtures::fetch_all_fixtures;
+use crate::fpl::models::gameweek::{update_gameweek_info, PlayerGameweekStats};
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::teams::get_team_name;
+use crate::fpl::projection::{backtest, project, PlayerRole, Projection, StatLine};
+use crate::fpl::squad::Position;
+
+/// Most gameweeks a single projection covers.
+const MAX_GAMEWEEKS: i32 = 6;
+
+/// Players listed by `top`.
+const TOP_PLAYERS: usize = 15;
+
+/// Registers the projections command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    let mut position = CreateCommandOption::new(
+        CommandOptionType::String,
+        "position",
+        "Only show players in this position",
+    )
+    .required(false);
+    for pos in Position::ALL {
+        position = position.add_string_choice(pos.short_name(), pos.short_name());
+    }
+
+    CreateCommand::new("projections")
+        .description("Projected points from expected goals, minutes and fixtures")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "top",
+                "Players with the most projected points over the next gameweeks",
+            )
+            .add_sub_option(position)
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "gameweeks",
+                    "Number of gameweeks to project (defaults to 1)",
+                )
+                .min_int_value(1)
+                .max_int_value(MAX_GAMEWEEKS as u64)
+                .required(false),
+            ),
+        )
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "backtest",
+            "How well the projections would have predicted this season's gameweeks",
+        ))
+}
+
+/// Main handler for the `/projections` slash command
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing the subcommand
+/// * `progress` - Progress handle updated while gameweek stats are stored
+///
+/// # Returns
+/// * `Result<Reply>` - The projected top players, or the backtest report
+///
+/// # Errors
+/// Returns error if:
+/// - There is no upcoming gameweek to project
+/// - FPL API requests or database operations fail
+///
+/// # Example Usage
+/// `/projections top`
+/// `/projections top position:MID gameweeks:3`
+/// `/projections backtest`
+pub async fn run(
+    _ctx: &Context,
+    command: &CommandInteraction,
+    progress: &Progress,
+) -> Result<Reply> {
+    let options = command.data.options();
+    let Some(ResolvedOption {
+        name,
+        value: ResolvedValue::SubCommand(sub_options),
+        ..
+    }) = options.first()
+    else {
+        return Err(anyhow!("Please choose a subcommand"));
+    };
+    info!(
+        "Processing projections {} for user {}",
+        name, command.user.name
+    );
+
+    let general = GeneralData::fetch().await?;
+    match *name {
+        "top" => show_top(sub_options, &general).await,
+        "backtest" => show_backtest(&general, progress).await,
+        other => Err(anyhow!("Unknown subcommand {}", other)),
+    }
+}
+
+/// Lists the top projected players over the requested gameweeks
+async fn show_top(options: &[ResolvedOption<'_>], general: &GeneralData) -> Result<Reply> {
+    let mut position = None;
+    let mut span = 1;
+    for option in options {
+        match (option.name, &option.value) {
+            ("position", ResolvedValue::String(short)) => {
+                position = Position::ALL
+                    .into_iter()
+                    .find(|pos| pos.short_name() == *short);
+            }
+            ("gameweeks", ResolvedValue::Integer(n)) => {
+                span = (*n as i32).clamp(1, MAX_GAMEWEEKS);
+            }
+            _ => {}
+        }
+    }
+
+    let first = general
+        .next_event()
+        .ok_or_else(|| anyhow!("There are no upcoming gameweeks to project"))?
+        .id;
+    let last = (first + span - 1).min(general.events.iter().map(|e| e.id).max().unwrap_or(first));
+    let fixtures = fetch_all_fixtures().await?;
+
+    let mut projections: Vec<Projection> = project(general, &fixtures, first..=last)
+        .into_iter()
+        .filter(|projection| {
+            position.is_none_or(|pos| {
+                general.player(projection.element).is_some_and(|player| {
+                    Position::from_element_type(player.element_type) == Some(pos)
+                })
+            })
+        })
+        .collect();
+    projections.sort_by(|a, b| {
+        b.total()
+            .total_cmp(&a.total())
+            .then(a.element.cmp(&b.element))
+    });
+
+    let lines: Vec<String> = projections
+        .iter()
+        .take(TOP_PLAYERS)
+        .enumerate()
+        .map(|(i, projection)| top_line(i + 1, projection, general))
+        .collect();
+    let range = if first == last {
+        format!("GW{}", first)
+    } else {
+        format!("GW{}-{}", first, last)
+    };
+    let title = match position {
+        Some(pos) => format!("📈 Projected points {} • {}", range, pos),
+        None => format!("📈 Projected points {}", range),
+    };
+
+    Ok(Reply::new().embed(
+        CreateEmbed::new()
+            .title(title)
+            .description(if lines.is_empty() {
+                "No players to project.".to_string()
+            } else {
+                lines.join("\n")
+            })
+            .color(0x00ff87) // green
+            .footer(CreateEmbedFooter::new(
+                "From per-90 xG/xA, minutes, team strength and fixture difficulty • See /projections backtest",
+            )),
+    ))
+}
+
+/// Formats a projection, e.g. "1. **Haaland** MCI 7.2 (4.1, 3.1)"
+fn top_line(rank: usize, projection: &Projection, general: &GeneralData) -> String {
+    let team = general.player(projection.element).map_or(0, |p| p.team);
+    let mut line = format!(
+        "{}. **{}** {} {:.1}",
+        rank,
+        general.player_name(projection.element),
+        get_team_name(team).short_name,
+        projection.total()
+    );
+    if projection.gameweeks.len() > 1 {
+        let per_gameweek: Vec<String> = projection
+            .gameweeks
+            .iter()
+            .map(|(_, points)| format!("{:.1}", points))
+            .collect();
+        line.push_str(&format!(" ({})", per_gameweek.join(", ")));
+    }
+    line
+}
+
+/// Stores missing gameweek stats, then backtests the model against them
+async fn show_backtest(general: &GeneralData, progress: &Progress) -> Result<Reply> {
+    let stored = store_finished_gameweeks(general, progress).await?;
+    info!("Stored stats for {} new gameweeks", stored);
+
+    progress.update("Replaying projections…").await;
+    let lines: Vec<StatLine> = db_service()
+        .get_gameweek_player_stats()
+        .await?
+        .into_iter()
+        .map(|stats| StatLine {
+            gameweek: stats.gameweek,
+            element: stats.player_id,
+            minutes: stats.minutes,
+            starts: stats.starts,
+            expected_goals: stats.expected_goals,
+            expected_assists: stats.expected_assists,
+            saves: stats.saves,
+            total_points: stats.total_points,
+        })
+        .collect();
+    let roles: Vec<PlayerRole> = general
+        .elements
+        .iter()
+        .filter_map(|player| {
+            Some(PlayerRole {
+                element: player.id,
+                position: Position::from_element_type(player.element_type)?,
+                team: player.team,
+            })
+        })
+        .collect();
+    let fixtures = fetch_all_fixtures().await?;
+    let report = backtest(&lines, &roles, &general.teams, &fixtures);
+
+    let description = if report.samples == 0 {
+        "Not enough finished gameweeks have been stored to backtest yet.".to_string()
+    } else {
+        format!(
+            "**Gameweeks projected:** {}\n\
+            **Player gameweeks:** {}\n\
+            **Mean absolute error:** {:.2} pts\n\
+            **Bias:** {:+.2} pts\n\
+            **Correlation:** {:.2}\n\
+            **Points per game error:** {:.2} pts",
+            report.gameweeks,
+            report.samples,
+            report.mean_absolute_error,
+            report.bias,
+            report.correlation,
+            report.baseline_error
+        )
+    };
+
+    Ok(Reply::new().embed(
+        CreateEmbed::new()
+            .title(format!("📈 Projection backtest {}", general.season()))
+            .description(description)
+            .color(0x37003c) // purple
+            .footer(CreateEmbedFooter::new(
+                "Each gameweek projected from the ones before it • Points per game is the naive comparison",
+            )),
+    ))
+}
+
+/// Stores the live stats of every checked gameweek not yet in the database
+///
+/// # Returns
+/// * `Result<usize>` - Number of gameweeks stored
+async fn store_finished_gameweeks(general: &GeneralData, progress: &Progress) -> Result<usize> {
+    let db = db_service();
+    let stored = db.get_stored_stat_gameweeks().await?;
+    let missing: Vec<i32> = general
+        .events
+        .iter()
+        .filter(|event| event.finished && event.data_checked && !stored.contains(&event.id))
+        .map(|event| event.id)
+        .collect();
+
+    for (i, gameweek) in missing.iter().enumerate() {
+        progress
+            .update(format!(
+                "Storing GW{} stats ({}/{})…",
+                gameweek,
+                i + 1,
+                missing.len()
+            ))
+            .await;
+        let live = update_gameweek_info(*gameweek).await?;
+        let stats: Vec<DBGameweekPlayerStats> = live
+            .elements
+            .iter()
+            .map(|element| stat_row(*gameweek, element))
+            .collect();
+        db.replace_gameweek_player_stats(*gameweek, &stats).await?;
+    }
+    Ok(missing.len())
+}
+
+/// Converts a player's live gameweek stats into a database row
+fn stat_row(gameweek: i32, element: &PlayerGameweekStats) -> DBGameweekPlayerStats {
+    let stats = &element.stats;
+    DBGameweekPlayerStats {
+        gameweek,
+        player_id: element.id,
+        minutes: stats.minutes,
+        goals_scored: stats.goals_scored,
+        assists: stats.assists,
+        clean_sheets: stats.clean_sheets,
+        goals_conceded: stats.goals_conceded,
+        own_goals: stats.own_goals,
+        penalties_saved: stats.penalties_saved,
+        penalties_missed: stats.penalties_missed,
+        yellow_cards: stats.yellow_cards,
+        red_cards: stats.red_cards,
+        saves: stats.saves,
+        bonus: stats.bonus,
+        bps: stats.bps,
+        clearances_blocks_interceptions: stats.clearances_blocks_interceptions,
+        recoveries: stats.recoveries,
+        tackles: stats.tackles,
+        defensive_contribution: stats.defensive_contribution,
+        starts: stats.starts,
+        influence: stats.influence,
+        creativity: stats.creativity,
+        threat: stats.threat,
+        ict_index: stats.ict_index,
+        expected_goals: stats.expected_goals,
+        expected_assists: stats.expected_assists,
+        expected_goal_involvements: stats.expected_goal_involvements,
+        expected_goals_conceded: stats.expected_goals_conceded,
+        total_points: stats.total_points,
+        in_dreamteam: stats.in_dreamteam,
+        modified: element.modified,
+    }
+}
+
+/// The `/projections` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct ProjectionsCommand;
+
+#[async_trait]
+impl SlashCommand for ProjectionsCommand {
+    fn name(&self) -> &'static str {
+        "projections"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command, progress).await
+    }
+}
@@ -0,0 +1,96 @@
+//! Schedule alert opt-in command
+//!
+//! Lets a channel subscribe to alerts posted when FPL moves a fixture to a
+//! different gameweek or kickoff time.
+
+use anyhow::{anyhow, Result};
+use log::{error, info};
+use serenity::all::{CommandInteraction, Context, ResolvedOption, ResolvedValue};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption};
+use serenity::model::application::CommandOptionType;
+
+use crate::bot::permissions::{audit, Access};
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::database::service::db_service;
+
+pub fn register() -> CreateCommand {
+    CreateCommand::new("schedule_alerts")
+        .description("Post an alert in this channel when fixtures are rescheduled")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "enabled",
+                "Whether this channel should receive alerts",
+            )
+            .required(true),
+        )
+}
+
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
+    let channel_id = command.channel_id;
+
+    let enabled = match command.data.options().first() {
+        Some(ResolvedOption {
+            value: ResolvedValue::Boolean(enabled),
+            ..
+        }) => *enabled,
+        _ => return Err(anyhow!("Please specify whether alerts are enabled")),
+    };
+
+    info!(
+        "Setting schedule alerts to {} for channel {}",
+        enabled, channel_id
+    );
+
+    if let Err(e) = db_service().set_schedule_alerts(channel_id, enabled).await {
+        error!(
+            "Failed to update schedule alerts for channel {}: {}",
+            channel_id, e
+        );
+        return Err(anyhow!("Failed to update schedule alerts"));
+    }
+    audit(
+        command,
+        format!(
+            "schedule alerts {}",
+            if enabled { "enabled" } else { "disabled" }
+        ),
+    )
+    .await;
+
+    let content = if enabled {
+        "This channel will now be alerted when fixtures are rescheduled"
+    } else {
+        "Schedule alerts disabled for this channel"
+    };
+    Ok(Reply::new().content(content))
+}
+
+/// The `/schedule_alerts` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct ScheduleAlertsCommand;
+
+#[async_trait]
+impl SlashCommand for ScheduleAlertsCommand {
+    fn name(&self) -> &'static str {
+        "schedule_alerts"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    fn access(&self) -> Access {
+        Access::Managers
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+}
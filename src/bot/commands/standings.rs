@@ -1,56 +1,80 @@
 //! League standings command implementation
 //!
 //! Provides Discord slash command functionality for displaying FPL league standings
-//! with interactive pagination and navigation controls.
+//! with interactive pagination and navigation controls. Pages are sliced from
+//! cached FPL API pages (see [`crate::fpl::standings`]), so any page of a large
+//! league can be reached directly, and "find me" jumps to the caller's entry.
 
+use crate::bot::custom_id::ComponentId;
+use crate::bot::leagues::{league_option, requested_league, resolve_league};
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
 use crate::database::service::db_service;
+use crate::fpl::client::MAX_CONCURRENT_REQUESTS;
+use crate::fpl::live_rank::{RankEstimator, StartingPosition};
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::history::ManagerHistory;
 use crate::fpl::models::league::{LeagueStandings, StandingsManager};
+use crate::fpl::models::manager::Manager;
+use crate::fpl::standings::{self, page_count, rank_by_metric, StandingsView, FORM_GAMEWEEKS};
 use anyhow::{anyhow, Result};
-use log::{error, info};
+use log::{error, info, warn};
 use serenity::all::{
-    ButtonStyle, CommandInteraction, Context, CreateInteractionResponse,
-    CreateInteractionResponseMessage,
+    ActionRowComponent, ButtonStyle, CommandInteraction, ComponentInteraction,
+    ComponentInteractionDataKind, Context, CreateActionRow, CreateInputText,
+    CreateInteractionResponse, CreateInteractionResponseFollowup, CreateInteractionResponseMessage,
+    CreateModal, CreateSelectMenu, CreateSelectMenuKind, CreateSelectMenuOption,
+    EditInteractionResponse, InputTextStyle, ModalInteraction, UserId,
 };
+use serenity::async_trait;
 use serenity::builder::{CreateButton, CreateCommand, CreateCommandOption, CreateEmbed};
 use serenity::model::application::{CommandOptionType, ResolvedOption, ResolvedValue};
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// Version of the standings navigation buttons, see [`ComponentId`].
+const COMPONENT_VERSION: u32 = 3;
 
 /// Main handler for the `/standings` slash command
 ///
-/// Fetches and displays league standings for a given league ID with interactive
-/// pagination controls. Shows manager names, positions, and points in an embed format.
+/// Fetches and displays league standings with interactive pagination controls.
+/// Shows manager names, positions, and points in an embed format, ranked by the
+/// `view` option (the league table by default). The league is
+/// the `league_id` option if given, otherwise one of the channel's configured
+/// leagues (see [`resolve_league`]).
 ///
 /// # Arguments
 /// * `_ctx` - Discord context (unused in current implementation)
 /// * `command` - The slash command interaction containing user input
+/// * `progress` - Progress handle updated while manager histories are fetched
 ///
 /// # Returns
-/// * `Result<CreateInteractionResponse>` - Discord response with standings embed and navigation buttons
+/// * `Result<Reply>` - Discord response with standings embed, navigation buttons and view menu
 ///
 /// # Errors
 /// Returns error if:
-/// - League ID is not provided or invalid
+/// - No league is given and none is configured for the channel
 /// - FPL API request fails
 /// - League data cannot be processed
 ///
 /// # Example Usage
 /// `/standings league_id:123456`
+/// `/standings league:cup view:form`
 pub async fn run(
     _ctx: &Context,
     command: &CommandInteraction,
-) -> Result<CreateInteractionResponse> {
+    progress: &Progress,
+) -> Result<Reply> {
     let user_id = &command.user.name;
     info!("Processing standings command for user {}", user_id);
 
-    let league_id = if command.data.options().is_empty() {
-        let db = db_service();
-        let channel = db.get_channel(command.channel_id).await?;
-
-        channel
-            .default_league_id
-            .ok_or_else(|| anyhow!("No default league"))?
-    } else {
-        extract_league_id(&command.data.options())?
+    let options = command.data.options();
+    let league_id = match extract_league_id(&options) {
+        Some(league_id) => league_id,
+        None => resolve_league(command, requested_league(&options)).await?,
     };
 
     info!(
@@ -58,13 +82,21 @@ pub async fn run(
         league_id, user_id
     );
 
-    let standings = match LeagueStandings::fetch(league_id).await {
-        Ok(standings) => {
+    let view = match options.iter().find(|o| o.name == VIEW_OPTION) {
+        Some(ResolvedOption {
+            value: ResolvedValue::String(view),
+            ..
+        }) => view.parse()?,
+        _ => StandingsView::Total,
+    };
+
+    let page = match StandingsPage::load(league_id, view, 0, None, Some(progress)).await {
+        Ok(page) => {
             info!(
                 "Successfully fetched standings for league_id: {} (user {})",
                 league_id, user_id
             );
-            standings
+            page
         }
         Err(e) => {
             error!(
@@ -75,195 +107,923 @@ pub async fn run(
         }
     };
 
-    let page = 0;
-    let embed = build_standings_embed(&standings, page);
-    let buttons = build_navigation_buttons(page, &standings);
-
     info!(
         "Successfully built standings response for league_id: {} (user {})",
         league_id, user_id
     );
-    Ok(CreateInteractionResponse::Message(
-        CreateInteractionResponseMessage::new()
-            .embed(embed)
-            .button(buttons.prev)
-            .button(buttons.next)
-            .button(buttons.refresh),
-    ))
+    build_standings_message(&page)
 }
 
-/// Extracts league ID from Discord command options
-///
-/// Parses the first command option to extract the league ID integer value.
+/// Extracts an explicit league ID from Discord command options
 ///
 /// # Arguments
 /// * `options` - Array of resolved command options from Discord
 ///
 /// # Returns
-/// * `Result<i32>` - The league ID as a 32-bit integer
-///
-/// # Errors
-/// Returns error if no valid integer option is provided
-fn extract_league_id(options: &[ResolvedOption]) -> Result<i32> {
-    match options.first() {
-        Some(ResolvedOption {
+/// * `Option<i32>` - The `league_id` option, if the user gave one
+fn extract_league_id(options: &[ResolvedOption]) -> Option<i32> {
+    options.iter().find_map(|option| match option {
+        ResolvedOption {
+            name: "league_id",
             value: ResolvedValue::Integer(id),
             ..
-        }) => Ok(*id as i32),
-        _ => {
-            error!("No valid league_id provided in command options");
-            Err(anyhow!("Please provide a valid league ID"))
+        } => Some(*id as i32),
+        _ => None,
+    })
+}
+
+/// Display entries per standings page.
+pub const PAGE_SIZE: usize = 25;
+
+/// Entries ranked by the gameweek view, which needs no extra requests.
+const MAX_RANKED_ENTRIES: usize = 500;
+
+/// Entries ranked by views that fetch every manager's history.
+const MAX_HISTORY_ENTRIES: usize = 100;
+
+/// Name of the option choosing a view.
+const VIEW_OPTION: &str = "view";
+
+/// One row of a standings table
+pub struct StandingsRow {
+    pub manager_id: i32,
+
+    pub manager_name: String,
+
+    /// Columns before the name, e.g. rank and rank change
+    pub left: Vec<String>,
+
+    /// Columns after the name, e.g. points
+    pub right: Vec<String>,
+}
+
+/// One display page of a league's standings in one view
+pub struct StandingsPage {
+    /// The first API page behind the view, for the league's details
+    pub standings: Arc<LeagueStandings>,
+
+    /// How the league is ranked
+    pub view: StandingsView,
+
+    /// The rows on this page
+    pub rows: Vec<StandingsRow>,
+
+    /// Page number (0-based)
+    pub page: usize,
+
+    /// Number of entries shown by the view, if it is small enough to count
+    pub total_entries: Option<usize>,
+
+    /// Whether a re-ranked view only covers the top of a larger league
+    pub truncated: bool,
+
+    /// Manager whose row is marked, after "find me"
+    pub highlight: Option<i32>,
+}
+
+impl StandingsPage {
+    /// Fetches a page of a league's standings in a view
+    ///
+    /// Pages past the end of the league are clamped to the last page.
+    ///
+    /// # Arguments
+    /// * `league_id` - The classic league ID
+    /// * `view` - How to rank the league
+    /// * `page` - Page number (0-based)
+    /// * `highlight` - Manager whose row should be marked
+    /// * `progress` - Progress handle updated while histories are fetched
+    ///
+    /// # Errors
+    /// Returns error if the FPL API request fails
+    pub async fn load(
+        league_id: i32,
+        view: StandingsView,
+        page: usize,
+        highlight: Option<i32>,
+        progress: Option<&Progress>,
+    ) -> Result<Self> {
+        if view.is_reranked() {
+            let (standings, ranked, truncated) = ranked_rows(league_id, view, progress).await?;
+            let page = page.min(page_count(ranked.len(), PAGE_SIZE) - 1);
+            let total_entries = Some(ranked.len());
+            let (entries, mut rows): (Vec<_>, Vec<_>) = ranked
+                .into_iter()
+                .skip(page * PAGE_SIZE)
+                .take(PAGE_SIZE)
+                .unzip();
+            if view == StandingsView::Gameweek {
+                add_live_ranks(&entries, &mut rows, progress).await?;
+            }
+            return Ok(Self {
+                standings,
+                view,
+                rows,
+                page,
+                total_entries,
+                truncated,
+                highlight,
+            });
+        }
+
+        let total_entries = standings::total_entries(league_id).await?;
+        let page = match total_entries {
+            Some(total) => page.min(page_count(total, PAGE_SIZE) - 1),
+            None => page,
+        };
+        let start = page.saturating_mul(PAGE_SIZE);
+        let rows = match view {
+            StandingsView::Gaps => {
+                let (first, count) = gap_window(start);
+                let (standings, entries) = standings::entries(league_id, first, count).await?;
+                let leader = standings::api_page(league_id, 1).await?;
+                let leader_points = leader
+                    .standings
+                    .managers
+                    .first()
+                    .map_or(0, |m| m.total_points);
+                let rows = gap_rows(&entries, start > 0, leader_points);
+                (standings, rows)
+            }
+            _ => {
+                let (standings, entries) = standings::entries(league_id, start, PAGE_SIZE).await?;
+                let rows = entries.iter().map(table_row).collect();
+                (standings, rows)
+            }
+        };
+
+        Ok(Self {
+            standings: rows.0,
+            view,
+            rows: rows.1,
+            page,
+            total_entries,
+            truncated: false,
+            highlight,
+        })
+    }
+
+    /// Number of pages, if the league could be counted.
+    pub fn total_pages(&self) -> Option<usize> {
+        self.total_entries.map(|total| page_count(total, PAGE_SIZE))
+    }
+
+    /// Whether a page follows this one.
+    pub fn has_next(&self) -> bool {
+        match self.total_pages() {
+            Some(pages) => self.page + 1 < pages,
+            None => self.rows.len() == PAGE_SIZE,
         }
     }
 }
 
+/// A league table row: rank, rank change, total and gameweek points.
+fn table_row(entry: &StandingsManager) -> StandingsRow {
+    StandingsRow {
+        manager_id: entry.manager_id,
+        manager_name: entry.manager_name.clone(),
+        left: vec![
+            entry.current_rank.to_string(),
+            format!("({:+})", entry.previous_rank - entry.current_rank),
+        ],
+        right: vec![
+            entry.total_points.to_string(),
+            format!("({})pts", entry.gameweek_points),
+        ],
+    }
+}
+
+/// Entries fetched for a points gaps page: the page's entries, preceded by
+/// the entry above the page, which gives the first row's gap, if there is one
+///
+/// # Arguments
+/// * `start` - Position of the page's first entry (0-based)
+///
+/// # Returns
+/// * `(usize, usize)` - Position of the first entry to fetch and how many
+pub fn gap_window(start: usize) -> (usize, usize) {
+    let above = usize::from(start > 0);
+    (start - above, PAGE_SIZE + above)
+}
+
+/// Points gap rows
+///
+/// # Arguments
+/// * `entries` - The page's entries, preceded by the entry above the page if
+///   `has_above` is set
+/// * `has_above` - Whether the first entry only provides the first row's gap
+/// * `leader_points` - The league leader's total
+pub fn gap_rows(
+    entries: &[StandingsManager],
+    has_above: bool,
+    leader_points: i32,
+) -> Vec<StandingsRow> {
+    let skip = usize::from(has_above);
+    entries
+        .iter()
+        .enumerate()
+        .skip(skip)
+        .map(|(i, entry)| {
+            let above = i.checked_sub(1).map(|j| &entries[j]);
+            StandingsRow {
+                manager_id: entry.manager_id,
+                manager_name: entry.manager_name.clone(),
+                left: vec![entry.current_rank.to_string()],
+                right: vec![
+                    entry.total_points.to_string(),
+                    format!("{:+}", entry.total_points - leader_points),
+                    above.map_or("-".to_string(), |above| {
+                        format!("{:+}", entry.total_points - above.total_points)
+                    }),
+                ],
+            }
+        })
+        .collect()
+}
+
+/// Ranks a league's top entries by a re-ranking view's metric
+///
+/// # Returns
+/// * The first API page, every ranked entry with its row, and whether the
+///   league had more entries than were ranked
+async fn ranked_rows(
+    league_id: i32,
+    view: StandingsView,
+    progress: Option<&Progress>,
+) -> Result<(
+    Arc<LeagueStandings>,
+    Vec<(StandingsManager, StandingsRow)>,
+    bool,
+)> {
+    let limit = if view.needs_history() {
+        MAX_HISTORY_ENTRIES
+    } else {
+        MAX_RANKED_ENTRIES
+    };
+    let (standings, entries, truncated) = standings::top_entries(league_id, limit).await?;
+    let histories = if view.needs_history() {
+        fetch_histories(&entries, progress).await
+    } else {
+        HashMap::new()
+    };
+
+    let metric = |entry: &StandingsManager| -> Option<i32> {
+        match view {
+            StandingsView::Gameweek => Some(entry.gameweek_points),
+            StandingsView::Form => histories
+                .get(&entry.manager_id)
+                .map(|h| h.form(FORM_GAMEWEEKS)),
+            StandingsView::Value => histories
+                .get(&entry.manager_id)
+                .and_then(|h| h.latest())
+                .map(|gw| gw.value),
+            _ => Some(entry.total_points),
+        }
+    };
+    let ranked = rank_by_metric(entries.iter().map(|e| (e, metric(e))).collect());
+
+    let rows = ranked
+        .into_iter()
+        .map(|(rank, entry, value)| {
+            let shown = |value: Option<i32>, format: fn(i32) -> String| {
+                value.map_or("-".to_string(), format)
+            };
+            let right = match view {
+                StandingsView::Gameweek => vec![
+                    shown(value, |v| format!("{}pts", v)),
+                    format!("({})", entry.total_points),
+                ],
+                StandingsView::Form => vec![shown(value, |v| format!("{}pts", v))],
+                _ => vec![
+                    shown(value, format_money),
+                    shown(
+                        histories
+                            .get(&entry.manager_id)
+                            .and_then(|h| h.latest())
+                            .map(|gw| gw.bank),
+                        |bank| format!("({} itb)", format_money(bank)),
+                    ),
+                ],
+            };
+            let row = StandingsRow {
+                manager_id: entry.manager_id,
+                manager_name: entry.manager_name.clone(),
+                left: vec![rank.to_string()],
+                right,
+            };
+            (entry.clone(), row)
+        })
+        .collect();
+    Ok((standings, rows, truncated))
+}
+
+/// Appends each gameweek row's overall rank
+///
+/// Only the displayed page's histories are fetched, so the gameweek view can
+/// still rank up to [`MAX_RANKED_ENTRIES`] entries on their points alone.
+/// Rows whose history could not be fetched show "-".
+///
+/// # Errors
+/// Returns error if the gameweek's data cannot be fetched
+async fn add_live_ranks(
+    entries: &[StandingsManager],
+    rows: &mut [StandingsRow],
+    progress: Option<&Progress>,
+) -> Result<()> {
+    let live = LiveRanks::fetch().await?;
+    let histories = fetch_histories(entries, progress).await;
+    for (entry, row) in entries.iter().zip(rows.iter_mut()) {
+        let rank = histories
+            .get(&entry.manager_id)
+            .and_then(|history| live.rank(entry, history));
+        row.right.push(rank.unwrap_or_else(|| "-".to_string()));
+    }
+    Ok(())
+}
+
+/// Overall ranks during the current gameweek: FPL's own once the gameweek's
+/// data is checked, estimated from live points until then
+struct LiveRanks {
+    gameweek: i32,
+
+    /// `None` once FPL's ranks are final
+    estimator: Option<RankEstimator>,
+}
+
+impl LiveRanks {
+    /// Prepares the current gameweek's ranks
+    ///
+    /// # Errors
+    /// Returns error if the FPL API request fails or the season has not started
+    async fn fetch() -> Result<Self> {
+        let general = GeneralData::fetch().await?;
+        let event = general
+            .current_event()
+            .ok_or_else(|| anyhow!("The season has not started yet"))?;
+        Ok(Self {
+            gameweek: event.id,
+            estimator: (!event.data_checked)
+                .then(|| RankEstimator::new(&general.events, event.id, general.total_players)),
+        })
+    }
+
+    /// A manager's overall rank, prefixed with `~` while estimated
+    fn rank(&self, entry: &StandingsManager, history: &ManagerHistory) -> Option<String> {
+        match &self.estimator {
+            Some(estimator) => {
+                let start = StartingPosition::before(&history.current, self.gameweek);
+                let rank = estimator.estimate(start, entry.gameweek_points);
+                Some(format!("~{}", format_rank(rank)))
+            }
+            None => history
+                .current
+                .iter()
+                .find(|gw| gw.event == self.gameweek)
+                .and_then(|gw| gw.overall_rank)
+                .map(|rank| format_rank(i64::from(rank))),
+        }
+    }
+}
+
+/// Formats an overall rank compactly, e.g. `987`, `45.6k` or `1.23M`.
+pub fn format_rank(rank: i64) -> String {
+    if rank < 1_000 {
+        rank.to_string()
+    } else if rank < 1_000_000 {
+        format!("{:.1}k", rank as f64 / 1_000.0)
+    } else {
+        format!("{:.2}M", rank as f64 / 1_000_000.0)
+    }
+}
+
+/// Formats an FPL price in tenths of a million, e.g. `£100.5m`.
+pub fn format_money(tenths: i32) -> String {
+    format!("£{:.1}m", f64::from(tenths) / 10.0)
+}
+
+/// Fetches the season history of each entry, skipping any that fail
+///
+/// Also used by other league commands that need every member's history. At
+/// most [`MAX_CONCURRENT_REQUESTS`] histories are fetched at once.
+pub async fn fetch_histories(
+    entries: &[StandingsManager],
+    progress: Option<&Progress>,
+) -> HashMap<i32, Arc<ManagerHistory>> {
+    let limit = Arc::new(Semaphore::new(MAX_CONCURRENT_REQUESTS));
+    let mut requests = JoinSet::new();
+    for manager_id in entries.iter().map(|e| e.manager_id) {
+        let limit = limit.clone();
+        requests.spawn(async move {
+            let _permit = limit.acquire_owned().await;
+            (manager_id, ManagerHistory::fetch_cached(manager_id).await)
+        });
+    }
+
+    let total = entries.len();
+    let mut histories = HashMap::new();
+    let mut fetched = 0;
+    while let Some(joined) = requests.join_next().await {
+        fetched += 1;
+        if let Some(progress) = progress {
+            progress
+                .update(format!("Fetched {}/{} manager histories…", fetched, total))
+                .await;
+        }
+        match joined {
+            Ok((manager_id, Ok(history))) => {
+                histories.insert(manager_id, history);
+            }
+            Ok((manager_id, Err(e))) => {
+                warn!("Skipping history of manager {}: {}", manager_id, e);
+            }
+            Err(e) => error!("Manager history request panicked: {}", e),
+        }
+    }
+    histories
+}
+
+/// Builds the standings message for a page: the table, its buttons and the
+/// view menu
+///
+/// # Errors
+/// Returns error if the component custom IDs cannot be encoded
+pub fn build_standings_message(page: &StandingsPage) -> Result<Reply> {
+    let mut reply = Reply::new().embed(build_standings_embed(page));
+    for button in build_navigation_buttons(page)? {
+        reply = reply.button(button);
+    }
+    Ok(reply.select_menu(build_view_menu(page)?))
+}
+
 /// Builds a Discord embed displaying league standings
 ///
 /// Creates a formatted embed with standings data, including manager names, ranks,
-/// points, and gameweek performance. Uses fixed-width formatting for consistent alignment.
+/// and the view's statistics. Uses fixed-width formatting for consistent alignment.
 ///
 /// # Arguments
-/// * `standings` - The league standings data from FPL API
-/// * `page` - Current page number for pagination (0-based)
+/// * `page` - The page of standings to show
 ///
 /// # Returns
 /// * `CreateEmbed` - Discord embed with formatted standings table
 ///
 /// # Display Format
-/// Shows columns for: Rank, Change, Manager Name, Total Points, GW Points
-/// Uses code block formatting for monospace alignment
-pub fn build_standings_embed(standings: &LeagueStandings, page: usize) -> CreateEmbed {
-    let managers = &standings.standings.managers;
-    let per_page = 25;
-    let start_idx = (page * per_page) % 50;
-    let end_idx = (start_idx + 25).min(managers.len());
-    let page_managers = &managers[start_idx..end_idx];
-
-    // Calculate maximum widths needed for each column (from all managers for consistency)
-    let max_rank_width = managers
-        .iter()
-        .map(|m| number_len(m.current_rank))
-        .max()
-        .unwrap_or(2);
-
-    let max_change_width = managers
-        .iter()
-        .map(|m| number_len(-(m.current_rank - m.previous_rank)))
-        .max()
-        .unwrap_or(4)
-        + 3;
-
-    let max_points_width = managers
-        .iter()
-        .map(|m| number_len(m.total_points))
-        .max()
-        .unwrap_or(4);
+/// Shows the view's columns around the manager name, e.g. rank, change, name,
+/// total and GW points for the league table. Uses code block formatting for
+/// monospace alignment. The highlighted manager's rank is marked with `>`
+/// instead of `#`.
+pub fn build_standings_embed(page: &StandingsPage) -> CreateEmbed {
+    let rows = &page.rows;
+    let column_widths = |columns: fn(&StandingsRow) -> &Vec<String>| -> Vec<usize> {
+        let count = rows.iter().map(|r| columns(r).len()).max().unwrap_or(0);
+        (0..count)
+            .map(|i| {
+                rows.iter()
+                    .filter_map(|r| columns(r).get(i))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    };
+    let left_widths = column_widths(|r| &r.left);
+    let right_widths = column_widths(|r| &r.right);
 
-    let max_gw_width = managers
-        .iter()
-        .map(|m| number_len(m.gameweek_points))
-        .max()
-        .unwrap_or(4)
-        + 2;
-
-    let separators_width: usize = 7;
-    let fixed_width =
-        max_rank_width + max_change_width + max_points_width + max_gw_width + separators_width;
+    // marker, cells with their spaces, and the two " | " separators
+    let fixed_width = 1
+        + left_widths.iter().map(|w| w + 1).sum::<usize>()
+        + right_widths.iter().map(|w| w + 1).sum::<usize>()
+        + 4;
     let total_available: usize = 40;
     let name_width = total_available.saturating_sub(fixed_width).max(5); // minimum 5 chars for names
 
+    let pad = |cells: &[String], widths: &[usize]| -> String {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+
     let mut description = String::new();
+    if let Some(legend) = legend(page) {
+        description.push_str(&legend);
+        description.push('\n');
+    }
     description.push_str("```");
-    // description.push_str(format!("{}{}{}{}", max_rank_width, max_change_width, max_points_width, max_gw_width).as_str());
-    for manager in page_managers.iter() {
-        let name = format_name(manager, name_width);
-        let rank_diff = -(manager.current_rank - manager.previous_rank);
-
+    for row in rows.iter() {
+        let marker = if page.highlight == Some(row.manager_id) {
+            '>'
+        } else {
+            '#'
+        };
         description.push_str(&format!(
-            "#{rank:<rank_width$}{change:<change_width$}| {name:<name_width$} | {total:<points_width$} {gw:<gw_width$}pts\n",
-            rank = manager.current_rank,
-            change = format!("({:+})", rank_diff),
-            name = name,
-            total = manager.total_points,
-            gw = format!("({})", manager.gameweek_points),
-            rank_width = max_rank_width,
-            change_width = max_change_width,
-            name_width = name_width,
-            points_width = max_points_width,
-            gw_width = max_gw_width
+            "{}{} | {:<name_width$} | {}\n",
+            marker,
+            pad(&row.left, &left_widths),
+            format_name(&row.manager_name, name_width),
+            pad(&row.right, &right_widths),
+            name_width = name_width
         ));
     }
+    if rows.is_empty() {
+        description.push_str("No entries on this page\n");
+    }
 
     description.push_str("```");
 
-    let total_pages = if standings.standings.has_next {
-        "?".to_string()
-    } else {
-        format!("{}", (managers.len() + per_page - 1) / per_page)
+    let position = match (page.total_pages(), page.total_entries) {
+        (Some(pages), Some(entries)) => {
+            format!("Page {} of {} • {} entries", page.page + 1, pages, entries)
+        }
+        _ => format!("Page {}", page.page + 1),
+    };
+
+    let title = match page.view {
+        StandingsView::Total => format!("🏆  {}", page.standings.league_info.league_name),
+        view => format!(
+            "🏆  {} • {}",
+            page.standings.league_info.league_name,
+            view.label()
+        ),
     };
 
     CreateEmbed::new()
-        .title(format!("🏆  {}", standings.league_info.league_name))
+        .title(title)
         .description(description)
         .color(0x37003c) // purple
         .footer(serenity::builder::CreateEmbedFooter::new(format!(
-            "League ID: {} • Page {} of {}",
-            standings.league_info.id,
-            page + 1,
-            total_pages
+            "League ID: {} • {}",
+            page.standings.league_info.id, position
         )))
 }
 
-/// Navigation button configuration for standings pagination
-pub struct NavigationButtons {
-    pub prev: CreateButton,
-    pub next: CreateButton,
-    pub refresh: CreateButton,
+/// Explains the columns of views other than the league table
+fn legend(page: &StandingsPage) -> Option<String> {
+    let legend = match page.view {
+        StandingsView::Total => return None,
+        StandingsView::Gameweek => {
+            "Points this gameweek (season total) and overall rank, ~ while estimated live"
+                .to_string()
+        }
+        StandingsView::Form => format!(
+            "Points over the last {} gameweeks, after hits",
+            FORM_GAMEWEEKS
+        ),
+        StandingsView::Gaps => "Total, points behind the leader and the place above".to_string(),
+        StandingsView::Value => "Team value (money in the bank)".to_string(),
+    };
+    Some(if page.truncated {
+        let ranked = if page.view.needs_history() {
+            MAX_HISTORY_ENTRIES
+        } else {
+            MAX_RANKED_ENTRIES
+        };
+        format!("{}. Covers the top {} of the league.", legend, ranked)
+    } else {
+        legend
+    })
+}
+
+/// Starts a standings custom ID carrying a page's league, view and highlight
+fn page_id(page: &StandingsPage, action: &str) -> ComponentId {
+    let mut id = ComponentId::new("standings", COMPONENT_VERSION, action)
+        .league(page.standings.league_info.id)
+        .page(page.page as u32)
+        .sort(page.view.as_str());
+    if let Some(manager_id) = page.highlight {
+        id = id.manager(manager_id);
+    }
+    id
 }
 
 /// Creates navigation buttons for standings pagination
 ///
-/// Builds previous, next, and refresh buttons with appropriate enabled/disabled states
-/// based on current page position and available data.
+/// Builds first, previous, next, last, refresh, jump-to-page and "find me"
+/// buttons with appropriate enabled/disabled states based on the current page
+/// position and the league's size.
 ///
 /// # Arguments
-/// * `page` - Current page number (0-based)
-/// * `standings` - League standings data to determine pagination limits
+/// * `page` - The page of standings the buttons navigate from
 ///
 /// # Returns
-/// * `NavigationButtons` - Struct containing the three navigation buttons
+/// * `Result<Vec<CreateButton>>` - The buttons, in display order
+///
+/// # Errors
+/// Returns error if the button custom IDs cannot be encoded
 ///
 /// # Button Behavior
-/// - Previous: Disabled on first page
-/// - Next: Disabled on last page (when no more data available)
-/// - Refresh: Always enabled
-pub fn build_navigation_buttons(page: usize, standings: &LeagueStandings) -> NavigationButtons {
-    let per_page = 25;
-    let total_managers = standings.standings.managers.len();
-    let api_has_next = standings.standings.has_next;
-    let total_pages_current = (total_managers + per_page - 1) / per_page;
-    let has_prev = page > 0;
-    let has_next = page + 1 < total_pages_current || api_has_next;
-
-    NavigationButtons {
-        prev: CreateButton::new(format!("standings_prev_{}", page))
-            .label("⬅️ Previous")
-            .style(ButtonStyle::Secondary)
-            .disabled(!has_prev),
-        next: CreateButton::new(format!("standings_next_{}", page))
-            .label("Next ➡️")
+/// - First/Previous: Disabled on first page
+/// - Next: Disabled on last page
+/// - Last and Jump: Disabled when the league is too large to count
+/// - Refresh and Find me: Always enabled
+pub fn build_navigation_buttons(page: &StandingsPage) -> Result<Vec<CreateButton>> {
+    let has_prev = page.page > 0;
+    let counted = page.total_pages().is_some();
+
+    let button = |action: &str, label: &str, enabled: bool| -> Result<CreateButton> {
+        Ok(CreateButton::new(page_id(page, action).encode()?)
+            .label(label)
             .style(ButtonStyle::Secondary)
-            .disabled(!has_next),
-        refresh: CreateButton::new(format!("standings_refresh_{}", page))
-            .label("🔄 Refresh")
-            .style(ButtonStyle::Primary),
+            .disabled(!enabled))
+    };
+
+    Ok(vec![
+        button("first", "⏮️ First", has_prev)?,
+        button("prev", "⬅️ Previous", has_prev)?,
+        button("next", "Next ➡️", page.has_next())?,
+        button("last", "Last ⏭️", counted && page.has_next())?,
+        button("refresh", "🔄 Refresh", true)?.style(ButtonStyle::Primary),
+        button("jump", "🔢 Go to page", counted)?,
+        button("me", "📍 Find me", true)?,
+    ])
+}
+
+/// Creates the menu switching a standings message between views
+///
+/// # Errors
+/// Returns error if the menu custom ID cannot be encoded
+pub fn build_view_menu(page: &StandingsPage) -> Result<CreateSelectMenu> {
+    let options = StandingsView::ALL
+        .into_iter()
+        .map(|view| {
+            CreateSelectMenuOption::new(view.label(), view.as_str())
+                .default_selection(view == page.view)
+        })
+        .collect();
+    Ok(CreateSelectMenu::new(
+        page_id(page, "view").encode()?,
+        CreateSelectMenuKind::String { options },
+    )
+    .placeholder("Change view"))
+}
+
+/// Handles standings-specific component interactions
+///
+/// Processes the navigation buttons and view menu of a standings message. The
+/// league, view, page and highlighted manager are read from the component
+/// custom ID. Responses are deferred, since re-ranked views may fetch every
+/// manager's history.
+///
+/// # Arguments
+/// * `ctx` - The Discord context for making API calls
+/// * `component` - The component interaction data with standings-specific custom ID
+///
+/// # Custom ID Format
+/// A [`ComponentId`] for `standings` carrying the league ID, the current
+/// 0-based page, the view as its sort and any highlighted manager, with action:
+/// - `first`, `prev`, `next`, `last` or `refresh` - Update the message
+/// - `view` - Switch the message to the view chosen in the menu
+/// - `jump` - Open a modal asking for a page number
+/// - `me` - Show the caller's linked manager's page privately
+pub async fn handle_component(ctx: &Context, component: &ComponentInteraction) {
+    let id = match ComponentId::parse(&component.data.custom_id, "standings", COMPONENT_VERSION) {
+        Ok(id) => id,
+        Err(e) => return respond_error(ctx, component, e).await,
+    };
+
+    match id.action.as_str() {
+        "jump" => {
+            let response = match jump_modal(&id) {
+                Ok(modal) => modal,
+                Err(e) => return respond_error(ctx, component, e).await,
+            };
+            if let Err(why) = component.create_response(&ctx.http, response).await {
+                info!("Cannot open standings page modal: {}", why);
+            }
+        }
+        "me" => {
+            if let Err(why) = component.defer_ephemeral(&ctx.http).await {
+                info!("Cannot defer standings find me: {}", why);
+                return;
+            }
+            let edit = match find_me(component.user.id, &id).await {
+                Ok(reply) => reply.into_edit(),
+                Err(e) => EditInteractionResponse::new().content(format!("Error: {}", e)),
+            };
+            if let Err(why) = component.edit_response(&ctx.http, edit).await {
+                info!("Cannot show standings find me: {}", why);
+            }
+        }
+        _ => {
+            if let Err(why) = component.defer(&ctx.http).await {
+                info!("Cannot defer standings update: {}", why);
+                return;
+            }
+            match navigate(component, &id).await {
+                Ok(reply) => {
+                    if let Err(why) = component.edit_response(&ctx.http, reply.into_edit()).await {
+                        info!("Cannot update standings message: {}", why);
+                    }
+                }
+                Err(e) => {
+                    let followup = CreateInteractionResponseFollowup::new()
+                        .content(format!("Error: {}", e))
+                        .ephemeral(true);
+                    if let Err(why) = component.create_followup(&ctx.http, followup).await {
+                        info!("Cannot report standings error: {}", why);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Handles the jump-to-page modal opened by the `jump` button
+///
+/// Loading a page can take many FPL requests, so the interaction is deferred
+/// first and the standings message edited once the page is ready.
+pub async fn handle_modal(ctx: &Context, modal: &ModalInteraction) {
+    if let Err(why) = modal.defer(&ctx.http).await {
+        info!("Cannot defer standings page jump: {}", why);
+        return;
+    }
+    match jump_page(modal).await {
+        Ok(reply) => {
+            if let Err(why) = modal.edit_response(&ctx.http, reply.into_edit()).await {
+                info!("Cannot jump to standings page: {}", why);
+            }
+        }
+        Err(e) => {
+            let followup = CreateInteractionResponseFollowup::new()
+                .content(format!("Error: {}", e))
+                .ephemeral(true);
+            if let Err(why) = modal.create_followup(&ctx.http, followup).await {
+                info!("Cannot report standings error: {}", why);
+            }
+        }
     }
 }
 
+fn error_response(e: anyhow::Error) -> CreateInteractionResponse {
+    CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(format!("Error: {}", e))
+            .ephemeral(true),
+    )
+}
+
+async fn respond_error(ctx: &Context, component: &ComponentInteraction, e: anyhow::Error) {
+    if let Err(why) = component
+        .create_response(&ctx.http, error_response(e))
+        .await
+    {
+        info!("Cannot report standings error: {}", why);
+    }
+}
+
+/// The view a standings custom ID was created in.
+fn id_view(id: &ComponentId) -> StandingsView {
+    id.state
+        .sort
+        .as_deref()
+        .and_then(|view| view.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Builds the updated standings message for a navigation button or the menu
+async fn navigate(component: &ComponentInteraction, id: &ComponentId) -> Result<Reply> {
+    let league_id = id.league_id()?;
+    let current_page = id.state.page.unwrap_or(0) as usize;
+    let mut view = id_view(id);
+
+    let new_page = match id.action.as_str() {
+        "first" => 0,
+        "prev" => current_page.saturating_sub(1),
+        "next" => current_page + 1,
+        // reranked views are always counted, and clamped to the last page when loaded
+        "last" if view.is_reranked() => usize::MAX,
+        "last" => match standings::total_entries(league_id).await? {
+            Some(total) => page_count(total, PAGE_SIZE) - 1,
+            None => return Err(anyhow!("This league is too large to find its last page")),
+        },
+        "refresh" => {
+            standings::invalidate(league_id);
+            current_page
+        }
+        "view" => {
+            if let ComponentInteractionDataKind::StringSelect { values } = &component.data.kind {
+                if let Some(chosen) = values.first() {
+                    view = chosen.parse()?;
+                }
+            }
+            0
+        }
+        _ => current_page,
+    };
+
+    let page = StandingsPage::load(league_id, view, new_page, id.state.manager_id, None)
+        .await
+        .map_err(|e| anyhow!("Could not fetch standings: {}", e))?;
+    build_standings_message(&page)
+}
+
+/// Opens a modal asking which page to jump to
+fn jump_modal(id: &ComponentId) -> Result<CreateInteractionResponse> {
+    let mut modal_id = ComponentId::new("standings", COMPONENT_VERSION, "goto")
+        .league(id.league_id()?)
+        .sort(id_view(id).as_str());
+    if let Some(manager_id) = id.state.manager_id {
+        modal_id = modal_id.manager(manager_id);
+    }
+
+    let input = CreateInputText::new(InputTextStyle::Short, "Page number", "page")
+        .placeholder(format!(
+            "Currently on page {}",
+            id.state.page.unwrap_or(0) + 1
+        ))
+        .min_length(1)
+        .max_length(6);
+    Ok(CreateInteractionResponse::Modal(
+        CreateModal::new(modal_id.encode()?, "Go to page")
+            .components(vec![CreateActionRow::InputText(input)]),
+    ))
+}
+
+/// Builds the standings page entered in the jump modal
+async fn jump_page(modal: &ModalInteraction) -> Result<Reply> {
+    let id = ComponentId::parse(&modal.data.custom_id, "standings", COMPONENT_VERSION)?;
+    let league_id = id.league_id()?;
+
+    let input = modal
+        .data
+        .components
+        .iter()
+        .flat_map(|row| &row.components)
+        .find_map(|component| match component {
+            ActionRowComponent::InputText(text) if text.custom_id == "page" => {
+                text.value.as_deref()
+            }
+            _ => None,
+        })
+        .unwrap_or_default();
+    let page = input
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|page| *page > 0)
+        .ok_or_else(|| anyhow!("{:?} is not a page number", input))?;
+
+    let page =
+        StandingsPage::load(league_id, id_view(&id), page - 1, id.state.manager_id, None).await?;
+    build_standings_message(&page)
+}
+
+/// Loads the page holding a user's linked manager, with their row marked
+///
+/// # Errors
+/// Returns error if the user has not linked a manager, the manager is not in
+/// the league, or FPL API requests fail
+async fn find_me(user_id: UserId, id: &ComponentId) -> Result<Reply> {
+    let league_id = id.league_id()?;
+    let view = id_view(id);
+    let manager_id = db_service()
+        .get_user(user_id)
+        .await
+        .ok()
+        .and_then(|user| user.manager_id)
+        .ok_or_else(|| anyhow!("Link your FPL team with /managers link first"))?;
+
+    let manager = Manager::fetch(manager_id).await?;
+    let rank = manager
+        .league_rank(league_id)
+        .ok_or_else(|| anyhow!("{} is not in this league", manager.team_name))?;
+    let not_found = || anyhow!("Could not find {} in the standings", manager.team_name);
+    let position = if view.is_reranked() {
+        let (_, ranked, _) = ranked_rows(league_id, view, None).await?;
+        ranked
+            .iter()
+            .position(|(entry, _)| entry.manager_id == manager_id)
+            .ok_or_else(|| {
+                anyhow!(
+                    "{} is outside the part of the league this view ranks",
+                    manager.team_name
+                )
+            })?
+    } else {
+        standings::find_manager(league_id, manager_id, rank)
+            .await?
+            .ok_or_else(not_found)?
+    };
+
+    info!(
+        "Found manager {} at position {} of league {} ({} view)",
+        manager_id, position, league_id, view
+    );
+    let page = StandingsPage::load(
+        league_id,
+        view,
+        position / PAGE_SIZE,
+        Some(manager_id),
+        None,
+    )
+    .await?;
+    build_standings_message(&page)
+}
+
 /// Registers the standings command with Discord
 ///
-/// Creates the command definition for the `/standings` slash command with required
-/// league_id parameter.
+/// Creates the command definition for the `/standings` slash command with optional
+/// league_id, league and view parameters.
 ///
 /// # Returns
 /// * `CreateCommand` - Discord command definition ready for registration
@@ -274,6 +1034,15 @@ pub fn register() -> CreateCommand {
             CreateCommandOption::new(CommandOptionType::Integer, "league_id", "The FPL league ID")
                 .required(false),
         )
+        .add_option(league_option())
+        .add_option(StandingsView::ALL.into_iter().fold(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                VIEW_OPTION,
+                "How to rank the league (defaults to the league table)",
+            ),
+            |option, view| option.add_string_choice(view.label(), view.as_str()),
+        ))
 }
 
 /// Formats manager name to fit within specified width
@@ -284,19 +1053,16 @@ pub fn register() -> CreateCommand {
 /// 3. Use "First." if still too long
 ///
 /// # Arguments
-/// * `manager` - The manager data containing the name
+/// * `manager_name` - The manager's name
 /// * `name_width` - Maximum character width allowed
 ///
 /// # Returns
 /// * `Cow<str>` - Formatted name that fits within the width constraint
-fn format_name(manager: &'_ StandingsManager, name_width: usize) -> Cow<'_, str> {
-    let name: Cow<str> = if manager.manager_name.chars().count() <= name_width {
-        Cow::Borrowed(&manager.manager_name)
+pub fn format_name(manager_name: &str, name_width: usize) -> Cow<'_, str> {
+    let name: Cow<str> = if manager_name.chars().count() <= name_width {
+        Cow::Borrowed(manager_name)
     } else {
-        let (first_name, last_name) = manager
-            .manager_name
-            .split_once(" ")
-            .unwrap_or((&manager.manager_name, ""));
+        let (first_name, last_name) = manager_name.split_once(" ").unwrap_or((manager_name, ""));
         // creates truncated name like "John S."
         let truncated = format!(
             "{} {}.",
@@ -339,3 +1105,42 @@ fn number_len(mut num: i32) -> usize {
     }
     count
 }
+
+/// The `/standings` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct StandingsCommand;
+
+#[async_trait]
+impl SlashCommand for StandingsCommand {
+    fn name(&self) -> &'static str {
+        "standings"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command, progress).await
+    }
+
+    async fn autocomplete(&self, ctx: &Context, interaction: &CommandInteraction) {
+        crate::bot::leagues::autocomplete(ctx, interaction).await
+    }
+
+    fn component_prefixes(&self) -> &'static [&'static str] {
+        &["standings:"]
+    }
+
+    async fn handle_component(&self, ctx: &Context, component: &ComponentInteraction) {
+        handle_component(ctx, component).await
+    }
+
+    async fn handle_modal(&self, ctx: &Context, modal: &ModalInteraction) {
+        handle_modal(ctx, modal).await
+    }
+}
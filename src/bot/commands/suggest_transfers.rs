@@ -0,0 +1,408 @@
+//! Suggest transfers command implementation
+//!
+//! Provides the `/suggest_transfers` slash command, which searches for the
+//! transfers that add the most projected points to the caller's linked squad
+//! over the next few gameweeks, and explains each move with the players'
+//! fixtures and projections.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use log::info;
+use serenity::all::{CommandInteraction, Context, ResolvedValue};
+use serenity::async_trait;
+use serenity::builder::{CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter};
+use serenity::model::application::CommandOptionType;
+
+use crate::bot::commands::standings::format_money;
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::database::service::db_service;
+use crate::fpl::models::fixtures::{fetch_all_fixtures, Fixture};
+use crate::fpl::models::general::GeneralData;
+use crate::fpl::models::history::ManagerHistory;
+use crate::fpl::models::picks::ManagerPicks;
+use crate::fpl::models::teams::get_team_name;
+use crate::fpl::models::transfers::{fetch_transfers, ManagerTransfer};
+use crate::fpl::projection::project;
+use crate::fpl::squad::Position;
+use crate::fpl::transfers::{
+    apply_pending_transfers, free_transfers, selling_price, suggest, TransferPlan, TransferPlayer,
+    MAX_TRANSFERS,
+};
+
+/// Longest horizon, in gameweeks.
+const MAX_HORIZON: i32 = 6;
+
+/// Horizon used when none is given.
+const DEFAULT_HORIZON: i32 = 3;
+
+/// Plans listed for each number of transfers.
+const PLANS_PER_COUNT: usize = 3;
+
+/// Registers the suggest transfers command with Discord
+///
+/// # Returns
+/// * `CreateCommand` - Discord command definition ready for registration
+pub fn register() -> CreateCommand {
+    CreateCommand::new("suggest_transfers")
+        .description("Transfers that add the most projected points to your squad")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "transfers",
+                "Most transfers to make, with hits beyond your free transfers (defaults to 2)",
+            )
+            .min_int_value(1)
+            .max_int_value(MAX_TRANSFERS as u64)
+            .required(false),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "horizon",
+                "Gameweeks of projected points to add up (defaults to 3)",
+            )
+            .min_int_value(1)
+            .max_int_value(MAX_HORIZON as u64)
+            .required(false),
+        )
+}
+
+/// Main handler for the `/suggest_transfers` slash command
+///
+/// Loads the caller's squad, bank, free transfers and what they paid for each
+/// player, after any transfers already made for the next deadline, projects
+/// every player over the horizon and lists the best plans for each number of
+/// transfers against rolling the transfer.
+///
+/// # Arguments
+/// * `_ctx` - Discord context (unused in current implementation)
+/// * `command` - The slash command interaction containing user input
+/// * `progress` - Progress handle updated while the search runs
+///
+/// # Returns
+/// * `Result<Reply>` - Discord response with the suggested transfers
+///
+/// # Errors
+/// Returns error if:
+/// - The caller has not linked an FPL manager
+/// - The season has not started or has no gameweeks left
+/// - FPL API requests fail
+///
+/// # Example Usage
+/// `/suggest_transfers`
+/// `/suggest_transfers transfers:1 horizon:5`
+pub async fn run(
+    _ctx: &Context,
+    command: &CommandInteraction,
+    progress: &Progress,
+) -> Result<Reply> {
+    let mut max_transfers = MAX_TRANSFERS;
+    let mut horizon = DEFAULT_HORIZON;
+    for option in command.data.options() {
+        match (option.name, option.value) {
+            ("transfers", ResolvedValue::Integer(n)) => {
+                max_transfers = (n as usize).clamp(1, MAX_TRANSFERS);
+            }
+            ("horizon", ResolvedValue::Integer(n)) => {
+                horizon = (n as i32).clamp(1, MAX_HORIZON);
+            }
+            _ => {}
+        }
+    }
+
+    let manager_id = db_service()
+        .get_user(command.user.id)
+        .await?
+        .manager_id
+        .ok_or_else(|| {
+            anyhow!("No manager_id configured for you. Use /update_manager_id please!")
+        })?;
+    info!(
+        "Suggesting up to {} transfers over {} gameweeks for manager {} (user {})",
+        max_transfers, horizon, manager_id, command.user.name
+    );
+
+    let general = GeneralData::fetch().await?;
+    let current = general
+        .current_event()
+        .ok_or_else(|| anyhow!("The season has not started yet"))?
+        .id;
+    let first = general
+        .next_event()
+        .ok_or_else(|| anyhow!("There are no gameweeks left to transfer for"))?
+        .id;
+    let last =
+        (first + horizon - 1).min(general.events.iter().map(|e| e.id).max().unwrap_or(first));
+
+    let (picks, history, transfers, fixtures) = tokio::try_join!(
+        ManagerPicks::fetch_upcoming(manager_id, current),
+        ManagerHistory::fetch_cached(manager_id),
+        fetch_transfers(manager_id),
+        fetch_all_fixtures(),
+    )?;
+    let mut elements: Vec<i32> = picks.picks.iter().map(|pick| pick.element).collect();
+    let bank = apply_pending_transfers(&mut elements, picks.entry_history.bank, &transfers, first);
+    let pending = transfers.iter().filter(|t| t.event == first).count() as i32;
+    let free = free_transfers(&history.current, &history.chips, first, pending);
+
+    let projections: HashMap<i32, Vec<f64>> = project(&general, &fixtures, first..=last)
+        .into_iter()
+        .map(|projection| {
+            let points = projection.gameweeks.iter().map(|(_, p)| *p).collect();
+            (projection.element, points)
+        })
+        .collect();
+    let pool: Vec<TransferPlayer> = general
+        .elements
+        .iter()
+        .filter_map(|player| {
+            Some(TransferPlayer {
+                element: player.id,
+                position: Position::from_element_type(player.element_type)?,
+                team: player.team,
+                price: player.now_cost,
+                points: projections.get(&player.id)?.clone(),
+            })
+        })
+        .collect();
+    let squad: Vec<TransferPlayer> = elements
+        .iter()
+        .filter_map(|&element| {
+            let player = general.player(element)?;
+            let mut squad_player = pool.iter().find(|p| p.element == element)?.clone();
+            let purchase = purchase_price(&transfers, element)
+                .unwrap_or(player.now_cost - player.cost_change_start);
+            squad_player.price = selling_price(purchase, player.now_cost);
+            Some(squad_player)
+        })
+        .collect();
+    if squad.len() != elements.len() {
+        return Err(anyhow!(
+            "Some of your players could not be found in FPL's data"
+        ));
+    }
+
+    progress.update("Searching transfers…").await;
+    let (search_squad, search_pool) = (squad.clone(), pool.clone());
+    let plans = tokio::task::spawn_blocking(move || {
+        suggest(
+            &search_squad,
+            &search_pool,
+            bank,
+            free,
+            max_transfers,
+            PLANS_PER_COUNT,
+        )
+    })
+    .await?;
+
+    let mut players: HashMap<i32, &TransferPlayer> = pool.iter().map(|p| (p.element, p)).collect();
+    // squad players are shown at their selling price
+    players.extend(squad.iter().map(|p| (p.element, p)));
+    let context = Explainer {
+        players,
+        first,
+        general: &general,
+        fixtures: &fixtures,
+    };
+    Ok(Reply::new().embed(build_embed(&plans, bank, free, last, &context)))
+}
+
+/// The price paid for a player at their latest purchase, if they were bought
+/// with a transfer rather than picked in the initial squad
+fn purchase_price(transfers: &[ManagerTransfer], element: i32) -> Option<i32> {
+    transfers
+        .iter()
+        .filter(|transfer| transfer.element_in == element)
+        .max_by_key(|transfer| transfer.time)
+        .map(|transfer| transfer.element_in_cost)
+}
+
+/// What is needed to explain a move: each player's projections and fixtures
+struct Explainer<'a> {
+    players: HashMap<i32, &'a TransferPlayer>,
+
+    /// First gameweek of the horizon
+    first: i32,
+
+    general: &'a GeneralData,
+
+    fixtures: &'a [Fixture],
+}
+
+impl Explainer<'_> {
+    /// Explains one side of a move, e.g.
+    /// "↳ In **Palmer** CHE £10.5m • 16.0 pts: BUR (H) 6.2 • WOL (A) 5.1 • blank"
+    fn line(&self, label: &str, element: i32) -> Option<String> {
+        let player = self.players.get(&element)?;
+        let gameweeks: Vec<String> = player
+            .points
+            .iter()
+            .enumerate()
+            .map(|(offset, points)| {
+                let gameweek = self.first + offset as i32;
+                let opponents = opponents(player.team, gameweek, self.fixtures);
+                if opponents.is_empty() {
+                    "blank".to_string()
+                } else {
+                    format!("{} {:.1}", opponents.join(" + "), points)
+                }
+            })
+            .collect();
+        Some(format!(
+            "↳ {} **{}** {} {} • {:.1} pts: {}",
+            label,
+            self.general.player_name(element),
+            get_team_name(player.team).short_name,
+            format_money(player.price),
+            player.total(),
+            gameweeks.join(" • ")
+        ))
+    }
+}
+
+/// A team's opponents in a gameweek, e.g. ["BUR (H)", "WOL (A)"]
+pub fn opponents(team: i32, gameweek: i32, fixtures: &[Fixture]) -> Vec<String> {
+    fixtures
+        .iter()
+        .filter(|fixture| fixture.event.map(i32::from) == Some(gameweek))
+        .filter_map(|fixture| {
+            if fixture.team_h == team {
+                Some(format!("{} (H)", get_team_name(fixture.team_a).short_name))
+            } else if fixture.team_a == team {
+                Some(format!("{} (A)", get_team_name(fixture.team_h).short_name))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Builds the embed: the recommendation, then the best plans for each number
+/// of transfers with the top one explained move by move
+fn build_embed(
+    plans: &[TransferPlan],
+    bank: i32,
+    free: i32,
+    last: i32,
+    explainer: &Explainer,
+) -> CreateEmbed {
+    let general = explainer.general;
+    let range = if explainer.first == last {
+        format!("GW{}", last)
+    } else {
+        format!("GW{}-{}", explainer.first, last)
+    };
+    let roll = &plans[0];
+    let best = plans
+        .iter()
+        .max_by(|a, b| a.net_points().total_cmp(&b.net_points()))
+        .unwrap_or(roll);
+
+    let mut description = format!(
+        "**Bank:** {} • **Free transfers:** {} • **Squad projects:** {:.1} pts\n\n",
+        format_money(bank),
+        free,
+        roll.points
+    );
+    if best.transfers.is_empty() {
+        description.push_str("✅ **Recommended:** roll the transfer, no move beats your squad");
+    } else {
+        description.push_str(&format!(
+            "✅ **Recommended:** {} ({})",
+            moves(best, general),
+            gain(best, roll)
+        ));
+    }
+
+    let mut embed = CreateEmbed::new()
+        .title(format!("🔁 Transfer suggestions {}", range))
+        .description(description)
+        .color(0x00ff87) // green
+        .footer(CreateEmbedFooter::new(
+            "Projected XI plus captain per gameweek • Selling prices keep half of each rise",
+        ));
+
+    for count in 1..=MAX_TRANSFERS {
+        let group: Vec<&TransferPlan> = plans
+            .iter()
+            .filter(|plan| plan.transfers.len() == count)
+            .collect();
+        if group.is_empty() {
+            continue;
+        }
+        let mut lines = Vec::new();
+        for (i, plan) in group.iter().enumerate() {
+            lines.push(format!(
+                "**{}. {}** {} • {} itb",
+                i + 1,
+                moves(plan, general),
+                gain(plan, roll),
+                format_money(plan.bank)
+            ));
+            if i == 0 {
+                for transfer in &plan.transfers {
+                    lines.extend(explainer.line("Out", transfer.element_out));
+                    lines.extend(explainer.line("In", transfer.element_in));
+                }
+            }
+        }
+        let name = if count == 1 {
+            "Best single transfers".to_string()
+        } else {
+            format!("Best {} transfers", count)
+        };
+        embed = embed.field(name, lines.join("\n"), false);
+    }
+    embed
+}
+
+/// Transfers as "Saka → Palmer, Gabriel → Saliba"
+fn moves(plan: &TransferPlan, general: &GeneralData) -> String {
+    plan.transfers
+        .iter()
+        .map(|t| {
+            format!(
+                "{} → {}",
+                general.player_name(t.element_out),
+                general.player_name(t.element_in)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Net gain over rolling the transfer, e.g. "+6.1 pts after -4 hit"
+fn gain(plan: &TransferPlan, roll: &TransferPlan) -> String {
+    let gain = plan.net_points() - roll.net_points();
+    if plan.hit > 0 {
+        format!("{:+.1} pts after -{} hit", gain, plan.hit)
+    } else {
+        format!("{:+.1} pts", gain)
+    }
+}
+
+/// The `/suggest_transfers` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct SuggestTransfersCommand;
+
+#[async_trait]
+impl SlashCommand for SuggestTransfersCommand {
+    fn name(&self) -> &'static str {
+        "suggest_transfers"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command, progress).await
+    }
+}
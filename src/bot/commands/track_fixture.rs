@@ -22,7 +22,8 @@ use serenity::builder::{CreateCommand, CreateCommandOption};
 /// * `CreateCommand` - Discord command definition ready for registration
 ///
 /// # TODO
-/// Implement the actual command handler and tracking functionality
+/// Implement the actual command handler and tracking functionality, then add a
+/// `SlashCommand` implementation to [`super::all`] so the command is served
 pub fn register() -> CreateCommand {
     CreateCommand::new("track_fixture")
         .description("Get updates on given fixture")
@@ -5,10 +5,14 @@ use serenity::all::{
     ChannelId, CommandInteraction, Context, CreateInteractionResponse,
     CreateInteractionResponseMessage, ResolvedOption, ResolvedValue,
 };
+use serenity::async_trait;
 use serenity::builder::{CreateCommand, CreateCommandOption};
 use serenity::model::application::CommandOptionType;
 
-use crate::database::models::DBChannel;
+use crate::bot::permissions::{audit, Access};
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
+use crate::database::models::DBChannelLeague;
 use crate::database::{models::DBUser, service::db_service};
 use crate::utils::type_conversion::r_option_to_i32;
 
@@ -25,10 +29,7 @@ pub fn register() -> CreateCommand {
         )
 }
 
-pub async fn run(
-    _ctx: &Context,
-    command: &CommandInteraction,
-) -> Result<CreateInteractionResponse> {
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
     let channel_id: ChannelId = command.channel_id;
 
     let league_id = r_option_to_i32(&command.data.options()[0])?;
@@ -39,17 +40,30 @@ pub async fn run(
         league_id, channel_id
     );
 
+    let primary = db_service()
+        .get_channel_leagues(channel_id)
+        .await?
+        .into_iter()
+        .find(|l| l.is_primary);
+    let previous = primary.as_ref().map(|l| l.league_id);
+    // replaces the primary league, keeping its name
+    let name = primary.map_or("main".to_string(), |l| l.name);
+
     let res = db_service()
-        .update_channel(&DBChannel {
+        .upsert_channel_league(&DBChannelLeague {
             channel_id: channel_id.into(),
-            default_league_id: Some(league_id),
+            name,
+            league_id,
+            is_primary: true,
         })
         .await;
 
     match res {
-        Ok(_) => Ok(CreateInteractionResponse::Message(
-            CreateInteractionResponseMessage::new().content("channel league_id update succesful"),
-        )),
+        Ok(_) => {
+            let previous = previous.map_or("none".to_string(), |id| id.to_string());
+            audit(command, format!("league {} -> {}", previous, league_id)).await;
+            Ok(Reply::new().content("channel league_id update succesful"))
+        }
         Err(e) => {
             error!(
                 "Failed to update league_id for channel {}: {}",
@@ -59,3 +73,30 @@ pub async fn run(
         }
     }
 }
+
+/// The `/update_channel_league_id` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct UpdateChannelLeagueIdCommand;
+
+#[async_trait]
+impl SlashCommand for UpdateChannelLeagueIdCommand {
+    fn name(&self) -> &'static str {
+        "update_channel_league_id"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    fn access(&self) -> Access {
+        Access::Managers
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
+    }
+}
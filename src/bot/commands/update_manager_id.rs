@@ -2,17 +2,18 @@
 //!
 //! Provides functionality for users to manage their FPL manager association
 //! within the Discord bot, including setting and updating manager IDs.
+//! Linking itself lives in [`managers`](super::managers).
 
 use anyhow::{anyhow, Result};
 use log::{error, info};
-use serenity::all::{
-    CommandInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
-    ResolvedOption, ResolvedValue,
-};
+use serenity::all::{CommandInteraction, Context, ResolvedOption, ResolvedValue};
+use serenity::async_trait;
 use serenity::builder::{CreateCommand, CreateCommandOption};
 use serenity::model::application::CommandOptionType;
 
-use crate::database::{models::DBUser, service::db_service};
+use super::managers::{link_prompt, record_guild_link};
+use crate::bot::registry::SlashCommand;
+use crate::bot::response::{Progress, Reply};
 
 /// Registers the update_manager_id command with Discord
 ///
@@ -36,31 +37,29 @@ pub fn register() -> CreateCommand {
 
 /// Main handler for the `/update_manager_id` slash command
 ///
-/// Allows Discord users to set or update their FPL manager ID in the database.
-/// This association enables the bot to provide personalized FPL data and use
-/// default manager IDs in commands.
+/// Looks the manager ID up on FPL and asks the user to confirm the team before
+/// linking it to their Discord account. The confirmation is handled by
+/// [`managers`](super::managers), which also offers admin linking and listing.
+/// In a server, the user's existing link is also recorded there.
 ///
 /// # Arguments
 /// * `_ctx` - Discord context (unused in current implementation)
 /// * `command` - The slash command interaction containing user input
 ///
 /// # Returns
-/// * `Result<CreateInteractionResponse>` - Success or error response
+/// * `Result<Reply>` - Confirmation prompt showing the team and manager name
 ///
 /// # Errors
 /// Returns error if:
-/// - Manager ID is not provided or invalid
-/// - Database update operation fails
-/// - User cannot be identified
+/// - Manager ID is not provided
+/// - No FPL team has the given ID
+/// - FPL API request fails
 ///
 /// # Example Usage
 /// `/update_manager_id manager_id:123456`
-pub async fn run(
-    _ctx: &Context,
-    command: &CommandInteraction,
-) -> Result<CreateInteractionResponse> {
+pub async fn run(_ctx: &Context, command: &CommandInteraction) -> Result<Reply> {
     let user_id = command.user.id;
-    let user_name = &command.user.name;
+    record_guild_link(command.guild_id, user_id).await;
 
     if let Some(ResolvedOption {
         value: ResolvedValue::Integer(id),
@@ -68,36 +67,38 @@ pub async fn run(
     }) = command.data.options().first()
     {
         info!(
-            "Attempting to update manager_id to {} for user {} ({})",
-            id, user_name, user_id
+            "Checking manager_id {} before linking it to user {} ({})",
+            id, command.user.name, user_id
         );
-        let res = db_service()
-            .update_user(&DBUser {
-                discord_id: i64::from(user_id),
-                manager_id: Some(*id as i32),
-            })
-            .await;
-
-        match res {
-            Ok(_) => {
-                info!(
-                    "Successfully updated manager_id to {} for user {}",
-                    id, user_name
-                );
-                Ok(CreateInteractionResponse::Message(
-                    CreateInteractionResponseMessage::new().content("Manager_id update succesful"),
-                ))
-            }
-            Err(e) => {
-                error!("Failed to update manager_id for user {}: {}", user_name, e);
-                Err(anyhow!("Failed to update users manager_id"))
-            }
-        }
+        link_prompt(user_id, user_id, *id as i32).await
     } else {
         error!(
             "No manager_id provided in command options for user {}",
             user_id
         );
-        Err(anyhow!("Failed to find users discord_id"))
+        Err(anyhow!("Please provide your manager ID"))
+    }
+}
+
+/// The `/update_manager_id` command as served by the [`CommandRegistry`](crate::bot::registry::CommandRegistry)
+pub struct UpdateManagerIdCommand;
+
+#[async_trait]
+impl SlashCommand for UpdateManagerIdCommand {
+    fn name(&self) -> &'static str {
+        "update_manager_id"
+    }
+
+    fn register(&self) -> CreateCommand {
+        register()
+    }
+
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        run(ctx, command).await
     }
 }
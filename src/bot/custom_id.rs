@@ -0,0 +1,298 @@
+//! Component custom ID encoding
+//!
+//! Buttons and menus carry everything their handler needs in the custom ID, so
+//! handlers never depend on the text of the message they are attached to. An
+//! ID names the command that owns the component, the version of its layout,
+//! the action, and typed state:
+//!
+//! `standings:1:next:l=314,p=2`
+//!
+//! Commands bump their version whenever the meaning of their components
+//! changes. Components from older versions, and the unversioned IDs used
+//! before this encoding, are rejected with a message asking the user to run
+//! the command again.
+
+use anyhow::{anyhow, Result};
+
+/// Discord's limit on the length of a component custom ID.
+pub const MAX_CUSTOM_ID_LENGTH: usize = 100;
+
+/// Typed state carried by a component
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentState {
+    /// FPL league ID
+    pub league_id: Option<i32>,
+
+    /// Page number (0-based)
+    pub page: Option<u32>,
+
+    /// Sort order or view name, made of lowercase letters, digits and `_`
+    pub sort: Option<String>,
+
+    /// Gameweek number
+    pub gameweek: Option<i32>,
+
+    /// FPL manager ID
+    pub manager_id: Option<i32>,
+
+    /// Discord user ID
+    pub user_id: Option<u64>,
+
+    /// Discord user ID of the member the component was created for, the
+    /// only one allowed to use it
+    pub requester_id: Option<u64>,
+
+    /// FPL fixture ID
+    pub fixture_id: Option<u32>,
+
+    /// FPL player (element) ID
+    pub player_id: Option<i32>,
+
+    /// ID of a row the bot stored, e.g. a poll
+    pub record_id: Option<i64>,
+}
+
+/// A decoded component custom ID
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComponentId {
+    /// Name of the command that owns the component
+    pub command: String,
+
+    /// Version of the command's component layout
+    pub version: u32,
+
+    /// What the component does, e.g. `next`
+    pub action: String,
+
+    /// State the handler needs
+    pub state: ComponentState,
+}
+
+impl ComponentId {
+    /// Starts an ID with no state.
+    pub fn new(command: &str, version: u32, action: &str) -> Self {
+        Self {
+            command: command.to_string(),
+            version,
+            action: action.to_string(),
+            state: ComponentState::default(),
+        }
+    }
+
+    /// Sets the league ID.
+    pub fn league(mut self, league_id: i32) -> Self {
+        self.state.league_id = Some(league_id);
+        self
+    }
+
+    /// Sets the page number.
+    pub fn page(mut self, page: u32) -> Self {
+        self.state.page = Some(page);
+        self
+    }
+
+    /// Sets the sort order or view name.
+    pub fn sort(mut self, sort: &str) -> Self {
+        self.state.sort = Some(sort.to_string());
+        self
+    }
+
+    /// Sets the gameweek.
+    pub fn gameweek(mut self, gameweek: i32) -> Self {
+        self.state.gameweek = Some(gameweek);
+        self
+    }
+
+    /// Sets the FPL manager ID.
+    pub fn manager(mut self, manager_id: i32) -> Self {
+        self.state.manager_id = Some(manager_id);
+        self
+    }
+
+    /// Sets the Discord user ID.
+    pub fn user(mut self, user_id: u64) -> Self {
+        self.state.user_id = Some(user_id);
+        self
+    }
+
+    /// Sets the Discord user ID of the member the component is for.
+    pub fn requester(mut self, user_id: u64) -> Self {
+        self.state.requester_id = Some(user_id);
+        self
+    }
+
+    /// Sets the fixture ID.
+    pub fn fixture(mut self, fixture_id: u32) -> Self {
+        self.state.fixture_id = Some(fixture_id);
+        self
+    }
+
+    /// Sets the player ID.
+    pub fn player(mut self, player_id: i32) -> Self {
+        self.state.player_id = Some(player_id);
+        self
+    }
+
+    /// Sets the stored record ID.
+    pub fn record(mut self, record_id: i64) -> Self {
+        self.state.record_id = Some(record_id);
+        self
+    }
+
+    /// Encodes the ID for use as a component custom ID
+    ///
+    /// # Returns
+    /// * `Result<String>` - e.g. `standings:1:next:l=314,p=2`
+    ///
+    /// # Errors
+    /// Returns error if the command, action or sort contain characters other
+    /// than lowercase letters, digits and `_`, or the result is longer than
+    /// Discord allows
+    pub fn encode(&self) -> Result<String> {
+        for part in [&self.command, &self.action] {
+            if !is_token(part) {
+                return Err(anyhow!("Invalid custom ID part {:?}", part));
+            }
+        }
+
+        let state = &self.state;
+        let mut fields = Vec::new();
+        if let Some(id) = state.league_id {
+            fields.push(format!("l={}", id));
+        }
+        if let Some(page) = state.page {
+            fields.push(format!("p={}", page));
+        }
+        if let Some(sort) = &state.sort {
+            if !is_token(sort) {
+                return Err(anyhow!("Invalid custom ID sort {:?}", sort));
+            }
+            fields.push(format!("s={}", sort));
+        }
+        if let Some(gameweek) = state.gameweek {
+            fields.push(format!("g={}", gameweek));
+        }
+        if let Some(id) = state.manager_id {
+            fields.push(format!("m={}", id));
+        }
+        if let Some(id) = state.user_id {
+            fields.push(format!("u={}", id));
+        }
+        if let Some(id) = state.requester_id {
+            fields.push(format!("q={}", id));
+        }
+        if let Some(id) = state.fixture_id {
+            fields.push(format!("f={}", id));
+        }
+        if let Some(id) = state.player_id {
+            fields.push(format!("e={}", id));
+        }
+        if let Some(id) = state.record_id {
+            fields.push(format!("r={}", id));
+        }
+
+        let mut encoded = format!("{}:{}:{}", self.command, self.version, self.action);
+        if !fields.is_empty() {
+            encoded.push(':');
+            encoded.push_str(&fields.join(","));
+        }
+        if encoded.len() > MAX_CUSTOM_ID_LENGTH {
+            return Err(anyhow!(
+                "Custom ID {:?} is longer than {} characters",
+                encoded,
+                MAX_CUSTOM_ID_LENGTH
+            ));
+        }
+        Ok(encoded)
+    }
+
+    /// Decodes a custom ID of any command and version
+    ///
+    /// # Errors
+    /// Returns error if the custom ID is not in the encoded format
+    pub fn decode(custom_id: &str) -> Result<Self> {
+        let malformed = || anyhow!("Malformed custom ID {:?}", custom_id);
+
+        let mut parts = custom_id.splitn(4, ':');
+        let command = parts.next().filter(|c| is_token(c)).ok_or_else(malformed)?;
+        let version = parts
+            .next()
+            .and_then(|v| v.parse().ok())
+            .ok_or_else(malformed)?;
+        let action = parts.next().filter(|a| is_token(a)).ok_or_else(malformed)?;
+
+        let mut state = ComponentState::default();
+        for field in parts.next().into_iter().flat_map(|f| f.split(',')) {
+            let (key, value) = field.split_once('=').ok_or_else(malformed)?;
+            match key {
+                "l" => state.league_id = Some(value.parse()?),
+                "p" => state.page = Some(value.parse()?),
+                "s" if is_token(value) => state.sort = Some(value.to_string()),
+                "g" => state.gameweek = Some(value.parse()?),
+                "m" => state.manager_id = Some(value.parse()?),
+                "u" => state.user_id = Some(value.parse()?),
+                "q" => state.requester_id = Some(value.parse()?),
+                "f" => state.fixture_id = Some(value.parse()?),
+                "e" => state.player_id = Some(value.parse()?),
+                "r" => state.record_id = Some(value.parse()?),
+                _ => return Err(malformed()),
+            }
+        }
+
+        Ok(Self {
+            command: command.to_string(),
+            version,
+            action: action.to_string(),
+            state,
+        })
+    }
+
+    /// Decodes a custom ID sent to one command's handler
+    ///
+    /// # Arguments
+    /// * `custom_id` - The custom ID of the interaction
+    /// * `command` - The command handling it
+    /// * `version` - The command's current component version
+    ///
+    /// # Errors
+    /// Returns an error asking the user to run the command again if the ID is
+    /// from another version of the command, or is not in the encoded format
+    pub fn parse(custom_id: &str, command: &str, version: u32) -> Result<Self> {
+        match Self::decode(custom_id) {
+            Ok(id) if id.command == command && id.version == version => Ok(id),
+            _ => Err(stale(command)),
+        }
+    }
+
+    /// The league ID, or an error if the component does not carry one.
+    pub fn league_id(&self) -> Result<i32> {
+        self.state
+            .league_id
+            .ok_or_else(|| anyhow!("Could not determine league ID"))
+    }
+
+    /// The stored record ID, or an error if the component does not carry one.
+    pub fn record_id(&self) -> Result<i64> {
+        self.state
+            .record_id
+            .ok_or_else(|| anyhow!("Could not determine record ID"))
+    }
+}
+
+/// The error shown for components the bot can no longer handle
+///
+/// # Arguments
+/// * `command` - The command the component belonged to
+pub fn stale(command: &str) -> anyhow::Error {
+    anyhow!(
+        "This message is out of date. Run /{} again to get a fresh one.",
+        command
+    )
+}
+
+/// Whether a string is safe to embed in a custom ID.
+fn is_token(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
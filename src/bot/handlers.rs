@@ -8,26 +8,53 @@
 use log::info;
 use serenity::{
     all::{
-        ComponentInteraction, CreateCommand, CreateInteractionResponse,
-        CreateInteractionResponseMessage, GuildId, Interaction, Ready,
+        ComponentInteraction, CreateInteractionResponse, CreateInteractionResponseMessage,
+        Interaction, Ready,
     },
     async_trait,
     prelude::*,
 };
 
-use crate::{bot::commands, fpl};
+use crate::bot::{
+    commands, registry::CommandRegistry, response, sync, sync::RegistrationMode, tasks,
+};
 
 /// Main event handler for the Discord bot
 ///
 /// Implements the Serenity [`EventHandler`] trait to process Discord events.
-/// Handles bot initialization, slash command registration, and interaction routing.
-pub struct Handler;
+/// Handles bot initialization, slash command registration, and interaction routing,
+/// all driven by the [`CommandRegistry`] it holds.
+pub struct Handler {
+    registry: CommandRegistry,
+
+    registration: RegistrationMode,
+}
+
+impl Handler {
+    /// Creates a handler serving every command in [`commands::all`]
+    ///
+    /// # Arguments
+    /// * `registration` - Where to register the commands on startup
+    ///
+    /// # Panics
+    /// Panics if two commands share a name or overlapping component prefixes,
+    /// which is a programming error caught on startup.
+    pub fn new(registration: RegistrationMode) -> Self {
+        let registry = CommandRegistry::new(commands::all()).expect("Invalid command registry");
+        Self {
+            registry,
+            registration,
+        }
+    }
+}
 
 #[async_trait]
 impl EventHandler for Handler {
     /// Called when the bot successfully connects to Discord
     ///
-    /// Registers all available slash commands globally and logs the bot's connection status.
+    /// Registers all available slash commands where configured (skipping scopes that
+    /// are already up to date), starts the background tasks and logs the
+    /// bot's connection status.
     ///
     /// # Arguments
     /// * `ctx` - The Discord context for making API calls
@@ -35,192 +62,86 @@ impl EventHandler for Handler {
     async fn ready(&self, ctx: Context, ready: Ready) {
         info!("Logged in as {}", ready.user.name);
 
-        let commands = vec![
-            CreateCommand::new("hello").description("Say hello to the bot"),
-            commands::standings::register(),
-            commands::fixtures::register(),
-            commands::update_manager_id::register(),
-            commands::check_manager_id::register(),
-            commands::update_channel_league_id::register(),
-            commands::check_channel_league_id::register(),
-            commands::check_team::register(),
-        ];
-        let guild_id = GuildId::new(1221876813165363270); // Replace with your server's ID
-        match guild_id.set_commands(&ctx.http, commands).await {
-            // match Command::set_global_commands(&ctx.http, commands).await {
+        let commands = self.registry.definitions();
+        match sync::sync_commands(&ctx.http, &self.registration, commands, false).await {
             Ok(_) => info!("Successfully registered slash commands"),
             Err(e) => info!("Failed to register slash commands: {}", e),
         }
+
+        tasks::spawn_background_tasks(ctx.http.clone());
     }
 
-    /// Handles incoming Discord interactions (commands and components)
+    /// Handles incoming Discord interactions (commands, autocomplete and components)
     ///
-    /// Routes slash commands to their respective handlers and processes
-    /// component interactions (button clicks, select menus, etc.).
+    /// Routes slash commands (through [`response::respond`], which defers slow ones)
+    /// and autocomplete requests to the registered command of the same name, and
+    /// component interactions (button clicks, select menus, etc.) and modal
+    /// submissions to the command that owns their custom ID prefix.
     ///
     /// # Arguments
     /// * `ctx` - The Discord context for making API calls
     /// * `interaction` - The interaction data from Discord
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
         match interaction {
-            Interaction::Command(command) => {
-                let response = match command.data.name.as_str() {
-                    "hello" => {
-                        let data = CreateInteractionResponseMessage::new().content("Hey!");
-                        Ok(CreateInteractionResponse::Message(data))
-                    }
-                    "standings" => commands::standings::run(&ctx, &command).await,
-                    "fixtures" => commands::fixtures::run(&ctx, &command).await,
-                    "update_manager_id" => commands::update_manager_id::run(&ctx, &command).await,
-                    "check_manager_id" => commands::check_manager_id::run(&ctx, &command).await,
-                    "update_channel_league_id" => {
-                        commands::update_channel_league_id::run(&ctx, &command).await
+            Interaction::Command(command) => match self.registry.get(&command.data.name) {
+                Some(handler) => response::respond(&ctx, &command, handler).await,
+                None => {
+                    let data = CreateInteractionResponseMessage::new().content("Unknown command");
+                    if let Err(why) = command
+                        .create_response(&ctx.http, CreateInteractionResponse::Message(data))
+                        .await
+                    {
+                        info!("Cannot respond to slash command: {}", why);
                     }
-                    "check_channel_league_id" => {
-                        commands::check_channel_league_id::run(&ctx, &command).await
-                    }
-                    "check_team" => commands::check_team::run(&ctx, &command).await,
-                    _ => {
-                        let data =
-                            CreateInteractionResponseMessage::new().content("Unknown command");
-                        Ok(CreateInteractionResponse::Message(data))
-                    }
-                };
-
-                if let Err(why) = match response {
-                    Ok(response) => command.create_response(&ctx.http, response).await,
-                    Err(e) => {
-                        let error_response = CreateInteractionResponseMessage::new()
-                            .content(format!("Error: {}", e));
-                        command
-                            .create_response(
-                                &ctx.http,
-                                CreateInteractionResponse::Message(error_response),
-                            )
-                            .await
-                    }
-                } {
-                    info!("Cannot respond to slash command: {}", why);
+                }
+            },
+            Interaction::Autocomplete(autocomplete) => {
+                if let Some(handler) = self.registry.get(&autocomplete.data.name) {
+                    handler.autocomplete(&ctx, &autocomplete).await;
                 }
             }
             Interaction::Component(component) => {
-                handle_component_interaction(&ctx, component).await;
+                self.handle_component_interaction(&ctx, component).await;
+            }
+            Interaction::Modal(modal) => {
+                if let Some(handler) = self.registry.component_owner(&modal.data.custom_id) {
+                    handler.handle_modal(&ctx, &modal).await;
+                }
             }
             _ => {}
         }
     }
 }
 
-/// Handles component interactions (buttons, select menus, etc.)
-///
-/// Routes component interactions based on their custom ID to appropriate handlers.
-/// Currently supports standings navigation buttons and other general components.
-///
-/// # Arguments
-/// * `ctx` - The Discord context for making API calls
-/// * `component` - The component interaction data
-async fn handle_component_interaction(ctx: &Context, component: ComponentInteraction) {
-    if component.data.custom_id.starts_with("standings_") {
-        handle_standings_interaction(ctx, component).await;
-        return;
-    }
-
-    let response = component.data.custom_id.as_str();
-
-    let data = CreateInteractionResponseMessage::new()
-        .content(response)
-        .ephemeral(true);
-
-    if let Err(why) = component
-        .create_response(&ctx.http, CreateInteractionResponse::Message(data))
-        .await
-    {
-        info!("Cannot respond to component interaction: {}", why);
-    }
-}
-
-/// Handles standings-specific component interactions
-///
-/// Processes navigation buttons (previous/next page) for league standings displays.
-/// Extracts pagination information from the component custom ID and updates the
-/// standings display with the requested page.
-///
-/// # Arguments
-/// * `ctx` - The Discord context for making API calls
-/// * `component` - The component interaction data with standings-specific custom ID
-///
-/// # Custom ID Format
-/// Expected format: `standings_{action}_{current_page}` where:
-/// - `action` can be "prev", "next", or "refresh"
-/// - `current_page` is the 0-based page number
-async fn handle_standings_interaction(ctx: &Context, component: ComponentInteraction) {
-    let parts: Vec<&str> = component.data.custom_id.split('_').collect();
-    if parts.len() < 3 {
-        return;
-    }
-    // parts formatted like ("standings_prev_{}", page)
-    let action = parts[1];
-    let current_page: usize = parts[2].parse().unwrap_or(0);
-
-    // extract league_id from the embed footer
-    let league_id = component
-        .message
-        .embeds
-        .first()
-        .and_then(|embed| embed.footer.as_ref())
-        .and_then(|footer| footer.text.split("League ID: ").nth(1))
-        .and_then(|s| s.split(' ').next())
-        .and_then(|s| s.parse::<i32>().ok());
+impl Handler {
+    /// Handles component interactions (buttons, select menus, etc.)
+    ///
+    /// Routes component interactions to the command that claimed their custom ID
+    /// prefix. Unclaimed components come from messages sent before the bot
+    /// versioned its custom IDs, and are answered with a stale message notice.
+    ///
+    /// # Arguments
+    /// * `ctx` - The Discord context for making API calls
+    /// * `component` - The component interaction data
+    async fn handle_component_interaction(&self, ctx: &Context, component: ComponentInteraction) {
+        if let Some(handler) = self.registry.component_owner(&component.data.custom_id) {
+            handler.handle_component(ctx, &component).await;
+            return;
+        }
 
-    let Some(league_id) = league_id else {
+        info!(
+            "Rejecting unclaimed component {:?}",
+            component.data.custom_id
+        );
         let data = CreateInteractionResponseMessage::new()
-            .content("Error: Could not determine league ID")
+            .content("Error: This message is out of date. Run the command again.")
             .ephemeral(true);
-        let _ = component
-            .create_response(&ctx.http, CreateInteractionResponse::Message(data))
-            .await;
-        return;
-    };
-
-    let new_page = match action {
-        "prev" => current_page.saturating_sub(1),
-        "next" => current_page + 1,
-        _ => current_page,
-    };
-
-    let needed_api_page = ((new_page / 2) + 1) as i32;
-    let standings_result =
-        fpl::models::league::LeagueStandings::fetch_page(league_id, needed_api_page).await;
-
-    match standings_result {
-        Ok(standings) => {
-            let per_page = 25;
-            let total_managers = standings.standings.managers.len();
-            let max_page = (50 * (needed_api_page as usize) + total_managers - 1) / per_page;
-            let actual_page = new_page.min(max_page);
 
-            let embed = commands::standings::build_standings_embed(&standings, actual_page);
-            let buttons = commands::standings::build_navigation_buttons(actual_page, &standings);
-
-            let response = CreateInteractionResponse::UpdateMessage(
-                serenity::builder::CreateInteractionResponseMessage::new()
-                    .embed(embed)
-                    .button(buttons.prev)
-                    .button(buttons.next)
-                    .button(buttons.refresh),
-            );
-
-            if let Err(why) = component.create_response(&ctx.http, response).await {
-                info!("Cannot update standings message: {}", why);
-            }
-        }
-        Err(e) => {
-            let data = CreateInteractionResponseMessage::new()
-                .content(format!("Error fetching standings: {}", e))
-                .ephemeral(true);
-            let _ = component
-                .create_response(&ctx.http, CreateInteractionResponse::Message(data))
-                .await;
+        if let Err(why) = component
+            .create_response(&ctx.http, CreateInteractionResponse::Message(data))
+            .await
+        {
+            info!("Cannot respond to component interaction: {}", why);
         }
     }
 }
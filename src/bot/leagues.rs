@@ -0,0 +1,165 @@
+//! League selection for league commands
+//!
+//! Channels hold any number of named leagues, one of them primary, and guilds
+//! can set a default league for channels without any. Commands that work on a
+//! league take an optional `league` option naming one of the channel's leagues
+//! and resolve it here.
+
+use anyhow::{anyhow, Result};
+use log::{error, info};
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateAutocompleteResponse,
+    CreateInteractionResponse, ResolvedOption, ResolvedValue,
+};
+use serenity::builder::CreateCommandOption;
+
+use crate::database::models::DBChannelLeague;
+use crate::database::service::db_service;
+
+/// Name of the option league commands use to pick a configured league.
+pub const LEAGUE_OPTION: &str = "league";
+
+/// Discord's limit on autocomplete suggestions.
+const MAX_AUTOCOMPLETE_CHOICES: usize = 25;
+
+/// The optional, autocompleted `league` option shared by league commands.
+pub fn league_option() -> CreateCommandOption {
+    CreateCommandOption::new(
+        CommandOptionType::String,
+        LEAGUE_OPTION,
+        "One of this channel's leagues (defaults to the primary league)",
+    )
+    .set_autocomplete(true)
+    .required(false)
+}
+
+/// Reads the `league` option from a command's options, if given.
+pub fn requested_league<'a>(options: &'a [ResolvedOption<'a>]) -> Option<&'a str> {
+    options
+        .iter()
+        .find(|o| o.name == LEAGUE_OPTION)
+        .and_then(|o| match o.value {
+            ResolvedValue::String(name) => Some(name),
+            _ => None,
+        })
+}
+
+/// Picks a league from a channel's configured leagues
+///
+/// # Arguments
+/// * `leagues` - The channel's leagues
+/// * `guild_default` - The guild's fallback league, if any
+/// * `requested` - A league name chosen by the user (case insensitive)
+///
+/// # Returns
+/// * `Result<i32>` - The league ID: the named league, else the channel's primary
+///   league, else its first league, else the guild default
+///
+/// # Errors
+/// Returns error if the named league does not exist, or nothing is configured
+pub fn choose_league(
+    leagues: &[DBChannelLeague],
+    guild_default: Option<i32>,
+    requested: Option<&str>,
+) -> Result<i32> {
+    if let Some(name) = requested.map(str::trim).filter(|n| !n.is_empty()) {
+        return leagues
+            .iter()
+            .find(|l| l.name.eq_ignore_ascii_case(name))
+            .map(|l| l.league_id)
+            .ok_or_else(|| {
+                if leagues.is_empty() {
+                    anyhow!("This channel has no leagues. Use /leagues add to add one.")
+                } else {
+                    let names: Vec<&str> = leagues.iter().map(|l| l.name.as_str()).collect();
+                    anyhow!(
+                        "No league called {:?} in this channel. Choose one of: {}",
+                        name,
+                        names.join(", ")
+                    )
+                }
+            });
+    }
+
+    leagues
+        .iter()
+        .find(|l| l.is_primary)
+        .or_else(|| leagues.first())
+        .map(|l| l.league_id)
+        .or(guild_default)
+        .ok_or_else(|| {
+            anyhow!("No league is set up for this channel. Use /leagues add to add one.")
+        })
+}
+
+/// Resolves the league a command should work on
+///
+/// # Arguments
+/// * `command` - The slash command interaction
+/// * `requested` - The league name chosen by the user, if any
+///
+/// # Errors
+/// Returns error if the league cannot be resolved or the database fails
+pub async fn resolve_league(command: &CommandInteraction, requested: Option<&str>) -> Result<i32> {
+    let leagues = db_service().get_channel_leagues(command.channel_id).await?;
+    let guild_default = match command.guild_id {
+        Some(guild_id) => {
+            db_service()
+                .get_guild_settings(guild_id)
+                .await?
+                .default_league_id
+        }
+        None => None,
+    };
+    let league_id = choose_league(&leagues, guild_default, requested)?;
+    info!(
+        "Resolved league {:?} to {} in channel {}",
+        requested, league_id, command.channel_id
+    );
+    Ok(league_id)
+}
+
+/// Suggests the channel's leagues for the `league` option
+///
+/// # Arguments
+/// * `ctx` - The Discord context for making API calls
+/// * `interaction` - The autocomplete interaction
+pub async fn autocomplete(ctx: &Context, interaction: &CommandInteraction) {
+    let query = interaction
+        .data
+        .autocomplete()
+        .map(|opt| opt.value.trim().to_lowercase())
+        .unwrap_or_default();
+
+    let leagues = match db_service()
+        .get_channel_leagues(interaction.channel_id)
+        .await
+    {
+        Ok(leagues) => leagues,
+        Err(e) => {
+            error!("Failed to load leagues for autocomplete: {}", e);
+            return;
+        }
+    };
+
+    let mut response = CreateAutocompleteResponse::new();
+    for league in leagues
+        .iter()
+        .filter(|l| l.name.to_lowercase().contains(&query))
+        .take(MAX_AUTOCOMPLETE_CHOICES)
+    {
+        let label = if league.is_primary {
+            format!("{} ({}, primary)", league.name, league.league_id)
+        } else {
+            format!("{} ({})", league.name, league.league_id)
+        };
+        response = response.add_string_choice(label, league.name.clone());
+    }
+
+    if let Err(why) = interaction
+        .create_response(&ctx.http, CreateInteractionResponse::Autocomplete(response))
+        .await
+    {
+        info!("Cannot respond to league autocomplete: {}", why);
+    }
+}
@@ -9,7 +9,21 @@
 //! # Modules
 //!
 //! * [`commands`] - Slash command implementations for FPL functionality
+//! * [`custom_id`] - Versioned encoding of state into component custom IDs
 //! * [`handlers`] - Discord event handlers and interaction processing
+//! * [`leagues`] - Resolving which configured league a command works on
+//! * [`permissions`] - Per-guild command access levels and the settings audit log
+//! * [`registry`] - The `SlashCommand` trait and the registry commands are served from
+//! * [`response`] - Command replies, automatic deferral and progress updates
+//! * [`sync`] - Where slash commands are registered and keeping Discord up to date
+//! * [`tasks`] - Background pollers that post updates to channels
 
 pub mod commands;
-pub mod handlers;
\ No newline at end of file
+pub mod custom_id;
+pub mod handlers;
+pub mod leagues;
+pub mod permissions;
+pub mod registry;
+pub mod response;
+pub mod sync;
+pub mod tasks;
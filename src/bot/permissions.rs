@@ -0,0 +1,251 @@
+//! Per-guild command permissions and the audit log
+//!
+//! Every [`SlashCommand`](crate::bot::registry::SlashCommand) declares a default
+//! [`Access`] level. Guilds can override the level per command and nominate
+//! admin roles with `/permissions`. Commands that change settings record what
+//! they changed with [`audit`].
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use log::{error, info};
+use serenity::all::{
+    ChannelId, CommandInteraction, ComponentInteraction, GuildId, Member, Permissions, RoleId, User,
+};
+
+use crate::database::models::DBAuditEntry;
+use crate::database::service::db_service;
+
+/// Who may run a command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Access {
+    /// Any member
+    Everyone,
+
+    /// Members who can manage channels, plus admins
+    Managers,
+
+    /// Members with Administrator or Manage Server, or one of the guild's
+    /// configured admin roles
+    Admins,
+}
+
+impl Access {
+    pub const ALL: [Access; 3] = [Access::Everyone, Access::Managers, Access::Admins];
+
+    /// The name stored in the database and offered as a command choice.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Access::Everyone => "everyone",
+            Access::Managers => "managers",
+            Access::Admins => "admins",
+        }
+    }
+
+    /// Human readable description of who is allowed, used in denial messages.
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Access::Everyone => "everyone",
+            Access::Managers => "members with Manage Channels or a bot admin role",
+            Access::Admins => "members with Manage Server or a bot admin role",
+        }
+    }
+}
+
+impl fmt::Display for Access {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Access {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Access::ALL
+            .into_iter()
+            .find(|access| access.as_str() == s)
+            .ok_or_else(|| anyhow!("Unknown access level {:?}", s))
+    }
+}
+
+/// Checks whether a member satisfies an access level
+///
+/// # Arguments
+/// * `access` - The level required
+/// * `permissions` - The member's resolved permissions in the channel
+/// * `roles` - The member's roles
+/// * `admin_roles` - The guild's configured admin roles
+///
+/// # Returns
+/// * `bool` - Whether the member may run the command
+pub fn is_allowed(
+    access: Access,
+    permissions: Permissions,
+    roles: &[RoleId],
+    admin_roles: &[RoleId],
+) -> bool {
+    let is_admin = permissions.administrator()
+        || permissions.manage_guild()
+        || roles.iter().any(|role| admin_roles.contains(role));
+    match access {
+        Access::Everyone => true,
+        Access::Managers => is_admin || permissions.manage_channels(),
+        Access::Admins => is_admin,
+    }
+}
+
+/// Checks that the invoking member may run a command
+///
+/// Commands used outside a guild are always allowed, since the only settings
+/// they can change are the user's own DM channel.
+///
+/// # Arguments
+/// * `command` - The slash command interaction
+/// * `default` - The command's access level when the guild has no override
+///
+/// # Errors
+/// Returns error, with a message suitable for the user, if access is denied
+pub async fn authorize(command: &CommandInteraction, default: Access) -> Result<()> {
+    let Some(guild_id) = command.guild_id else {
+        return Ok(());
+    };
+
+    let access = match db_service()
+        .get_command_access(guild_id, &command.data.name)
+        .await?
+    {
+        Some(access) => access.parse().unwrap_or(default),
+        None => default,
+    };
+    if has_access(command, access).await? {
+        Ok(())
+    } else {
+        info!(
+            "Denied /{} to {} in guild {}",
+            command.data.name, command.user.name, guild_id
+        );
+        Err(anyhow!(
+            "Only {} can use /{} in this server",
+            access.describe(),
+            command.data.name
+        ))
+    }
+}
+
+/// Checks whether the invoking member has an access level, for commands that
+/// gate individual subcommands or options
+///
+/// # Arguments
+/// * `command` - The slash command interaction
+/// * `access` - The level required
+///
+/// # Errors
+/// Returns error if the guild's admin roles cannot be loaded
+pub async fn has_access(command: &CommandInteraction, access: Access) -> Result<bool> {
+    member_has_access(command.guild_id, command.member.as_deref(), access).await
+}
+
+/// Checks whether the user pressing a button or menu has an access level
+///
+/// # Arguments
+/// * `component` - The component interaction
+/// * `access` - The level required
+///
+/// # Errors
+/// Returns error if the guild's admin roles cannot be loaded
+pub async fn component_has_access(
+    component: &ComponentInteraction,
+    access: Access,
+) -> Result<bool> {
+    member_has_access(component.guild_id, component.member.as_ref(), access).await
+}
+
+async fn member_has_access(
+    guild_id: Option<GuildId>,
+    member: Option<&Member>,
+    access: Access,
+) -> Result<bool> {
+    let Some(guild_id) = guild_id else {
+        return Ok(true);
+    };
+    if access == Access::Everyone {
+        return Ok(true);
+    }
+
+    let admin_roles = db_service().get_admin_roles(guild_id).await?;
+    let (permissions, roles) = match member {
+        Some(member) => (
+            member.permissions.unwrap_or_else(Permissions::empty),
+            member.roles.as_slice(),
+        ),
+        None => (Permissions::empty(), &[][..]),
+    };
+    Ok(is_allowed(access, permissions, roles, &admin_roles))
+}
+
+/// Records a settings change in the audit log
+///
+/// Failures are logged rather than returned, so a broken audit log never
+/// undoes a change that already succeeded.
+///
+/// # Arguments
+/// * `command` - The interaction that made the change
+/// * `change` - What changed, e.g. `league 123 -> 456`
+pub async fn audit(command: &CommandInteraction, change: impl Into<String>) {
+    write_audit(
+        command.guild_id,
+        command.channel_id,
+        &command.user,
+        &command.data.name,
+        change.into(),
+    )
+    .await
+}
+
+/// Records a settings change confirmed through a button or menu
+///
+/// # Arguments
+/// * `component` - The interaction that made the change
+/// * `command` - Name of the command the component belongs to
+/// * `change` - What changed
+pub async fn audit_component(
+    component: &ComponentInteraction,
+    command: &str,
+    change: impl Into<String>,
+) {
+    write_audit(
+        component.guild_id,
+        component.channel_id,
+        &component.user,
+        command,
+        change.into(),
+    )
+    .await
+}
+
+async fn write_audit(
+    guild_id: Option<GuildId>,
+    channel_id: ChannelId,
+    user: &User,
+    command: &str,
+    change: String,
+) {
+    info!(
+        "Audit: {} ran /{} in channel {}: {}",
+        user.name, command, channel_id, change
+    );
+    let entry = DBAuditEntry {
+        guild_id: guild_id.map(i64::from),
+        channel_id: channel_id.into(),
+        user_id: user.id.into(),
+        command: command.to_string(),
+        change,
+        created_at: Utc::now(),
+    };
+    if let Err(e) = db_service().add_audit_entry(&entry).await {
+        error!("Failed to write audit entry: {}", e);
+    }
+}
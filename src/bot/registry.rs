@@ -0,0 +1,142 @@
+//! Slash command framework
+//!
+//! Every slash command implements [`SlashCommand`] and is collected into a
+//! [`CommandRegistry`]. The event handler derives both the command definitions
+//! sent to Discord and the dispatch of commands, autocomplete requests and
+//! component interactions from the registry, so the two can never drift apart.
+
+use anyhow::{anyhow, Result};
+use serenity::all::{
+    CommandInteraction, ComponentInteraction, Context, CreateCommand, ModalInteraction,
+};
+use serenity::async_trait;
+
+use crate::bot::permissions::Access;
+use crate::bot::response::{Progress, Reply};
+
+/// A Discord slash command and the interactions it owns
+///
+/// Only [`name`](SlashCommand::name), [`register`](SlashCommand::register) and
+/// [`run`](SlashCommand::run) are required. Commands with autocompleted options
+/// override [`autocomplete`](SlashCommand::autocomplete), and commands that send
+/// buttons or menus claim their custom IDs through
+/// [`component_prefixes`](SlashCommand::component_prefixes), which also routes
+/// the modals those components open. Commands that change settings raise their
+/// [`access`](SlashCommand::access) level.
+#[async_trait]
+pub trait SlashCommand: Send + Sync {
+    /// The command name as typed by users, without the leading slash.
+    fn name(&self) -> &'static str;
+
+    /// Builds the command definition registered with Discord.
+    fn register(&self) -> CreateCommand;
+
+    /// Who may run the command unless a guild overrides it with `/permissions`.
+    fn access(&self) -> Access {
+        Access::Everyone
+    }
+
+    /// Whether the command replies privately to the member who ran it. A slow
+    /// command is then deferred privately too.
+    fn ephemeral(&self) -> bool {
+        false
+    }
+
+    /// Handles an invocation of the command
+    ///
+    /// Slow commands are deferred automatically by the dispatch layer; `progress`
+    /// lets long running ones show intermediate status while they work.
+    async fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        progress: &Progress,
+    ) -> Result<Reply>;
+
+    /// Responds to an autocomplete request for one of the command's options.
+    async fn autocomplete(&self, _ctx: &Context, _interaction: &CommandInteraction) {}
+
+    /// Custom ID prefixes of the components this command creates.
+    fn component_prefixes(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Handles a component interaction whose custom ID matched one of
+    /// [`component_prefixes`](SlashCommand::component_prefixes).
+    async fn handle_component(&self, _ctx: &Context, _component: &ComponentInteraction) {}
+
+    /// Handles a modal submitted from one of the command's components. Modal
+    /// custom IDs are matched against
+    /// [`component_prefixes`](SlashCommand::component_prefixes) like components.
+    async fn handle_modal(&self, _ctx: &Context, _modal: &ModalInteraction) {}
+}
+
+/// The set of slash commands the bot serves
+pub struct CommandRegistry {
+    commands: Vec<Box<dyn SlashCommand>>,
+}
+
+impl CommandRegistry {
+    /// Builds a registry from a list of commands
+    ///
+    /// # Arguments
+    /// * `commands` - The commands to serve
+    ///
+    /// # Errors
+    /// Returns error if two commands share a name, or if one command's
+    /// component prefix is a prefix of another's (which would make routing
+    /// ambiguous)
+    pub fn new(commands: Vec<Box<dyn SlashCommand>>) -> Result<Self> {
+        for (i, command) in commands.iter().enumerate() {
+            for other in &commands[i + 1..] {
+                if command.name() == other.name() {
+                    return Err(anyhow!("Duplicate slash command /{}", command.name()));
+                }
+                for prefix in command.component_prefixes() {
+                    for other_prefix in other.component_prefixes() {
+                        if prefix.starts_with(other_prefix) || other_prefix.starts_with(prefix) {
+                            return Err(anyhow!(
+                                "Component prefixes {:?} (/{}) and {:?} (/{}) overlap",
+                                prefix,
+                                command.name(),
+                                other_prefix,
+                                other.name()
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(Self { commands })
+    }
+
+    /// Definitions of every command, ready to register with Discord.
+    pub fn definitions(&self) -> Vec<CreateCommand> {
+        self.commands.iter().map(|c| c.register()).collect()
+    }
+
+    /// Names of every command, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.commands.iter().map(|c| c.name())
+    }
+
+    /// Looks up a command by name.
+    pub fn get(&self, name: &str) -> Option<&dyn SlashCommand> {
+        self.commands
+            .iter()
+            .find(|c| c.name() == name)
+            .map(|c| c.as_ref())
+    }
+
+    /// Finds the command that owns a component custom ID.
+    pub fn component_owner(&self, custom_id: &str) -> Option<&dyn SlashCommand> {
+        self.commands
+            .iter()
+            .find(|c| {
+                c.component_prefixes()
+                    .iter()
+                    .any(|prefix| custom_id.starts_with(prefix))
+            })
+            .map(|c| c.as_ref())
+    }
+}
@@ -0,0 +1,331 @@
+//! Slash command responses
+//!
+//! Commands return an owned [`Reply`] rather than a Discord response builder so
+//! the dispatch layer can decide how to deliver it. [`respond`] runs a command
+//! and, if it has not finished within [`DEFER_AFTER`], defers the interaction so
+//! Discord's three second deadline is met, then edits the original response
+//! once the reply is ready. Long running commands can also report intermediate
+//! status through the [`Progress`] handle they are given.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use log::{error, info, warn};
+use serenity::all::{
+    CommandInteraction, Context, CreateActionRow, CreateAttachment, CreateButton, CreateEmbed,
+    CreateInteractionResponse, CreateInteractionResponseFollowup, CreateInteractionResponseMessage,
+    CreateSelectMenu, EditInteractionResponse, Http,
+};
+use tokio::sync::Mutex;
+
+use crate::bot::permissions;
+use crate::bot::registry::SlashCommand;
+
+/// How long a command may run before the interaction is deferred.
+///
+/// Discord requires an initial response within three seconds; this leaves
+/// headroom for the response request itself.
+pub const DEFER_AFTER: Duration = Duration::from_millis(2000);
+
+/// Minimum time between two progress edits, to stay clear of rate limits.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(1500);
+
+/// A command's reply, deliverable either as the initial response or as an
+/// edit of a deferred one
+#[derive(Debug, Clone, Default)]
+pub struct Reply {
+    content: Option<String>,
+
+    embeds: Vec<CreateEmbed>,
+
+    components: Option<Vec<CreateActionRow>>,
+
+    attachments: Vec<CreateAttachment>,
+
+    ephemeral: bool,
+}
+
+impl Reply {
+    /// Creates an empty reply.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the message text.
+    pub fn content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Appends an embed.
+    pub fn embed(mut self, embed: CreateEmbed) -> Self {
+        self.embeds.push(embed);
+        self
+    }
+
+    /// Replaces all embeds.
+    pub fn embeds(mut self, embeds: Vec<CreateEmbed>) -> Self {
+        self.embeds = embeds;
+        self
+    }
+
+    /// Adds a button, starting a new action row every five buttons.
+    pub fn button(mut self, button: CreateButton) -> Self {
+        let rows = self.components.get_or_insert_with(Vec::new);
+        match rows.last_mut() {
+            Some(CreateActionRow::Buttons(buttons)) if buttons.len() < 5 => buttons.push(button),
+            _ => rows.push(CreateActionRow::Buttons(vec![button])),
+        }
+        self
+    }
+
+    /// Adds a select menu on its own action row.
+    pub fn select_menu(mut self, menu: CreateSelectMenu) -> Self {
+        self.components
+            .get_or_insert_with(Vec::new)
+            .push(CreateActionRow::SelectMenu(menu));
+        self
+    }
+
+    /// Replaces all components. An empty list removes existing ones.
+    pub fn components(mut self, components: Vec<CreateActionRow>) -> Self {
+        self.components = Some(components);
+        self
+    }
+
+    /// Attaches a file.
+    pub fn add_file(mut self, attachment: CreateAttachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    /// Marks the reply as only visible to the invoking user.
+    ///
+    /// Only honoured when the reply is sent as the initial response; a deferred
+    /// response has already been shown publicly.
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.ephemeral = ephemeral;
+        self
+    }
+
+    /// Whether the reply is only shown to the user who invoked the command.
+    pub fn is_ephemeral(&self) -> bool {
+        self.ephemeral
+    }
+
+    /// Converts the reply into an interaction response message.
+    pub fn into_message(self) -> CreateInteractionResponseMessage {
+        let mut message = CreateInteractionResponseMessage::new()
+            .embeds(self.embeds)
+            .add_files(self.attachments)
+            .ephemeral(self.ephemeral);
+        if let Some(content) = self.content {
+            message = message.content(content);
+        }
+        if let Some(components) = self.components {
+            message = message.components(components);
+        }
+        message
+    }
+
+    /// Converts the reply into an edit of the original response.
+    ///
+    /// Content and embeds are always overwritten so progress text left on a
+    /// deferred response is cleared.
+    pub fn into_edit(self) -> EditInteractionResponse {
+        let mut edit = EditInteractionResponse::new()
+            .content(self.content.unwrap_or_default())
+            .embeds(self.embeds);
+        for attachment in self.attachments {
+            edit = edit.new_attachment(attachment);
+        }
+        if let Some(components) = self.components {
+            edit = edit.components(components);
+        }
+        edit
+    }
+
+    /// Converts the reply into a followup message.
+    pub fn into_followup(self) -> CreateInteractionResponseFollowup {
+        let mut followup = CreateInteractionResponseFollowup::new()
+            .embeds(self.embeds)
+            .add_files(self.attachments)
+            .ephemeral(self.ephemeral);
+        if let Some(content) = self.content {
+            followup = followup.content(content);
+        }
+        if let Some(components) = self.components {
+            followup = followup.components(components);
+        }
+        followup
+    }
+}
+
+#[derive(Debug, Default)]
+struct ProgressState {
+    deferred: bool,
+
+    last_update: Option<Instant>,
+}
+
+/// Handle for reporting progress on a long running command
+///
+/// The first update defers the interaction if that has not happened yet, and
+/// every update replaces the text of the original response. Updates arriving
+/// faster than once every 1.5 seconds are dropped.
+pub struct Progress {
+    http: Arc<Http>,
+
+    command: CommandInteraction,
+
+    ephemeral: bool,
+
+    state: Mutex<ProgressState>,
+}
+
+impl Progress {
+    /// Creates a progress handle for a command interaction.
+    pub fn new(http: Arc<Http>, command: &CommandInteraction) -> Self {
+        Self {
+            http,
+            command: command.clone(),
+            ephemeral: false,
+            state: Mutex::new(ProgressState::default()),
+        }
+    }
+
+    /// Sets whether deferring shows the response only to the invoking user.
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.ephemeral = ephemeral;
+        self
+    }
+
+    /// Shows a status message on the original response
+    ///
+    /// Failures are logged rather than returned, since progress is cosmetic and
+    /// should never abort the command reporting it.
+    ///
+    /// # Arguments
+    /// * `message` - The status to display, e.g. "Fetched 40/100 squads"
+    pub async fn update(&self, message: impl Into<String>) {
+        let mut state = self.state.lock().await;
+        if let Err(e) = self.defer_locked(&mut state).await {
+            warn!("Failed to defer command {}: {}", self.command.data.name, e);
+            return;
+        }
+        if state
+            .last_update
+            .is_some_and(|last| last.elapsed() < PROGRESS_INTERVAL)
+        {
+            return;
+        }
+        state.last_update = Some(Instant::now());
+
+        let edit = EditInteractionResponse::new().content(message);
+        if let Err(e) = self.command.edit_response(&self.http, edit).await {
+            warn!(
+                "Failed to post progress for command {}: {}",
+                self.command.data.name, e
+            );
+        }
+    }
+
+    /// Defers the interaction, if it has not been deferred already.
+    pub async fn defer(&self) -> serenity::Result<()> {
+        let mut state = self.state.lock().await;
+        self.defer_locked(&mut state).await
+    }
+
+    /// Whether the interaction has been deferred.
+    pub async fn is_deferred(&self) -> bool {
+        self.state.lock().await.deferred
+    }
+
+    async fn defer_locked(&self, state: &mut ProgressState) -> serenity::Result<()> {
+        if !state.deferred {
+            if self.ephemeral {
+                self.command.defer_ephemeral(&self.http).await?;
+            } else {
+                self.command.defer(&self.http).await?;
+            }
+            state.deferred = true;
+        }
+        Ok(())
+    }
+}
+
+/// Runs a slash command and delivers its reply
+///
+/// Replies directly if the command finishes within [`DEFER_AFTER`]; otherwise
+/// defers the interaction and edits the original response once the command
+/// completes. Errors are shown to the user as "Error: ..." either way.
+/// Commands that are [`ephemeral`](SlashCommand::ephemeral) are deferred
+/// privately. An ephemeral reply to a command deferred publicly replaces the
+/// public response with a private followup, so it is never shown to everyone.
+/// Members without the command's [`access`](SlashCommand::access) level get an
+/// ephemeral explanation and the command is not run.
+///
+/// # Arguments
+/// * `ctx` - The Discord context for making API calls
+/// * `command` - The slash command interaction to answer
+/// * `handler` - The registered command to run
+pub async fn respond(ctx: &Context, command: &CommandInteraction, handler: &dyn SlashCommand) {
+    if let Err(e) = permissions::authorize(command, handler.access()).await {
+        let reply = Reply::new().content(e.to_string()).ephemeral(true);
+        if let Err(why) = command
+            .create_response(
+                &ctx.http,
+                CreateInteractionResponse::Message(reply.into_message()),
+            )
+            .await
+        {
+            info!("Cannot respond to slash command: {}", why);
+        }
+        return;
+    }
+
+    let progress = Progress::new(ctx.http.clone(), command).ephemeral(handler.ephemeral());
+    let run = handler.run(ctx, command, &progress);
+    tokio::pin!(run);
+    let result = tokio::select! {
+        result = &mut run => result,
+        _ = tokio::time::sleep(DEFER_AFTER) => {
+            info!(
+                "Command {} is taking longer than {:?}, deferring",
+                command.data.name, DEFER_AFTER
+            );
+            if let Err(e) = progress.defer().await {
+                error!("Failed to defer command {}: {}", command.data.name, e);
+            }
+            run.await
+        }
+    };
+
+    let reply = result.unwrap_or_else(|e| Reply::new().content(format!("Error: {}", e)));
+
+    let delivered = if !progress.is_deferred().await {
+        command
+            .create_response(
+                &ctx.http,
+                CreateInteractionResponse::Message(reply.into_message()),
+            )
+            .await
+    } else if reply.is_ephemeral() && !handler.ephemeral() {
+        if let Err(why) = command.delete_response(&ctx.http).await {
+            info!("Cannot remove deferred response: {}", why);
+        }
+        command
+            .create_followup(&ctx.http, reply.into_followup())
+            .await
+            .map(|_| ())
+    } else {
+        command
+            .edit_response(&ctx.http, reply.into_edit())
+            .await
+            .map(|_| ())
+    };
+
+    if let Err(why) = delivered {
+        info!("Cannot respond to slash command: {}", why);
+    }
+}
@@ -0,0 +1,325 @@
+//! Slash command registration
+//!
+//! Decides where commands are registered (globally or in specific guilds) and
+//! keeps Discord in sync with the local definitions. Registered commands are
+//! compared with the definitions first, so Discord is only written to when a
+//! definition actually changed.
+
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, Result};
+use log::info;
+use serde_json::{Map, Value};
+use serenity::all::{Command, CreateCommand, GuildId, Http};
+
+/// Guild commands were registered in before registration was configurable.
+const LEGACY_GUILD_ID: u64 = 1221876813165363270;
+
+/// Where slash commands are registered
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistrationMode {
+    /// Registered once for every guild the bot is in. Updates can take up to an
+    /// hour to reach clients.
+    Global,
+
+    /// Registered separately in each listed guild. Updates apply immediately,
+    /// which makes this the better choice while developing.
+    Guilds(Vec<GuildId>),
+}
+
+impl RegistrationMode {
+    /// Builds a registration mode from its textual configuration
+    ///
+    /// # Arguments
+    /// * `mode` - `global` or `guilds` (case insensitive)
+    /// * `guild_ids` - comma separated guild IDs, ignored in global mode
+    ///
+    /// Guild mode without any guild IDs falls back to the original development
+    /// guild.
+    ///
+    /// # Errors
+    /// Returns error if the mode is unknown or a guild ID is not a number
+    pub fn parse(mode: &str, guild_ids: &str) -> Result<Self> {
+        match mode.trim().to_lowercase().as_str() {
+            "global" => Ok(Self::Global),
+            "guild" | "guilds" => {
+                let mut ids = Vec::new();
+                for id in guild_ids
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                {
+                    let id: u64 = id
+                        .parse()
+                        .map_err(|_| anyhow!("Invalid guild ID {:?}", id))?;
+                    ids.push(GuildId::new(id));
+                }
+                if ids.is_empty() {
+                    ids.push(GuildId::new(LEGACY_GUILD_ID));
+                }
+                Ok(Self::Guilds(ids))
+            }
+            other => Err(anyhow!(
+                "Unknown command registration mode {:?}, expected \"global\" or \"guilds\"",
+                other
+            )),
+        }
+    }
+}
+
+/// Differences between local command definitions and those registered with Discord
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CommandDiff {
+    pub added: Vec<String>,
+
+    pub changed: Vec<String>,
+
+    pub removed: Vec<String>,
+}
+
+impl CommandDiff {
+    /// Whether the registered commands already match the definitions.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl std::fmt::Display for CommandDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return f.write_str("up to date");
+        }
+        let mut parts = Vec::new();
+        for (label, names) in [
+            ("added", &self.added),
+            ("changed", &self.changed),
+            ("removed", &self.removed),
+        ] {
+            if !names.is_empty() {
+                parts.push(format!("{} {}", label, names.join(", ")));
+            }
+        }
+        f.write_str(&parts.join("; "))
+    }
+}
+
+/// Reduces a serialized command to the fields Discord compares, so a local
+/// `CreateCommand` and a registered `Command` describing the same thing produce
+/// equal values.
+fn signature(command: &Value) -> Value {
+    let mut normalized = option_signature(command);
+    // chat input commands are type 1 whether or not the definition says so
+    if let Some(object) = normalized.as_object_mut() {
+        if object.get("type").and_then(Value::as_u64) == Some(1) {
+            object.remove("type");
+        }
+    }
+    normalized
+}
+
+/// Reduces a serialized command or command option to its compared fields.
+fn option_signature(value: &Value) -> Value {
+    const KEYS: [&str; 13] = [
+        "type",
+        "name",
+        "description",
+        "required",
+        "autocomplete",
+        "choices",
+        "options",
+        "min_value",
+        "max_value",
+        "min_length",
+        "max_length",
+        "channel_types",
+        "default_member_permissions",
+    ];
+
+    let Some(object) = value.as_object() else {
+        return value.clone();
+    };
+
+    let mut out = Map::new();
+    for key in KEYS {
+        let field = match object.get(key) {
+            None | Some(Value::Null) | Some(Value::Bool(false)) => continue,
+            Some(Value::Array(items)) if items.is_empty() => continue,
+            Some(field) => field,
+        };
+        let normalized = match (key, field) {
+            ("default_member_permissions", Value::Number(n)) => Value::String(n.to_string()),
+            ("choices", Value::Array(choices)) => Value::Array(
+                choices
+                    .iter()
+                    .map(|c| {
+                        let mut choice = Map::new();
+                        choice.insert("name".to_string(), c["name"].clone());
+                        choice.insert("value".to_string(), c["value"].clone());
+                        Value::Object(choice)
+                    })
+                    .collect(),
+            ),
+            ("options", Value::Array(options)) => {
+                Value::Array(options.iter().map(option_signature).collect())
+            }
+            (_, field) => field.clone(),
+        };
+        out.insert(key.to_string(), normalized);
+    }
+    Value::Object(out)
+}
+
+/// Compares local command definitions with the commands registered on Discord
+///
+/// # Arguments
+/// * `desired` - Local definitions, serialized from `CreateCommand`
+/// * `registered` - Registered commands, serialized from `Command`
+///
+/// # Returns
+/// * `CommandDiff` - Names of commands to add, update and delete
+pub fn diff_commands(desired: &[Value], registered: &[Value]) -> CommandDiff {
+    let index = |commands: &[Value]| -> BTreeMap<String, Value> {
+        commands
+            .iter()
+            .map(|c| {
+                (
+                    c["name"].as_str().unwrap_or_default().to_string(),
+                    signature(c),
+                )
+            })
+            .collect()
+    };
+    let desired = index(desired);
+    let registered = index(registered);
+
+    let mut diff = CommandDiff::default();
+    for (name, definition) in &desired {
+        match registered.get(name) {
+            None => diff.added.push(name.clone()),
+            Some(existing) if existing != definition => diff.changed.push(name.clone()),
+            Some(_) => {}
+        }
+    }
+    diff.removed = registered
+        .keys()
+        .filter(|name| !desired.contains_key(*name))
+        .cloned()
+        .collect();
+    diff
+}
+
+/// Fetches the commands currently registered in a scope
+async fn registered_commands(http: &Http, guild: Option<GuildId>) -> Result<Vec<Command>> {
+    Ok(match guild {
+        Some(guild_id) => guild_id.get_commands(http).await?,
+        None => Command::get_global_commands(http).await?,
+    })
+}
+
+/// Registers commands in one scope if they differ from what Discord has
+///
+/// # Arguments
+/// * `http` - Discord HTTP client
+/// * `guild` - The guild to register in, or `None` for global commands
+/// * `definitions` - The commands that should be registered
+/// * `force` - Overwrite even if nothing changed
+///
+/// # Returns
+/// * `Result<CommandDiff>` - What differed before syncing
+///
+/// # Errors
+/// Returns error if Discord rejects the request
+pub async fn sync_scope(
+    http: &Http,
+    guild: Option<GuildId>,
+    definitions: Vec<CreateCommand>,
+    force: bool,
+) -> Result<CommandDiff> {
+    let desired: Vec<Value> = definitions
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<serde_json::Result<_>>()?;
+    let registered: Vec<Value> = registered_commands(http, guild)
+        .await?
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<serde_json::Result<_>>()?;
+
+    let diff = diff_commands(&desired, &registered);
+    if diff.is_empty() && !force {
+        return Ok(diff);
+    }
+
+    match guild {
+        Some(guild_id) => {
+            guild_id.set_commands(http, definitions).await?;
+        }
+        None => {
+            Command::set_global_commands(http, definitions).await?;
+        }
+    }
+    Ok(diff)
+}
+
+/// Brings every scope of a registration mode in line with the definitions
+///
+/// # Arguments
+/// * `http` - Discord HTTP client
+/// * `mode` - Where commands should be registered
+/// * `definitions` - The commands that should be registered
+/// * `force` - Overwrite even if nothing changed
+///
+/// # Returns
+/// * `Result<Vec<String>>` - One human readable line per scope
+///
+/// # Errors
+/// Returns error if any scope fails to sync
+pub async fn sync_commands(
+    http: &Http,
+    mode: &RegistrationMode,
+    definitions: Vec<CreateCommand>,
+    force: bool,
+) -> Result<Vec<String>> {
+    let scopes: Vec<Option<GuildId>> = match mode {
+        RegistrationMode::Global => vec![None],
+        RegistrationMode::Guilds(ids) => ids.iter().copied().map(Some).collect(),
+    };
+
+    let mut report = Vec::new();
+    for scope in scopes {
+        let diff = sync_scope(http, scope, definitions.clone(), force).await?;
+        let line = format!("{}: {}", scope_label(scope), diff);
+        info!("Command sync {}", line);
+        report.push(line);
+    }
+    Ok(report)
+}
+
+/// Removes every command registered in a scope
+///
+/// # Arguments
+/// * `http` - Discord HTTP client
+/// * `guild` - The guild to purge, or `None` for global commands
+///
+/// # Errors
+/// Returns error if Discord rejects the request
+pub async fn purge_scope(http: &Http, guild: Option<GuildId>) -> Result<()> {
+    match guild {
+        Some(guild_id) => {
+            guild_id.set_commands(http, vec![]).await?;
+        }
+        None => {
+            Command::set_global_commands(http, vec![]).await?;
+        }
+    }
+    info!("Purged commands from {}", scope_label(guild));
+    Ok(())
+}
+
+/// Human readable name of a registration scope
+pub fn scope_label(guild: Option<GuildId>) -> String {
+    match guild {
+        Some(guild_id) => format!("guild {}", guild_id),
+        None => "global".to_string(),
+    }
+}
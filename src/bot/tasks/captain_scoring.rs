@@ -0,0 +1,139 @@
+//! Captain poll scoring
+//!
+//! Periodically checks open captain polls, and once FPL has finished and
+//! checked a poll's gameweek, stores each option's live points and posts the
+//! result to the poll's channel.
+
+use anyhow::Result;
+use log::{error, info};
+use serenity::all::{ChannelId, CreateMessage};
+use serenity::builder::{CreateEmbed, CreateEmbedFooter};
+use serenity::http::Http;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::bot::commands::captain_poll::poll_result;
+use crate::database::service::db_service;
+use crate::fpl::captaincy::PollResult;
+use crate::fpl::models::gameweek::update_gameweek_info;
+use crate::fpl::models::general::GeneralData;
+
+/// Runs the scorer forever, checking every `poll_interval`
+pub async fn run(http: Arc<Http>, poll_interval: Duration) {
+    let mut interval = tokio::time::interval(poll_interval);
+    loop {
+        interval.tick().await;
+        if let Err(e) = poll_once(&http).await {
+            error!("Captain poll scoring failed: {}", e);
+        }
+    }
+}
+
+/// Scores every open poll whose gameweek is finished and posts the results
+pub async fn poll_once(http: &Http) -> Result<()> {
+    let db = db_service();
+    let polls = db.get_unscored_captain_polls().await?;
+    if polls.is_empty() {
+        return Ok(());
+    }
+
+    let general = GeneralData::fetch().await?;
+    let mut live_points: HashMap<i32, HashMap<i32, i32>> = HashMap::new();
+    for poll in polls {
+        let finished = general
+            .event(poll.gameweek)
+            .is_some_and(|event| event.finished && event.data_checked);
+        if poll.season != general.season() || !finished {
+            continue;
+        }
+
+        let points = match live_points.entry(poll.gameweek) {
+            Entry::Occupied(points) => points.into_mut(),
+            Entry::Vacant(entry) => {
+                let live = update_gameweek_info(poll.gameweek).await?;
+                entry.insert(
+                    live.elements
+                        .iter()
+                        .map(|e| (e.id, e.stats.total_points))
+                        .collect(),
+                )
+            }
+        };
+
+        let options: Vec<(i32, i32)> = db
+            .get_captain_options(poll.id)
+            .await?
+            .iter()
+            .map(|option| {
+                (
+                    option.element,
+                    points.get(&option.element).copied().unwrap_or(0),
+                )
+            })
+            .collect();
+        db.score_captain_poll(poll.id, &options).await?;
+        info!("Scored captain poll {} for GW{}", poll.id, poll.gameweek);
+
+        let result = poll_result(poll.id).await?;
+        let embed = build_result_embed(poll.gameweek, &result, &general);
+        let channel = ChannelId::new(poll.channel_id as u64);
+        if let Err(e) = channel
+            .send_message(http, CreateMessage::new().embed(embed))
+            .await
+        {
+            error!(
+                "Failed to post captain poll result to channel {}: {}",
+                channel, e
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Builds the result embed: each option's points and votes, and who picked best
+pub fn build_result_embed(
+    gameweek: i32,
+    result: &PollResult,
+    general: &GeneralData,
+) -> CreateEmbed {
+    let mut options = result.options.clone();
+    options.sort_by_key(|(_, points)| std::cmp::Reverse(*points));
+    let best = result.best_points();
+
+    let mut description = String::new();
+    for (element, points) in &options {
+        let votes = result.votes.iter().filter(|(_, e)| e == element).count();
+        let marker = if Some(*points) == best { "🏆 " } else { "" };
+        description.push_str(&format!(
+            "{}**{}** {} pts • {} vote{}\n",
+            marker,
+            general.player_name(*element),
+            points,
+            votes,
+            if votes == 1 { "" } else { "s" }
+        ));
+    }
+
+    let winners: Vec<String> = result
+        .votes
+        .iter()
+        .filter(|(_, element)| Some(result.points(*element)) == best)
+        .map(|(user_id, _)| format!("<@{}>", user_id))
+        .collect();
+    description.push('\n');
+    if winners.is_empty() {
+        description.push_str("Nobody picked the best captain.");
+    } else {
+        description.push_str(&format!("Best pick: {}", winners.join(", ")));
+    }
+
+    CreateEmbed::new()
+        .title(format!("🎖️ GW{} captain poll result", gameweek))
+        .description(description)
+        .color(0x00ff87) // green
+        .footer(CreateEmbedFooter::new(
+            "See the season standings with /captain_poll leaderboard",
+        ))
+}
@@ -0,0 +1,51 @@
+//! Background tasks for the FPL bot
+//!
+//! Long-running pollers that watch the FPL API for changes and post updates to
+//! Discord channels without a user interaction to respond to.
+//!
+//! # Tasks
+//!
+//! * [`schedule_watch`] - Detects rescheduled fixtures and alerts opted-in channels
+//! * [`captain_scoring`] - Scores captain polls once their gameweek is finished
+//! * [`prediction_scoring`] - Scores fixture predictions once the fixture is finished
+
+use log::info;
+use serenity::http::Http;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::config::config;
+
+pub mod captain_scoring;
+pub mod prediction_scoring;
+pub mod schedule_watch;
+
+static STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Spawns every background task
+///
+/// Safe to call on each `ready` event: tasks are only spawned the first time,
+/// so gateway reconnects do not start duplicate pollers. Poll intervals come
+/// from the `[polling]` section of the configuration.
+///
+/// # Arguments
+/// * `http` - Discord HTTP client used to post updates
+pub fn spawn_background_tasks(http: Arc<Http>) {
+    if STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    info!("Starting background tasks");
+    let polling = &config().polling;
+    tokio::spawn(schedule_watch::run(
+        http.clone(),
+        polling.schedule_watch_interval(),
+    ));
+    tokio::spawn(captain_scoring::run(
+        http,
+        polling.captain_scoring_interval(),
+    ));
+    tokio::spawn(prediction_scoring::run(
+        polling.prediction_scoring_interval(),
+    ));
+}
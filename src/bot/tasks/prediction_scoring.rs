@@ -0,0 +1,58 @@
+//! Prediction scoring
+//!
+//! Periodically checks unscored fixture predictions and scores those whose
+//! fixture FPL has marked finished. Standings are read from the stored points,
+//! so nothing is posted to Discord.
+
+use anyhow::Result;
+use log::{error, info};
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::database::service::db_service;
+use crate::fpl::models::fixtures::{fetch_all_fixtures, Fixture};
+use crate::fpl::predictions::score_prediction;
+
+/// Runs the scorer forever, checking every `poll_interval`
+pub async fn run(poll_interval: Duration) {
+    let mut interval = tokio::time::interval(poll_interval);
+    loop {
+        interval.tick().await;
+        if let Err(e) = poll_once().await {
+            error!("Prediction scoring failed: {}", e);
+        }
+    }
+}
+
+/// Scores every prediction whose fixture is finished
+pub async fn poll_once() -> Result<()> {
+    let db = db_service();
+    let predictions = db.get_unscored_predictions().await?;
+    if predictions.is_empty() {
+        return Ok(());
+    }
+
+    let fixtures: HashMap<i64, Fixture> = fetch_all_fixtures()
+        .await?
+        .into_iter()
+        .map(|fixture| (i64::from(fixture.id), fixture))
+        .collect();
+    let points: Vec<(i64, i32)> = predictions
+        .iter()
+        .filter_map(|prediction| {
+            let fixture = fixtures.get(&prediction.fixture_id)?;
+            if !fixture.finished {
+                return None;
+            }
+            let actual = (fixture.team_h_score?, fixture.team_a_score?);
+            let predicted = (prediction.home_goals as u8, prediction.away_goals as u8);
+            Some((prediction.id, score_prediction(predicted, actual)))
+        })
+        .collect();
+
+    if !points.is_empty() {
+        db.set_prediction_points(&points).await?;
+        info!("Scored {} predictions", points.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,205 @@
+//! Fixture schedule watcher
+//!
+//! Periodically polls the full fixture list, diffs each fixture's gameweek and
+//! kickoff time against the previous poll stored in the database, and posts an
+//! alert to every channel that opted in via `/schedule_alerts`.
+
+use anyhow::{anyhow, Result};
+use log::{error, info};
+use serenity::all::CreateMessage;
+use serenity::builder::CreateEmbed;
+use serenity::http::Http;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::database::models::DBFixtureSlot;
+use crate::database::service::db_service;
+use crate::fpl::calendar::{diff_schedules, FixtureSlot, ScheduleChange, SeasonCalendar};
+use crate::fpl::models::teams::get_team_name;
+
+/// Most moved fixtures listed in one alert; the rest are summarised, keeping
+/// the embed within Discord's size limits after a mass rescheduling.
+const MAX_LISTED_CHANGES: usize = 15;
+
+/// Most gameweeks given a blank/double field in one alert.
+const MAX_GAMEWEEK_FIELDS: usize = 12;
+
+impl From<&FixtureSlot> for DBFixtureSlot {
+    fn from(slot: &FixtureSlot) -> Self {
+        Self {
+            fixture_id: i64::from(slot.fixture_id),
+            team_h: i64::from(slot.team_h),
+            team_a: i64::from(slot.team_a),
+            event: slot.event.map(i64::from),
+            kickoff_time: slot.kickoff_time,
+        }
+    }
+}
+
+impl From<DBFixtureSlot> for FixtureSlot {
+    fn from(slot: DBFixtureSlot) -> Self {
+        Self {
+            fixture_id: slot.fixture_id as u32,
+            team_h: slot.team_h as i32,
+            team_a: slot.team_a as i32,
+            event: slot.event.map(|e| e as u8),
+            kickoff_time: slot.kickoff_time,
+        }
+    }
+}
+
+/// Runs the watcher forever, polling every `poll_interval`
+pub async fn run(http: Arc<Http>, poll_interval: Duration) {
+    let mut interval = tokio::time::interval(poll_interval);
+    loop {
+        interval.tick().await;
+        if let Err(e) = poll_once(&http).await {
+            error!("Fixture schedule poll failed: {}", e);
+        }
+    }
+}
+
+/// Fetches the fixture list once, stores it and alerts channels about changes
+///
+/// The very first poll only seeds the stored schedule, since there is nothing
+/// to compare it against. The new schedule is only stored once the alert has
+/// reached at least one channel, so changes are reported again on the next
+/// poll if every post failed.
+pub async fn poll_once(http: &Http) -> Result<()> {
+    let db = db_service();
+    let calendar = SeasonCalendar::fetch().await?;
+    let current = calendar.slots();
+
+    let previous: Vec<FixtureSlot> = db
+        .get_fixture_schedule()
+        .await?
+        .into_iter()
+        .map(FixtureSlot::from)
+        .collect();
+    let changes = diff_schedules(&previous, &current);
+
+    if !changes.is_empty() {
+        info!("Detected {} fixture schedule changes", changes.len());
+
+        let embed = build_alert_embed(&changes, &calendar);
+        let channels = db.get_schedule_alert_channels().await?;
+        let mut delivered = channels.is_empty();
+        for channel in channels {
+            match channel
+                .send_message(http, CreateMessage::new().embed(embed.clone()))
+                .await
+            {
+                Ok(_) => delivered = true,
+                Err(e) => error!(
+                    "Failed to send schedule alert to channel {}: {}",
+                    channel, e
+                ),
+            }
+        }
+        if !delivered {
+            return Err(anyhow!(
+                "No schedule alert was delivered, keeping the previous schedule"
+            ));
+        }
+    }
+
+    let stored: Vec<DBFixtureSlot> = current.iter().map(DBFixtureSlot::from).collect();
+    db.replace_fixture_schedule(&stored).await?;
+    Ok(())
+}
+
+/// Builds the alert embed listing each moved fixture
+///
+/// Also reports the resulting blank/double status of every gameweek a fixture
+/// was moved into or out of. At most [`MAX_LISTED_CHANGES`] fixtures and
+/// [`MAX_GAMEWEEK_FIELDS`] gameweeks are shown, followed by a count of the rest.
+pub fn build_alert_embed(changes: &[ScheduleChange], calendar: &SeasonCalendar) -> CreateEmbed {
+    let mut description = String::new();
+    let mut affected = BTreeSet::new();
+
+    for change in changes {
+        if change.gameweek_changed() {
+            affected.extend(change.previous.event.map(i32::from));
+            affected.extend(change.current.event.map(i32::from));
+        }
+    }
+
+    for change in changes.iter().take(MAX_LISTED_CHANGES) {
+        let home = get_team_name(change.current.team_h).short_name;
+        let away = get_team_name(change.current.team_a).short_name;
+        let kickoff = change
+            .current
+            .kickoff_time
+            .map(|t| t.format("%a %d.%m %H:%M").to_string())
+            .unwrap_or_else(|| "TBC".to_string());
+
+        if change.gameweek_changed() {
+            description.push_str(&format!(
+                "**{} v {}**: {} → {} ({})\n",
+                home,
+                away,
+                format_gameweek(change.previous.event),
+                format_gameweek(change.current.event),
+                kickoff
+            ));
+        } else {
+            description.push_str(&format!(
+                "**{} v {}**: kickoff moved to {} ({})\n",
+                home,
+                away,
+                kickoff,
+                format_gameweek(change.current.event)
+            ));
+        }
+    }
+    if changes.len() > MAX_LISTED_CHANGES {
+        description.push_str(&format!(
+            "…and {} more\n",
+            changes.len() - MAX_LISTED_CHANGES
+        ));
+    }
+    if affected.len() > MAX_GAMEWEEK_FIELDS {
+        description.push_str(&format!(
+            "\nBlanks and doubles shown for the first {} of {} affected gameweeks\n",
+            MAX_GAMEWEEK_FIELDS,
+            affected.len()
+        ));
+    }
+
+    let mut embed = CreateEmbed::new()
+        .title("📅 Fixtures rescheduled")
+        .description(description)
+        .color(0x37003c);
+
+    for gw in affected.into_iter().take(MAX_GAMEWEEK_FIELDS) {
+        let summary = calendar.summary(gw);
+        let names = |teams: &[i32]| {
+            teams
+                .iter()
+                .map(|id| get_team_name(*id).short_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut value = String::new();
+        if !summary.doubles.is_empty() {
+            value.push_str(&format!("Double: {}\n", names(&summary.doubles)));
+        }
+        if !summary.blanks.is_empty() {
+            value.push_str(&format!("Blank: {}\n", names(&summary.blanks)));
+        }
+        if value.is_empty() {
+            value.push_str("Every team plays once");
+        }
+        embed = embed.field(format!("GW{}", gw), value, true);
+    }
+
+    embed
+}
+
+fn format_gameweek(event: Option<u8>) -> String {
+    match event {
+        Some(gw) => format!("GW{}", gw),
+        None => "unscheduled".to_string(),
+    }
+}
@@ -0,0 +1,415 @@
+//! Bot configuration
+//!
+//! Settings are layered: built-in defaults, then an optional TOML file
+//! (`config.toml`, or the path in `FPLBOT_CONFIG`), then environment variables.
+//! The merged configuration is validated once at startup so misconfiguration
+//! fails fast with every problem listed, instead of surfacing later as a
+//! confusing runtime error.
+//!
+//! See `config.example.toml` for every setting and its default.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::{env, fs};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use serenity::all::GuildId;
+
+use crate::bot::sync::RegistrationMode;
+
+/// Config file read when `FPLBOT_CONFIG` is not set. Optional.
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// The complete bot configuration
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub discord: DiscordConfig,
+
+    pub database: DatabaseConfig,
+
+    pub fpl: FplConfig,
+
+    pub polling: PollingConfig,
+
+    pub logging: LoggingConfig,
+}
+
+/// Discord connection and command registration
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DiscordConfig {
+    /// Bot token. Prefer setting `BOT_TOKEN` over writing it to a file.
+    pub token: String,
+
+    /// `global` or `guilds`
+    pub registration: String,
+
+    /// Guilds to register commands in when `registration` is `guilds`
+    pub guild_ids: Vec<u64>,
+}
+
+/// Database location
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    pub url: String,
+}
+
+/// FPL API client settings
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FplConfig {
+    pub base_url: String,
+
+    /// Per-request timeout
+    pub timeout_secs: u64,
+
+    /// Extra attempts after a failed request (network errors, 429 and 5xx)
+    pub retries: u32,
+
+    /// Delay before the first retry, doubled on each further retry
+    pub retry_backoff_ms: u64,
+}
+
+/// Background task intervals
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PollingConfig {
+    /// How often the fixture list is checked for rescheduled matches
+    pub schedule_watch_secs: u64,
+
+    /// How often open captain polls are checked for a finished gameweek to score
+    pub captain_scoring_secs: u64,
+
+    /// How often unscored predictions are checked for a finished fixture
+    pub prediction_scoring_secs: u64,
+}
+
+/// Log output
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoggingConfig {
+    /// `full`, `compact`, `pretty` or `json`
+    pub format: String,
+
+    /// `error`, `warn`, `info`, `debug` or `trace`
+    pub level: String,
+}
+
+impl Default for DiscordConfig {
+    fn default() -> Self {
+        Self {
+            token: String::new(),
+            registration: "guilds".to_string(),
+            guild_ids: Vec::new(),
+        }
+    }
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            url: "sqlite:fplbot.db".to_string(),
+        }
+    }
+}
+
+impl Default for FplConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "https://fantasy.premierleague.com/api".to_string(),
+            timeout_secs: 5,
+            retries: 2,
+            retry_backoff_ms: 500,
+        }
+    }
+}
+
+impl Default for PollingConfig {
+    fn default() -> Self {
+        Self {
+            schedule_watch_secs: 30 * 60,
+            captain_scoring_secs: 60 * 60,
+            prediction_scoring_secs: 15 * 60,
+        }
+    }
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            format: "full".to_string(),
+            level: "info".to_string(),
+        }
+    }
+}
+
+impl FplConfig {
+    /// The per-request timeout as a duration.
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+
+    /// Delay before retry number `attempt` (1-based).
+    pub fn retry_delay(&self, attempt: u32) -> Duration {
+        Duration::from_millis(
+            self.retry_backoff_ms
+                .saturating_mul(1 << attempt.saturating_sub(1).min(10)),
+        )
+    }
+}
+
+impl PollingConfig {
+    /// The fixture schedule poll interval as a duration.
+    pub fn schedule_watch_interval(&self) -> Duration {
+        Duration::from_secs(self.schedule_watch_secs)
+    }
+
+    /// The captain poll scoring interval as a duration.
+    pub fn captain_scoring_interval(&self) -> Duration {
+        Duration::from_secs(self.captain_scoring_secs)
+    }
+
+    /// The prediction scoring interval as a duration.
+    pub fn prediction_scoring_interval(&self) -> Duration {
+        Duration::from_secs(self.prediction_scoring_secs)
+    }
+}
+
+impl DiscordConfig {
+    /// Where slash commands should be registered
+    ///
+    /// # Errors
+    /// Returns error if `registration` is not a known mode
+    pub fn registration_mode(&self) -> Result<RegistrationMode> {
+        let guild_ids: Vec<String> = self.guild_ids.iter().map(u64::to_string).collect();
+        RegistrationMode::parse(&self.registration, &guild_ids.join(","))
+    }
+}
+
+impl Config {
+    /// Loads the configuration from defaults, the config file and the environment
+    ///
+    /// The file is `FPLBOT_CONFIG` if set (and must then exist), otherwise
+    /// `config.toml` if present.
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be read or parsed, an environment
+    /// variable has the wrong type, or validation fails
+    pub fn load() -> Result<Self> {
+        let (path, required) = match env::var("FPLBOT_CONFIG") {
+            Ok(path) => (PathBuf::from(path), true),
+            Err(_) => (PathBuf::from(DEFAULT_CONFIG_PATH), false),
+        };
+
+        let mut config = if required || path.exists() {
+            Self::from_file(&path)?
+        } else {
+            Self::default()
+        };
+        config.apply_env(|key| env::var(key).ok())?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parses a TOML config file on top of the defaults
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be read or is not valid config TOML
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        Self::from_toml(&text).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+
+    /// Parses config TOML on top of the defaults
+    ///
+    /// # Errors
+    /// Returns error on invalid TOML, unknown keys or wrongly typed values
+    pub fn from_toml(text: &str) -> Result<Self> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// Applies environment variable overrides
+    ///
+    /// | Variable | Setting |
+    /// |---|---|
+    /// | `BOT_TOKEN` | `discord.token` |
+    /// | `COMMAND_REGISTRATION` | `discord.registration` |
+    /// | `COMMAND_GUILD_IDS` | `discord.guild_ids` (comma separated) |
+    /// | `BOT_ENV` | `discord.registration` is `global` when `production`, unless set explicitly |
+    /// | `FPLBOT_DATABASE_URL` | `database.url` |
+    /// | `FPLBOT_FPL_BASE_URL` | `fpl.base_url` |
+    /// | `FPLBOT_FPL_TIMEOUT_SECS` | `fpl.timeout_secs` |
+    /// | `FPLBOT_FPL_RETRIES` | `fpl.retries` |
+    /// | `FPLBOT_SCHEDULE_WATCH_SECS` | `polling.schedule_watch_secs` |
+    /// | `FPLBOT_CAPTAIN_SCORING_SECS` | `polling.captain_scoring_secs` |
+    /// | `FPLBOT_PREDICTION_SCORING_SECS` | `polling.prediction_scoring_secs` |
+    /// | `FPLBOT_LOG_FORMAT` | `logging.format` |
+    /// | `FPLBOT_LOG_LEVEL` | `logging.level` |
+    ///
+    /// # Arguments
+    /// * `lookup` - Reads a variable, e.g. `|key| std::env::var(key).ok()`
+    ///
+    /// # Errors
+    /// Returns error if a numeric variable does not parse
+    pub fn apply_env(&mut self, lookup: impl Fn(&str) -> Option<String>) -> Result<()> {
+        fn number<T: std::str::FromStr>(key: &str, value: &str) -> Result<T> {
+            value
+                .trim()
+                .parse()
+                .map_err(|_| anyhow!("{} must be a number, got {:?}", key, value))
+        }
+
+        if let Some(token) = lookup("BOT_TOKEN") {
+            self.discord.token = token;
+        }
+        if lookup("BOT_ENV").as_deref() == Some("production") {
+            self.discord.registration = "global".to_string();
+        }
+        if let Some(mode) = lookup("COMMAND_REGISTRATION") {
+            self.discord.registration = mode;
+        }
+        if let Some(ids) = lookup("COMMAND_GUILD_IDS") {
+            self.discord.guild_ids = ids
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|id| number("COMMAND_GUILD_IDS", id))
+                .collect::<Result<_>>()?;
+        }
+        if let Some(url) = lookup("FPLBOT_DATABASE_URL") {
+            self.database.url = url;
+        }
+        if let Some(url) = lookup("FPLBOT_FPL_BASE_URL") {
+            self.fpl.base_url = url;
+        }
+        if let Some(secs) = lookup("FPLBOT_FPL_TIMEOUT_SECS") {
+            self.fpl.timeout_secs = number("FPLBOT_FPL_TIMEOUT_SECS", &secs)?;
+        }
+        if let Some(retries) = lookup("FPLBOT_FPL_RETRIES") {
+            self.fpl.retries = number("FPLBOT_FPL_RETRIES", &retries)?;
+        }
+        if let Some(secs) = lookup("FPLBOT_SCHEDULE_WATCH_SECS") {
+            self.polling.schedule_watch_secs = number("FPLBOT_SCHEDULE_WATCH_SECS", &secs)?;
+        }
+        if let Some(secs) = lookup("FPLBOT_CAPTAIN_SCORING_SECS") {
+            self.polling.captain_scoring_secs = number("FPLBOT_CAPTAIN_SCORING_SECS", &secs)?;
+        }
+        if let Some(secs) = lookup("FPLBOT_PREDICTION_SCORING_SECS") {
+            self.polling.prediction_scoring_secs = number("FPLBOT_PREDICTION_SCORING_SECS", &secs)?;
+        }
+        if let Some(format) = lookup("FPLBOT_LOG_FORMAT") {
+            self.logging.format = format;
+        }
+        if let Some(level) = lookup("FPLBOT_LOG_LEVEL") {
+            self.logging.level = level;
+        }
+        Ok(())
+    }
+
+    /// Checks every setting, reporting all problems at once
+    ///
+    /// # Errors
+    /// Returns error listing each invalid setting
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = Vec::new();
+
+        if self.discord.token.trim().is_empty() {
+            problems.push("discord.token is empty; set BOT_TOKEN".to_string());
+        }
+        if let Err(e) = self.discord.registration_mode() {
+            problems.push(format!("discord.registration: {}", e));
+        }
+        if self.discord.guild_ids.contains(&0) {
+            problems.push("discord.guild_ids must not contain 0".to_string());
+        }
+        if !self.database.url.starts_with("sqlite:") {
+            problems.push(format!(
+                "database.url must be a sqlite: URL, got {:?}",
+                self.database.url
+            ));
+        }
+        if !(self.fpl.base_url.starts_with("https://") || self.fpl.base_url.starts_with("http://"))
+        {
+            problems.push(format!(
+                "fpl.base_url must be an http(s) URL, got {:?}",
+                self.fpl.base_url
+            ));
+        }
+        if self.fpl.base_url.ends_with('/') {
+            problems.push("fpl.base_url must not end with '/'".to_string());
+        }
+        if !(1..=120).contains(&self.fpl.timeout_secs) {
+            problems.push("fpl.timeout_secs must be between 1 and 120".to_string());
+        }
+        if self.fpl.retries > 10 {
+            problems.push("fpl.retries must be at most 10".to_string());
+        }
+        if self.polling.schedule_watch_secs < 60 {
+            problems.push("polling.schedule_watch_secs must be at least 60".to_string());
+        }
+        if self.polling.captain_scoring_secs < 60 {
+            problems.push("polling.captain_scoring_secs must be at least 60".to_string());
+        }
+        if self.polling.prediction_scoring_secs < 60 {
+            problems.push("polling.prediction_scoring_secs must be at least 60".to_string());
+        }
+        if !["full", "compact", "pretty", "json"].contains(&self.logging.format.as_str()) {
+            problems.push(format!(
+                "logging.format must be full, compact, pretty or json, got {:?}",
+                self.logging.format
+            ));
+        }
+        if self.logging.level.parse::<tracing::Level>().is_err() {
+            problems.push(format!(
+                "logging.level must be error, warn, info, debug or trace, got {:?}",
+                self.logging.level
+            ));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "Invalid configuration:\n  - {}",
+                problems.join("\n  - ")
+            ))
+        }
+    }
+
+    /// Guild IDs from `discord.guild_ids` as Discord IDs.
+    pub fn guild_ids(&self) -> Vec<GuildId> {
+        self.discord
+            .guild_ids
+            .iter()
+            .map(|id| GuildId::new(*id))
+            .collect()
+    }
+}
+
+/// Stores the loaded configuration for application-wide access
+///
+/// # Errors
+/// Returns error if the configuration was already initialized
+pub fn init_config(config: Config) -> Result<()> {
+    CONFIG
+        .set(config)
+        .map_err(|_| anyhow!("Config already initialized"))
+}
+
+/// Returns the global configuration
+///
+/// # Panics
+///
+/// Panics if `init_config()` has not been called first.
+pub fn config() -> &'static Config {
+    CONFIG
+        .get()
+        .expect("Config not initialized - call init_config() first")
+}
@@ -1,9 +1,105 @@
+use chrono::{DateTime, Utc};
+
 pub struct DBUser {
     pub discord_id: i64,
     pub manager_id: Option<i32>,
+    pub team_name: Option<String>,
+    pub manager_name: Option<String>,
 }
 
-pub struct DBChannel {
+pub struct DBChannelLeague {
     pub channel_id: i64,
+    pub name: String,
+    pub league_id: i32,
+    pub is_primary: bool,
+}
+
+pub struct DBGuildSettings {
+    pub guild_id: i64,
     pub default_league_id: Option<i32>,
 }
+
+pub struct DBFixtureSlot {
+    pub fixture_id: i64,
+    pub team_h: i64,
+    pub team_a: i64,
+    pub event: Option<i64>,
+    pub kickoff_time: Option<DateTime<Utc>>,
+}
+
+pub struct DBAuditEntry {
+    pub guild_id: Option<i64>,
+    pub channel_id: i64,
+    pub user_id: i64,
+    pub command: String,
+    pub change: String,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct DBCaptainPoll {
+    pub id: i64,
+    pub channel_id: i64,
+    pub season: String,
+    pub gameweek: i32,
+    pub deadline: DateTime<Utc>,
+    pub created_by: i64,
+    pub scored: bool,
+}
+
+pub struct DBCaptainOption {
+    pub poll_id: i64,
+    pub element: i32,
+    pub points: Option<i32>,
+}
+
+pub struct DBCaptainVote {
+    pub poll_id: i64,
+    pub user_id: i64,
+    pub element: i32,
+}
+
+pub struct DBPrediction {
+    pub id: i64,
+    pub guild_id: i64,
+    pub season: String,
+    pub fixture_id: i64,
+    pub user_id: i64,
+    pub home_goals: i32,
+    pub away_goals: i32,
+    pub predicted_at: DateTime<Utc>,
+    pub points: Option<i32>,
+}
+
+pub struct DBGameweekPlayerStats {
+    pub gameweek: i32,
+    pub player_id: i32,
+    pub minutes: i32,
+    pub goals_scored: i32,
+    pub assists: i32,
+    pub clean_sheets: i32,
+    pub goals_conceded: i32,
+    pub own_goals: i32,
+    pub penalties_saved: i32,
+    pub penalties_missed: i32,
+    pub yellow_cards: i32,
+    pub red_cards: i32,
+    pub saves: i32,
+    pub bonus: i32,
+    pub bps: i32,
+    pub clearances_blocks_interceptions: i32,
+    pub recoveries: i32,
+    pub tackles: i32,
+    pub defensive_contribution: i32,
+    pub starts: i32,
+    pub influence: f64,
+    pub creativity: f64,
+    pub threat: f64,
+    pub ict_index: f64,
+    pub expected_goals: f64,
+    pub expected_assists: f64,
+    pub expected_goal_involvements: f64,
+    pub expected_goals_conceded: f64,
+    pub total_points: i32,
+    pub in_dreamteam: bool,
+    pub modified: bool,
+}
@@ -1,17 +1,21 @@
 use anyhow::anyhow;
 use anyhow::Result;
-use serenity::all::{ChannelId, UserId};
+use chrono::{DateTime, Utc};
+use serenity::all::{ChannelId, GuildId, RoleId, UserId};
 use sqlx::SqlitePool;
 use std::option::Option;
 
-use crate::database::models::{DBChannel, DBUser};
+use crate::database::models::{
+    DBAuditEntry, DBCaptainOption, DBCaptainPoll, DBCaptainVote, DBChannelLeague, DBFixtureSlot,
+    DBGameweekPlayerStats, DBGuildSettings, DBPrediction, DBUser,
+};
 
 use tokio::sync::OnceCell;
 
 static DB_SERVICE: OnceCell<Database> = OnceCell::const_new();
 
-pub async fn init_db_service() -> Result<()> {
-    let database = Database::setup().await?;
+pub async fn init_db_service(url: &str) -> Result<()> {
+    let database = Database::setup(url).await?;
     DB_SERVICE
         .set(database)
         .map_err(|_| anyhow::anyhow!("Database service already initialized"))?;
@@ -29,8 +33,8 @@ pub struct Database {
 }
 
 impl Database {
-    async fn setup() -> Result<Self> {
-        let pool = SqlitePool::connect("sqlite:fplbot.db").await?;
+    async fn setup(url: &str) -> Result<Self> {
+        let pool = SqlitePool::connect(url).await?;
         sqlx::migrate!("./migrations").run(&pool).await?;
         Ok(Self { pool })
     }
@@ -38,7 +42,7 @@ impl Database {
     pub async fn get_user(&self, discord_id: UserId) -> Result<DBUser> {
         let id = i64::from(discord_id);
         let row = sqlx::query!(
-            "SELECT discord_id, manager_id FROM users WHERE discord_id = ?",
+            "SELECT discord_id, manager_id, team_name, manager_name FROM users WHERE discord_id = ?",
             id
         )
         .fetch_optional(&self.pool)
@@ -48,6 +52,8 @@ impl Database {
             Option::Some(r) => DBUser {
                 discord_id: r.discord_id,
                 manager_id: r.manager_id.map(|i| i as i32),
+                team_name: r.team_name,
+                manager_name: r.manager_name,
             },
             Option::None => return Err(anyhow!("User {} not found", discord_id)),
         };
@@ -56,41 +62,702 @@ impl Database {
 
     pub async fn update_user(&self, user: &DBUser) -> Result<()> {
         sqlx::query!(
-            "INSERT OR REPLACE INTO users (discord_id, manager_id) VALUES (?, ?)",
+            "INSERT OR REPLACE INTO users (discord_id, manager_id, team_name, manager_name)
+            VALUES (?, ?, ?, ?)",
             user.discord_id,
-            user.manager_id
+            user.manager_id,
+            user.team_name,
+            user.manager_name
         )
         .execute(&self.pool)
         .await?;
         Ok(())
     }
 
-    pub async fn get_channel(&self, channel_id: ChannelId) -> Result<DBChannel> {
+    pub async fn remove_user(&self, discord_id: UserId) -> Result<bool> {
+        let id = i64::from(discord_id);
+        let mut tx = self.pool.begin().await?;
+        sqlx::query!("DELETE FROM guild_links WHERE discord_id = ?", id)
+            .execute(&mut *tx)
+            .await?;
+        let result = sqlx::query!("DELETE FROM users WHERE discord_id = ?", id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn add_guild_link(&self, guild_id: GuildId, discord_id: UserId) -> Result<()> {
+        let guild = i64::from(guild_id);
+        let user = i64::from(discord_id);
+        sqlx::query!(
+            "INSERT OR IGNORE INTO guild_links (guild_id, discord_id) VALUES (?, ?)",
+            guild,
+            user
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_guild_users(&self, guild_id: GuildId) -> Result<Vec<DBUser>> {
+        let id = i64::from(guild_id);
+        let rows = sqlx::query!(
+            r#"SELECT u.discord_id as "discord_id!", u.manager_id, u.team_name, u.manager_name
+            FROM guild_links g JOIN users u ON u.discord_id = g.discord_id
+            WHERE g.guild_id = ? AND u.manager_id IS NOT NULL
+            ORDER BY u.team_name"#,
+            id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| DBUser {
+                discord_id: r.discord_id,
+                manager_id: r.manager_id.map(|i| i as i32),
+                team_name: r.team_name,
+                manager_name: r.manager_name,
+            })
+            .collect())
+    }
+
+    pub async fn get_unscoped_users(&self) -> Result<Vec<DBUser>> {
+        let rows = sqlx::query!(
+            "SELECT discord_id, manager_id, team_name, manager_name FROM users
+            WHERE manager_id IS NOT NULL
+            AND discord_id NOT IN (SELECT discord_id FROM guild_links)"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| DBUser {
+                discord_id: r.discord_id,
+                manager_id: r.manager_id.map(|i| i as i32),
+                team_name: r.team_name,
+                manager_name: r.manager_name,
+            })
+            .collect())
+    }
+
+    pub async fn get_channel_leagues(&self, channel_id: ChannelId) -> Result<Vec<DBChannelLeague>> {
+        let id = i64::from(channel_id);
+        let rows = sqlx::query!(
+            "SELECT channel_id, name, league_id, is_primary FROM channel_leagues
+            WHERE channel_id = ? ORDER BY is_primary DESC, name",
+            id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| DBChannelLeague {
+                channel_id: r.channel_id,
+                name: r.name,
+                league_id: r.league_id as i32,
+                is_primary: r.is_primary != 0,
+            })
+            .collect())
+    }
+
+    pub async fn upsert_channel_league(&self, league: &DBChannelLeague) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        if league.is_primary {
+            sqlx::query!(
+                "UPDATE channel_leagues SET is_primary = 0 WHERE channel_id = ?",
+                league.channel_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        sqlx::query!(
+            "INSERT OR REPLACE INTO channel_leagues (channel_id, name, league_id, is_primary)
+            VALUES (?, ?, ?, ?)",
+            league.channel_id,
+            league.name,
+            league.league_id,
+            league.is_primary
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn remove_channel_league(&self, channel_id: ChannelId, name: &str) -> Result<bool> {
         let id = i64::from(channel_id);
+        let result = sqlx::query!(
+            "DELETE FROM channel_leagues WHERE channel_id = ? AND name = ?",
+            id,
+            name
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn get_guild_settings(&self, guild_id: GuildId) -> Result<DBGuildSettings> {
+        let id = i64::from(guild_id);
         let row = sqlx::query!(
-            "SELECT channel_id, default_league_id FROM channels WHERE channel_id = ?",
+            "SELECT default_league_id FROM guild_settings WHERE guild_id = ?",
             id
         )
         .fetch_optional(&self.pool)
         .await?;
+        Ok(DBGuildSettings {
+            guild_id: id,
+            default_league_id: row.and_then(|r| r.default_league_id).map(|i| i as i32),
+        })
+    }
 
-        match row {
-            Option::Some(r) => Ok(DBChannel {
-                channel_id: r.channel_id,
-                default_league_id: r.default_league_id.map(|i| i as i32),
-            }),
-            Option::None => Err(anyhow!("User not found")),
+    pub async fn update_guild_settings(&self, settings: &DBGuildSettings) -> Result<()> {
+        sqlx::query!(
+            "INSERT OR REPLACE INTO guild_settings (guild_id, default_league_id) VALUES (?, ?)",
+            settings.guild_id,
+            settings.default_league_id
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_fixture_schedule(&self) -> Result<Vec<DBFixtureSlot>> {
+        let rows = sqlx::query_as!(
+            DBFixtureSlot,
+            r#"SELECT fixture_id, team_h, team_a, event,
+                kickoff_time as "kickoff_time: DateTime<Utc>"
+            FROM fixture_schedule"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn replace_fixture_schedule(&self, slots: &[DBFixtureSlot]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query!("DELETE FROM fixture_schedule")
+            .execute(&mut *tx)
+            .await?;
+        for slot in slots {
+            sqlx::query!(
+                "INSERT INTO fixture_schedule (fixture_id, team_h, team_a, event, kickoff_time)
+                VALUES (?, ?, ?, ?, ?)",
+                slot.fixture_id,
+                slot.team_h,
+                slot.team_a,
+                slot.event,
+                slot.kickoff_time
+            )
+            .execute(&mut *tx)
+            .await?;
         }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_schedule_alert_channels(&self) -> Result<Vec<ChannelId>> {
+        let rows = sqlx::query!("SELECT channel_id FROM schedule_alert_channels")
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| ChannelId::new(r.channel_id as u64))
+            .collect())
+    }
+
+    pub async fn set_schedule_alerts(&self, channel_id: ChannelId, enabled: bool) -> Result<()> {
+        let id = i64::from(channel_id);
+        if enabled {
+            sqlx::query!(
+                "INSERT OR IGNORE INTO schedule_alert_channels (channel_id) VALUES (?)",
+                id
+            )
+            .execute(&self.pool)
+            .await?;
+        } else {
+            sqlx::query!(
+                "DELETE FROM schedule_alert_channels WHERE channel_id = ?",
+                id
+            )
+            .execute(&self.pool)
+            .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn get_admin_roles(&self, guild_id: GuildId) -> Result<Vec<RoleId>> {
+        let id = i64::from(guild_id);
+        let rows = sqlx::query!(
+            "SELECT role_id FROM guild_admin_roles WHERE guild_id = ?",
+            id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|r| RoleId::new(r.role_id as u64))
+            .collect())
+    }
+
+    pub async fn set_admin_role(
+        &self,
+        guild_id: GuildId,
+        role_id: RoleId,
+        enabled: bool,
+    ) -> Result<()> {
+        let guild = i64::from(guild_id);
+        let role = i64::from(role_id);
+        if enabled {
+            sqlx::query!(
+                "INSERT OR IGNORE INTO guild_admin_roles (guild_id, role_id) VALUES (?, ?)",
+                guild,
+                role
+            )
+            .execute(&self.pool)
+            .await?;
+        } else {
+            sqlx::query!(
+                "DELETE FROM guild_admin_roles WHERE guild_id = ? AND role_id = ?",
+                guild,
+                role
+            )
+            .execute(&self.pool)
+            .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn get_command_access(
+        &self,
+        guild_id: GuildId,
+        command: &str,
+    ) -> Result<Option<String>> {
+        let id = i64::from(guild_id);
+        let row = sqlx::query!(
+            "SELECT access FROM command_permissions WHERE guild_id = ? AND command = ?",
+            id,
+            command
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.map(|r| r.access))
+    }
+
+    pub async fn get_command_accesses(&self, guild_id: GuildId) -> Result<Vec<(String, String)>> {
+        let id = i64::from(guild_id);
+        let rows = sqlx::query!(
+            "SELECT command, access FROM command_permissions WHERE guild_id = ? ORDER BY command",
+            id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|r| (r.command, r.access)).collect())
+    }
+
+    pub async fn set_command_access(
+        &self,
+        guild_id: GuildId,
+        command: &str,
+        access: Option<&str>,
+    ) -> Result<()> {
+        let id = i64::from(guild_id);
+        match access {
+            Some(access) => {
+                sqlx::query!(
+                    "INSERT OR REPLACE INTO command_permissions (guild_id, command, access) VALUES (?, ?, ?)",
+                    id,
+                    command,
+                    access
+                )
+                .execute(&self.pool)
+                .await?;
+            }
+            None => {
+                sqlx::query!(
+                    "DELETE FROM command_permissions WHERE guild_id = ? AND command = ?",
+                    id,
+                    command
+                )
+                .execute(&self.pool)
+                .await?;
+            }
+        }
+        Ok(())
     }
 
-    pub async fn update_channel(&self, channel: &DBChannel) -> Result<()> {
+    pub async fn add_audit_entry(&self, entry: &DBAuditEntry) -> Result<()> {
         sqlx::query!(
-            "INSERT OR REPLACE INTO channels (channel_id, default_league_id) VALUES (?, ?)",
-            channel.channel_id,
-            channel.default_league_id
+            "INSERT INTO audit_log (guild_id, channel_id, user_id, command, change, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)",
+            entry.guild_id,
+            entry.channel_id,
+            entry.user_id,
+            entry.command,
+            entry.change,
+            entry.created_at
         )
         .execute(&self.pool)
         .await?;
         Ok(())
     }
+
+    pub async fn get_audit_entries(
+        &self,
+        guild_id: GuildId,
+        limit: i64,
+    ) -> Result<Vec<DBAuditEntry>> {
+        let id = i64::from(guild_id);
+        let rows = sqlx::query_as!(
+            DBAuditEntry,
+            r#"SELECT guild_id, channel_id, user_id, command, change,
+                created_at as "created_at: DateTime<Utc>"
+            FROM audit_log WHERE guild_id = ? ORDER BY id DESC LIMIT ?"#,
+            id,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn create_captain_poll(&self, poll: &DBCaptainPoll, elements: &[i32]) -> Result<i64> {
+        let mut tx = self.pool.begin().await?;
+        let id = sqlx::query!(
+            "INSERT INTO captain_polls (channel_id, season, gameweek, deadline, created_by)
+            VALUES (?, ?, ?, ?, ?)",
+            poll.channel_id,
+            poll.season,
+            poll.gameweek,
+            poll.deadline,
+            poll.created_by
+        )
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+        for element in elements {
+            sqlx::query!(
+                "INSERT INTO captain_poll_options (poll_id, element) VALUES (?, ?)",
+                id,
+                element
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(id)
+    }
+
+    pub async fn get_captain_poll(&self, id: i64) -> Result<DBCaptainPoll> {
+        let row = sqlx::query_as!(
+            DBCaptainPoll,
+            r#"SELECT id as "id!", channel_id, season, gameweek as "gameweek: i32",
+                deadline as "deadline: DateTime<Utc>", created_by, scored as "scored: bool"
+            FROM captain_polls WHERE id = ?"#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        row.ok_or_else(|| anyhow!("Captain poll {} not found", id))
+    }
+
+    pub async fn find_captain_poll(
+        &self,
+        channel_id: ChannelId,
+        season: &str,
+        gameweek: i32,
+    ) -> Result<Option<DBCaptainPoll>> {
+        let id = i64::from(channel_id);
+        let row = sqlx::query_as!(
+            DBCaptainPoll,
+            r#"SELECT id as "id!", channel_id, season, gameweek as "gameweek: i32",
+                deadline as "deadline: DateTime<Utc>", created_by, scored as "scored: bool"
+            FROM captain_polls WHERE channel_id = ? AND season = ? AND gameweek = ?"#,
+            id,
+            season,
+            gameweek
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row)
+    }
+
+    pub async fn get_unscored_captain_polls(&self) -> Result<Vec<DBCaptainPoll>> {
+        let rows = sqlx::query_as!(
+            DBCaptainPoll,
+            r#"SELECT id as "id!", channel_id, season, gameweek as "gameweek: i32",
+                deadline as "deadline: DateTime<Utc>", created_by, scored as "scored: bool"
+            FROM captain_polls WHERE scored = 0"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn get_scored_captain_polls(
+        &self,
+        channel_id: ChannelId,
+        season: &str,
+    ) -> Result<Vec<DBCaptainPoll>> {
+        let id = i64::from(channel_id);
+        let rows = sqlx::query_as!(
+            DBCaptainPoll,
+            r#"SELECT id as "id!", channel_id, season, gameweek as "gameweek: i32",
+                deadline as "deadline: DateTime<Utc>", created_by, scored as "scored: bool"
+            FROM captain_polls WHERE channel_id = ? AND season = ? AND scored = 1
+            ORDER BY gameweek"#,
+            id,
+            season
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn get_captain_options(&self, poll_id: i64) -> Result<Vec<DBCaptainOption>> {
+        let rows = sqlx::query_as!(
+            DBCaptainOption,
+            r#"SELECT poll_id, element as "element: i32", points as "points: i32"
+            FROM captain_poll_options WHERE poll_id = ? ORDER BY rowid"#,
+            poll_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn get_captain_votes(&self, poll_id: i64) -> Result<Vec<DBCaptainVote>> {
+        let rows = sqlx::query_as!(
+            DBCaptainVote,
+            r#"SELECT poll_id, user_id, element as "element: i32"
+            FROM captain_votes WHERE poll_id = ?"#,
+            poll_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn set_captain_vote(&self, vote: &DBCaptainVote) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO captain_votes (poll_id, user_id, element) VALUES (?, ?, ?)
+            ON CONFLICT(poll_id, user_id) DO UPDATE SET element = excluded.element",
+            vote.poll_id,
+            vote.user_id,
+            vote.element
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn score_captain_poll(&self, poll_id: i64, points: &[(i32, i32)]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        for (element, points) in points {
+            sqlx::query!(
+                "UPDATE captain_poll_options SET points = ? WHERE poll_id = ? AND element = ?",
+                points,
+                poll_id,
+                element
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        sqlx::query!("UPDATE captain_polls SET scored = 1 WHERE id = ?", poll_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn upsert_prediction(&self, prediction: &DBPrediction) -> Result<()> {
+        sqlx::query!(
+            "INSERT INTO predictions
+                (guild_id, season, fixture_id, user_id, home_goals, away_goals, predicted_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(guild_id, season, fixture_id, user_id) DO UPDATE SET
+                home_goals = excluded.home_goals,
+                away_goals = excluded.away_goals,
+                predicted_at = excluded.predicted_at",
+            prediction.guild_id,
+            prediction.season,
+            prediction.fixture_id,
+            prediction.user_id,
+            prediction.home_goals,
+            prediction.away_goals,
+            prediction.predicted_at
+        )
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_user_predictions(
+        &self,
+        guild_id: GuildId,
+        season: &str,
+        user_id: UserId,
+    ) -> Result<Vec<DBPrediction>> {
+        let guild = i64::from(guild_id);
+        let user = i64::from(user_id);
+        let rows = sqlx::query_as!(
+            DBPrediction,
+            r#"SELECT id as "id!", guild_id, season, fixture_id, user_id,
+                home_goals as "home_goals: i32", away_goals as "away_goals: i32",
+                predicted_at as "predicted_at: DateTime<Utc>", points as "points: i32"
+            FROM predictions WHERE guild_id = ? AND season = ? AND user_id = ?
+            ORDER BY predicted_at DESC"#,
+            guild,
+            season,
+            user
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn get_scored_predictions(
+        &self,
+        guild_id: GuildId,
+        season: &str,
+    ) -> Result<Vec<DBPrediction>> {
+        let guild = i64::from(guild_id);
+        let rows = sqlx::query_as!(
+            DBPrediction,
+            r#"SELECT id as "id!", guild_id, season, fixture_id, user_id,
+                home_goals as "home_goals: i32", away_goals as "away_goals: i32",
+                predicted_at as "predicted_at: DateTime<Utc>", points as "points: i32"
+            FROM predictions WHERE guild_id = ? AND season = ? AND points IS NOT NULL"#,
+            guild,
+            season
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn get_unscored_predictions(&self) -> Result<Vec<DBPrediction>> {
+        let rows = sqlx::query_as!(
+            DBPrediction,
+            r#"SELECT id as "id!", guild_id, season, fixture_id, user_id,
+                home_goals as "home_goals: i32", away_goals as "away_goals: i32",
+                predicted_at as "predicted_at: DateTime<Utc>", points as "points: i32"
+            FROM predictions WHERE points IS NULL"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn set_prediction_points(&self, points: &[(i64, i32)]) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        for (id, points) in points {
+            sqlx::query!("UPDATE predictions SET points = ? WHERE id = ?", points, id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_stored_stat_gameweeks(&self) -> Result<Vec<i32>> {
+        let rows = sqlx::query!(
+            r#"SELECT DISTINCT gameweek as "gameweek: i32"
+            FROM gameweek_player_stats ORDER BY gameweek"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().map(|r| r.gameweek).collect())
+    }
+
+    pub async fn get_gameweek_player_stats(&self) -> Result<Vec<DBGameweekPlayerStats>> {
+        let rows = sqlx::query_as!(
+            DBGameweekPlayerStats,
+            r#"SELECT
+                gameweek as "gameweek: i32", player_id as "player_id: i32",
+                minutes as "minutes: i32", goals_scored as "goals_scored: i32",
+                assists as "assists: i32", clean_sheets as "clean_sheets: i32",
+                goals_conceded as "goals_conceded: i32", own_goals as "own_goals: i32",
+                penalties_saved as "penalties_saved: i32",
+                penalties_missed as "penalties_missed: i32",
+                yellow_cards as "yellow_cards: i32", red_cards as "red_cards: i32",
+                saves as "saves: i32", bonus as "bonus: i32", bps as "bps: i32",
+                clearances_blocks_interceptions as "clearances_blocks_interceptions: i32",
+                recoveries as "recoveries: i32", tackles as "tackles: i32",
+                defensive_contribution as "defensive_contribution: i32",
+                starts as "starts: i32", influence, creativity, threat, ict_index,
+                expected_goals, expected_assists, expected_goal_involvements,
+                expected_goals_conceded, total_points as "total_points: i32",
+                in_dreamteam as "in_dreamteam: bool", modified as "modified: bool"
+            FROM gameweek_player_stats"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows)
+    }
+
+    pub async fn replace_gameweek_player_stats(
+        &self,
+        gameweek: i32,
+        stats: &[DBGameweekPlayerStats],
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query!(
+            "DELETE FROM gameweek_player_stats WHERE gameweek = ?",
+            gameweek
+        )
+        .execute(&mut *tx)
+        .await?;
+        for stats in stats {
+            sqlx::query!(
+                "INSERT INTO gameweek_player_stats (
+                gameweek, player_id, minutes, goals_scored, assists, clean_sheets,
+                goals_conceded, own_goals, penalties_saved, penalties_missed,
+                yellow_cards, red_cards, saves, bonus, bps,
+                clearances_blocks_interceptions, recoveries, tackles,
+                defensive_contribution, starts, influence, creativity, threat,
+                ict_index, expected_goals, expected_assists, expected_goal_involvements,
+                expected_goals_conceded, total_points, in_dreamteam, modified
+                ) VALUES (
+                    ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?,
+                    ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+                )",
+                stats.gameweek,
+                stats.player_id,
+                stats.minutes,
+                stats.goals_scored,
+                stats.assists,
+                stats.clean_sheets,
+                stats.goals_conceded,
+                stats.own_goals,
+                stats.penalties_saved,
+                stats.penalties_missed,
+                stats.yellow_cards,
+                stats.red_cards,
+                stats.saves,
+                stats.bonus,
+                stats.bps,
+                stats.clearances_blocks_interceptions,
+                stats.recoveries,
+                stats.tackles,
+                stats.defensive_contribution,
+                stats.starts,
+                stats.influence,
+                stats.creativity,
+                stats.threat,
+                stats.ict_index,
+                stats.expected_goals,
+                stats.expected_assists,
+                stats.expected_goal_involvements,
+                stats.expected_goals_conceded,
+                stats.total_points,
+                stats.in_dreamteam,
+                stats.modified
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,229 @@
+//! Season calendar built from the full fixture list
+//!
+//! Works out which teams blank (no fixture) or double (two or more fixtures) in
+//! each gameweek, keeps track of fixtures that have not been assigned to a
+//! gameweek yet, and detects when FPL moves a fixture between polls.
+
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Utc};
+use std::collections::{BTreeSet, HashMap};
+
+use crate::fpl::models::fixtures::{fetch_all_fixtures, Fixture};
+
+/// Number of gameweeks in a Premier League season.
+pub const SEASON_GAMEWEEKS: i32 = 38;
+
+/// First month of a season; earlier months belong to the previous season.
+const SEASON_START_MONTH: u32 = 7;
+
+/// The name of the season a date falls in, e.g. `2026/27` for any date from
+/// July 2026 to June 2027.
+pub fn season_name(date: DateTime<Utc>) -> String {
+    let year = if date.month() >= SEASON_START_MONTH {
+        date.year()
+    } else {
+        date.year() - 1
+    };
+    format!("{}/{:02}", year, (year + 1) % 100)
+}
+
+/// Blank and double gameweek information for a single gameweek.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameweekSummary {
+    pub gameweek: i32,
+
+    /// Teams with no fixture in this gameweek
+    pub blanks: Vec<i32>,
+
+    /// Teams with more than one fixture in this gameweek
+    pub doubles: Vec<i32>,
+}
+
+impl GameweekSummary {
+    /// Whether every team plays exactly once.
+    pub fn is_regular(&self) -> bool {
+        self.blanks.is_empty() && self.doubles.is_empty()
+    }
+}
+
+/// Where and when a fixture is scheduled, as captured by one poll.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixtureSlot {
+    pub fixture_id: u32,
+
+    pub team_h: i32,
+
+    pub team_a: i32,
+
+    pub event: Option<u8>,
+
+    pub kickoff_time: Option<DateTime<Utc>>,
+}
+
+impl From<&Fixture> for FixtureSlot {
+    fn from(fixture: &Fixture) -> Self {
+        Self {
+            fixture_id: fixture.id,
+            team_h: fixture.team_h,
+            team_a: fixture.team_a,
+            event: fixture.event,
+            kickoff_time: fixture.kickoff_time,
+        }
+    }
+}
+
+/// A fixture whose gameweek or kickoff time changed between two polls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleChange {
+    pub previous: FixtureSlot,
+
+    pub current: FixtureSlot,
+}
+
+impl ScheduleChange {
+    /// Whether the fixture moved to a different gameweek (or in/out of one).
+    pub fn gameweek_changed(&self) -> bool {
+        self.previous.event != self.current.event
+    }
+}
+
+/// The whole season's fixtures, indexed by gameweek.
+#[derive(Debug, Clone)]
+pub struct SeasonCalendar {
+    fixtures: Vec<Fixture>,
+
+    teams: BTreeSet<i32>,
+}
+
+impl SeasonCalendar {
+    /// Builds a calendar from the full fixture list.
+    pub fn new(fixtures: Vec<Fixture>) -> Self {
+        let teams = fixtures.iter().flat_map(|f| [f.team_h, f.team_a]).collect();
+        Self { fixtures, teams }
+    }
+
+    /// Fetches the full fixture list and builds a calendar from it.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use fplbot::fpl::calendar::SeasonCalendar;
+    ///
+    /// let calendar = SeasonCalendar::fetch().await?;
+    /// println!("GW29 blanks: {:?}", calendar.blanks(29));
+    /// ```
+    pub async fn fetch() -> Result<Self> {
+        Ok(Self::new(fetch_all_fixtures().await?))
+    }
+
+    /// All fixtures in the season, scheduled or not.
+    pub fn fixtures(&self) -> &[Fixture] {
+        &self.fixtures
+    }
+
+    /// IDs of every team appearing in the fixture list, ascending.
+    pub fn teams(&self) -> impl Iterator<Item = i32> + '_ {
+        self.teams.iter().copied()
+    }
+
+    /// Fixtures scheduled in a gameweek.
+    pub fn gameweek_fixtures(&self, gameweek: i32) -> impl Iterator<Item = &Fixture> {
+        self.fixtures
+            .iter()
+            .filter(move |f| f.event.map(i32::from) == Some(gameweek))
+    }
+
+    /// A team's fixtures in a gameweek; empty for a blank, two or more for a double.
+    pub fn team_fixtures(&self, team_id: i32, gameweek: i32) -> Vec<&Fixture> {
+        self.gameweek_fixtures(gameweek)
+            .filter(|f| f.team_h == team_id || f.team_a == team_id)
+            .collect()
+    }
+
+    /// Fixtures that have been postponed and not yet assigned a gameweek.
+    pub fn unscheduled(&self) -> impl Iterator<Item = &Fixture> {
+        self.fixtures.iter().filter(|f| f.event.is_none())
+    }
+
+    /// Teams with no fixture in `gameweek`.
+    pub fn blanks(&self, gameweek: i32) -> Vec<i32> {
+        let counts = self.fixture_counts(gameweek);
+        self.teams()
+            .filter(|team| !counts.contains_key(team))
+            .collect()
+    }
+
+    /// Teams with two or more fixtures in `gameweek`.
+    pub fn doubles(&self, gameweek: i32) -> Vec<i32> {
+        let counts = self.fixture_counts(gameweek);
+        self.teams()
+            .filter(|team| counts.get(team).copied().unwrap_or(0) > 1)
+            .collect()
+    }
+
+    /// Blank and double teams for a single gameweek.
+    pub fn summary(&self, gameweek: i32) -> GameweekSummary {
+        GameweekSummary {
+            gameweek,
+            blanks: self.blanks(gameweek),
+            doubles: self.doubles(gameweek),
+        }
+    }
+
+    /// Summaries for every gameweek of the season that has a blank or a double.
+    pub fn irregular_gameweeks(&self) -> Vec<GameweekSummary> {
+        (1..=SEASON_GAMEWEEKS)
+            .map(|gw| self.summary(gw))
+            .filter(|s| !s.is_regular())
+            .collect()
+    }
+
+    /// Current scheduling of every fixture, for diffing against a later poll.
+    pub fn slots(&self) -> Vec<FixtureSlot> {
+        self.fixtures.iter().map(FixtureSlot::from).collect()
+    }
+
+    fn fixture_counts(&self, gameweek: i32) -> HashMap<i32, usize> {
+        let mut counts = HashMap::new();
+        for fixture in self.gameweek_fixtures(gameweek) {
+            *counts.entry(fixture.team_h).or_insert(0) += 1;
+            *counts.entry(fixture.team_a).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+/// Compares two polls of the fixture schedule
+///
+/// Returns every fixture present in both polls whose `event` or `kickoff_time`
+/// changed. Fixtures only present in one poll are ignored, so the first poll
+/// against an empty snapshot reports nothing. Fixture IDs are reused from one
+/// season to the next, so an ID whose teams changed is a new fixture rather
+/// than a moved one.
+///
+/// # Arguments
+/// * `previous` - Slots stored from the last poll
+/// * `current` - Slots from the latest fixture list
+pub fn diff_schedules(previous: &[FixtureSlot], current: &[FixtureSlot]) -> Vec<ScheduleChange> {
+    let previous: HashMap<u32, &FixtureSlot> = previous
+        .iter()
+        .map(|slot| (slot.fixture_id, slot))
+        .collect();
+
+    current
+        .iter()
+        .filter_map(|slot| {
+            let old = previous
+                .get(&slot.fixture_id)
+                .filter(|old| old.team_h == slot.team_h && old.team_a == slot.team_a)?;
+            if old.event != slot.event || old.kickoff_time != slot.kickoff_time {
+                Some(ScheduleChange {
+                    previous: (*old).clone(),
+                    current: slot.clone(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
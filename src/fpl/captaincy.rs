@@ -0,0 +1,165 @@
+//! Captain poll candidates and scoring
+//!
+//! Picks the players a captain poll offers, ranking them by how widely they
+//! are owned and how kind their fixtures are, and turns scored polls into a
+//! season-long leaderboard of who voted for the best captain.
+
+use std::collections::HashMap;
+
+use crate::fpl::models::fixtures::Fixture;
+use crate::fpl::models::general::GeneralData;
+
+/// Players offered by a captain poll.
+pub const POLL_OPTIONS: usize = 5;
+
+/// A player who could be offered as captain
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptainCandidate {
+    pub element: i32,
+
+    /// Percentage of FPL managers who own the player
+    pub ownership: f64,
+
+    /// Difficulty (1-5) of each of the player's fixtures in the gameweek
+    pub difficulties: Vec<u8>,
+}
+
+impl CaptainCandidate {
+    /// Ownership weighted by fixture ease
+    ///
+    /// Each fixture adds `(6 - difficulty) / 3`, so an average fixture counts
+    /// once, a blank scores nothing and a double roughly doubles the score.
+    pub fn score(&self) -> f64 {
+        let ease: f64 = self
+            .difficulties
+            .iter()
+            .map(|d| f64::from(6 - i32::from(*d).clamp(1, 5)) / 3.0)
+            .sum();
+        self.ownership * ease
+    }
+}
+
+/// Builds a candidate for every available player with a fixture in the gameweek
+///
+/// # Arguments
+/// * `general` - Bootstrap data with players and their ownership
+/// * `fixtures` - The gameweek's fixtures
+pub fn gameweek_candidates(general: &GeneralData, fixtures: &[Fixture]) -> Vec<CaptainCandidate> {
+    general
+        .elements
+        .iter()
+        // available or doubtful, not injured, suspended or unavailable
+        .filter(|player| matches!(player.status.as_str(), "a" | "d"))
+        .filter_map(|player| {
+            let difficulties: Vec<u8> = fixtures
+                .iter()
+                .filter_map(|fixture| {
+                    if fixture.team_h == player.team {
+                        Some(fixture.team_h_difficulty)
+                    } else if fixture.team_a == player.team {
+                        Some(fixture.team_a_difficulty)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            if difficulties.is_empty() {
+                return None;
+            }
+            Some(CaptainCandidate {
+                element: player.id,
+                ownership: player.selected_by_percent,
+                difficulties,
+            })
+        })
+        .collect()
+}
+
+/// The best candidates by [`CaptainCandidate::score`], best first
+pub fn top_candidates(candidates: Vec<CaptainCandidate>, limit: usize) -> Vec<CaptainCandidate> {
+    let mut candidates: Vec<CaptainCandidate> =
+        candidates.into_iter().filter(|c| c.score() > 0.0).collect();
+    candidates.sort_by(|a, b| {
+        b.score()
+            .total_cmp(&a.score())
+            .then(a.element.cmp(&b.element))
+    });
+    candidates.truncate(limit);
+    candidates
+}
+
+/// A scored captain poll
+#[derive(Debug, Clone, Default)]
+pub struct PollResult {
+    /// Each option's element ID and gameweek points
+    pub options: Vec<(i32, i32)>,
+
+    /// Each voter's Discord user ID and chosen element ID
+    pub votes: Vec<(u64, i32)>,
+}
+
+impl PollResult {
+    /// The highest points scored by any option, if the poll had options.
+    pub fn best_points(&self) -> Option<i32> {
+        self.options.iter().map(|(_, points)| *points).max()
+    }
+
+    /// The points of an option, or 0 for players the poll did not offer.
+    pub fn points(&self, element: i32) -> i32 {
+        self.options
+            .iter()
+            .find(|(option, _)| *option == element)
+            .map_or(0, |(_, points)| *points)
+    }
+}
+
+/// A voter's season in captain polls
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub user_id: u64,
+
+    /// Polls voted in
+    pub polls: usize,
+
+    /// Polls where the vote went to the top scoring option, ties included
+    pub best_picks: usize,
+
+    /// Points scored by the voter's picks, before captaincy doubling
+    pub points: i32,
+}
+
+/// Ranks voters across scored polls
+///
+/// # Returns
+/// * `Vec<LeaderboardEntry>` - Voters ordered by best picks, then points, then
+///   fewest polls voted in
+pub fn leaderboard(results: &[PollResult]) -> Vec<LeaderboardEntry> {
+    let mut entries: HashMap<u64, LeaderboardEntry> = HashMap::new();
+    for result in results {
+        let best = result.best_points();
+        for (user_id, element) in &result.votes {
+            let points = result.points(*element);
+            let entry = entries.entry(*user_id).or_insert(LeaderboardEntry {
+                user_id: *user_id,
+                polls: 0,
+                best_picks: 0,
+                points: 0,
+            });
+            entry.polls += 1;
+            entry.points += points;
+            if Some(points) == best {
+                entry.best_picks += 1;
+            }
+        }
+    }
+
+    let mut entries: Vec<LeaderboardEntry> = entries.into_values().collect();
+    entries.sort_by(|a, b| {
+        b.best_picks
+            .cmp(&a.best_picks)
+            .then(b.points.cmp(&a.points))
+            .then(a.polls.cmp(&b.polls))
+            .then(a.user_id.cmp(&b.user_id))
+    });
+    entries
+}
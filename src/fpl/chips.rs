@@ -0,0 +1,185 @@
+//! Chip rules and chip returns
+//!
+//! Every chip can be played once in each half of the season. The first half
+//! ends with gameweek 19, and a first-half chip that has not been played by
+//! then is lost rather than carried over. This module works out which chips a
+//! manager has left from their history, and how many points a chip earned in
+//! the gameweek it was played.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::fpl::models::history::ChipPlay;
+use crate::fpl::models::picks::ManagerPicks;
+
+/// First gameweek of the second half of the season.
+pub const SECOND_HALF_START: i32 = 20;
+
+/// A chip a manager can play
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Chip {
+    Wildcard,
+    FreeHit,
+    BenchBoost,
+    TripleCaptain,
+}
+
+impl Chip {
+    /// Every chip, in display order.
+    pub const ALL: [Chip; 4] = [
+        Chip::Wildcard,
+        Chip::FreeHit,
+        Chip::BenchBoost,
+        Chip::TripleCaptain,
+    ];
+
+    /// Parses the chip identifier used by the FPL API, e.g. `bboost`.
+    pub fn from_api(name: &str) -> Option<Self> {
+        match name {
+            "wildcard" => Some(Chip::Wildcard),
+            "freehit" => Some(Chip::FreeHit),
+            "bboost" => Some(Chip::BenchBoost),
+            "3xc" => Some(Chip::TripleCaptain),
+            _ => None,
+        }
+    }
+
+    /// Two letter abbreviation, e.g. `BB`.
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Chip::Wildcard => "WC",
+            Chip::FreeHit => "FH",
+            Chip::BenchBoost => "BB",
+            Chip::TripleCaptain => "TC",
+        }
+    }
+}
+
+impl fmt::Display for Chip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Chip::Wildcard => "Wildcard",
+            Chip::FreeHit => "Free Hit",
+            Chip::BenchBoost => "Bench Boost",
+            Chip::TripleCaptain => "Triple Captain",
+        })
+    }
+}
+
+/// One of the two halves of the season, each with its own set of chips
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeasonHalf {
+    First,
+    Second,
+}
+
+impl SeasonHalf {
+    /// The half a gameweek belongs to.
+    pub fn of(gameweek: i32) -> Self {
+        if gameweek < SECOND_HALF_START {
+            SeasonHalf::First
+        } else {
+            SeasonHalf::Second
+        }
+    }
+}
+
+/// A chip played in a gameweek, as recorded in a manager's history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayedChip {
+    pub chip: Chip,
+
+    pub gameweek: i32,
+}
+
+impl PlayedChip {
+    /// Reads a history chip entry, skipping chips the bot does not know.
+    pub fn from_play(play: &ChipPlay) -> Option<Self> {
+        Some(Self {
+            chip: Chip::from_api(&play.name)?,
+            gameweek: play.event,
+        })
+    }
+}
+
+/// Works out which chips a manager can still play
+///
+/// A chip is remaining in a half if it has not been played in that half and
+/// the half has not finished. First-half chips are lost once the next
+/// gameweek to be played is in the second half.
+///
+/// # Arguments
+/// * `played` - The chips the manager has played this season
+/// * `next_gameweek` - The next gameweek whose deadline has not passed
+///
+/// # Returns
+/// * `Vec<(Chip, SeasonHalf)>` - Remaining chips, first half first
+pub fn remaining_chips(played: &[PlayedChip], next_gameweek: i32) -> Vec<(Chip, SeasonHalf)> {
+    [SeasonHalf::First, SeasonHalf::Second]
+        .into_iter()
+        .filter(|half| *half == SeasonHalf::Second || next_gameweek < SECOND_HALF_START)
+        .flat_map(|half| Chip::ALL.into_iter().map(move |chip| (chip, half)))
+        .filter(|(chip, half)| {
+            !played
+                .iter()
+                .any(|p| p.chip == *chip && SeasonHalf::of(p.gameweek) == *half)
+        })
+        .collect()
+}
+
+/// What a chip earned in the gameweek it was played
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChipReturn {
+    pub chip: Chip,
+
+    /// The manager's gameweek points, after transfer hits
+    pub gameweek_points: i32,
+
+    /// Points only the chip earned: the bench's points for a bench boost, or
+    /// the extra captain points for a triple captain
+    pub chip_points: Option<i32>,
+
+    /// The tripled player, for a triple captain
+    pub captain: Option<i32>,
+}
+
+/// Works out what the chip active in a gameweek's picks earned
+///
+/// # Arguments
+/// * `picks` - The manager's picks for the gameweek
+/// * `points` - Live points by element ID for the same gameweek
+///
+/// # Returns
+/// * `Option<ChipReturn>` - `None` if no known chip was active
+pub fn chip_return(picks: &ManagerPicks, points: &HashMap<i32, i32>) -> Option<ChipReturn> {
+    let chip = Chip::from_api(picks.active_chip.as_deref()?)?;
+    let element_points = |element: i32| points.get(&element).copied().unwrap_or(0);
+
+    // after automatic substitutions the vice captain may carry the multiplier
+    let tripled = picks.picks.iter().find(|p| p.multiplier >= 3);
+    let (chip_points, captain) = match chip {
+        Chip::BenchBoost => (
+            Some(
+                picks
+                    .picks
+                    .iter()
+                    .filter(|p| p.position > 11)
+                    .map(|p| element_points(p.element))
+                    .sum(),
+            ),
+            None,
+        ),
+        Chip::TripleCaptain => (
+            Some(tripled.map_or(0, |p| element_points(p.element))),
+            tripled.map(|p| p.element),
+        ),
+        Chip::Wildcard | Chip::FreeHit => (None, None),
+    };
+
+    Some(ChipReturn {
+        chip,
+        gameweek_points: picks.entry_history.points - picks.entry_history.event_transfers_cost,
+        chip_points,
+        captain,
+    })
+}
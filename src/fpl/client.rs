@@ -1,18 +1,26 @@
 use anyhow::Result;
-use log::{error, info};
-use reqwest::Client;
+use log::{error, info, warn};
+use reqwest::{Client, StatusCode};
 use serde_json::Value;
 use std::{collections::HashMap, time::Duration};
 
 use std::sync::OnceLock;
 
+use crate::config::FplConfig;
+use crate::utils::error::FplBotError;
+
 static FPL_CLIENT: OnceLock<FplApiClient> = OnceLock::new();
 
+/// Most FPL requests one command has in flight at once when fetching data for
+/// every manager in a league, so large leagues do not burst the API.
+pub const MAX_CONCURRENT_REQUESTS: usize = 8;
+
 /// Initializes the global FPL API service singleton.
 ///
 /// This function must be called once at application startup before any
-/// calls to `fpl_client()`. It creates a new `FplApiClient` instance and
-/// stores it in a global static for application-wide access.
+/// calls to `fpl_client()`. It creates a new `FplApiClient` instance with the
+/// default settings and stores it in a global static for application-wide
+/// access. Use `init_fpl_service_with()` to apply the bot's configuration.
 ///
 /// # Returns
 ///
@@ -27,7 +35,21 @@ static FPL_CLIENT: OnceLock<FplApiClient> = OnceLock::new();
 /// init_fpl_service().expect("Failed to initialize FPL service");
 /// ```
 pub fn init_fpl_service() -> Result<()> {
-    let client = FplApiClient::new();
+    init_fpl_service_with(&FplConfig::default())
+}
+
+/// Initializes the global FPL API service singleton from configuration.
+///
+/// # Arguments
+///
+/// * `config` - Base URL, timeout and retry settings for the client
+///
+/// # Returns
+///
+/// * `Ok(())` - Service initialized successfully
+/// * `Err` - Service already initialized or client creation failed
+pub fn init_fpl_service_with(config: &FplConfig) -> Result<()> {
+    let client = FplApiClient::new(config)?;
     FPL_CLIENT
         .set(client)
         .map_err(|_| anyhow::anyhow!("FPL service already initialized"))?;
@@ -65,22 +87,21 @@ pub fn fpl_client() -> &'static FplApiClient {
 #[derive(Debug)]
 pub struct FplApiClient {
     client: Client,
-    base_url: String,
+    config: FplConfig,
 }
 
 impl FplApiClient {
-    fn new() -> Self {
+    fn new(config: &FplConfig) -> Result<Self> {
         let client = Client::builder()
             .pool_idle_timeout(Duration::from_secs(300))
             .pool_max_idle_per_host(2)
-            .timeout(Duration::from_secs(5))
-            .build()
-            .expect("Failed to create HTTP client");
+            .timeout(config.timeout())
+            .build()?;
 
-        Self {
+        Ok(Self {
             client,
-            base_url: "https://fantasy.premierleague.com/api".to_string(),
-        }
+            config: config.clone(),
+        })
     }
 
     /// Returns the base URL for the Fantasy Premier League API.
@@ -89,7 +110,7 @@ impl FplApiClient {
     ///
     /// A string slice containing the FPL API base URL.
     pub fn base_url(&self) -> &str {
-        &self.base_url
+        &self.config.base_url
     }
 
     async fn _get_request(
@@ -97,24 +118,45 @@ impl FplApiClient {
         endpoint: impl Into<String>,
         params: Option<HashMap<String, String>>,
     ) -> Result<Value> {
-        let url = format!("{}/{}/", self.base_url, endpoint.into());
+        let url = format!("{}/{}/", self.config.base_url, endpoint.into());
         info!("Making GET request to {} with params {:?}", url, params);
 
-        let mut request = self.client.get(&url);
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.get(&url);
+            if let Some(params) = &params {
+                request = request.query(params);
+            }
 
-        if let Some(params) = params {
-            request = request.query(&params);
-        }
+            let error = match request.send().await {
+                Ok(response) if response.status().is_success() => {
+                    return Ok(response.json::<Value>().await?);
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    error!("HTTP Error: {} for URL {}", status, url);
+                    if status == StatusCode::NOT_FOUND {
+                        return Err(FplBotError::NotFound(url).into());
+                    }
+                    if !is_retryable(status) {
+                        return Err(anyhow::anyhow!("HTTP Error: {}", status));
+                    }
+                    anyhow::anyhow!("HTTP Error: {}", status)
+                }
+                Err(e) => anyhow::Error::from(e),
+            };
 
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            error!("HTTP Error: {} for URL {}", response.status(), url);
-            return Err(anyhow::anyhow!("HTTP Error: {}", response.status()));
+            attempt += 1;
+            if attempt > self.config.retries {
+                return Err(error);
+            }
+            let delay = self.config.retry_delay(attempt);
+            warn!(
+                "Request to {} failed ({}), retry {}/{} in {:?}",
+                url, error, attempt, self.config.retries, delay
+            );
+            tokio::time::sleep(delay).await;
         }
-
-        let json = response.json::<Value>().await?;
-        Ok(json)
     }
 
     /// Fetches general FPL data including teams, players, and gameweek information.
@@ -348,21 +390,68 @@ impl FplApiClient {
             .await
     }
 
+    /// Fetches the official team of the week for a gameweek.
+    ///
+    /// # Parameters
+    ///
+    /// * `gameweek` - The gameweek number
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Value)` - JSON response containing the dream team picks and top player
+    /// * `Err` - Network error or API error
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let dream_team = fpl_client().get_dream_team(10).await?;
+    /// ```
+    pub async fn get_dream_team(&self, gameweek: i32) -> Result<Value> {
+        self._get_request(format!("dream-team/{}", gameweek), None)
+            .await
+    }
+
     /// Fetches the current gameweek number
     pub async fn get_current_gameweek_id(&self) -> Result<i32> {
+        self.find_flagged_gameweek(&["is_current"]).await
+    }
+
+    /// Fetches the next gameweek number
+    pub async fn get_next_gameweek_id(&self) -> Result<i32> {
+        self.find_flagged_gameweek(&["is_next"]).await
+    }
+
+    /// Fetches the next gameweek number, or the current one once the last
+    /// deadline of the season has passed
+    pub async fn get_upcoming_gameweek_id(&self) -> Result<i32> {
+        self.find_flagged_gameweek(&["is_next", "is_current"]).await
+    }
+
+    /// Finds the first gameweek with a flag set, trying `flags` in order
+    async fn find_flagged_gameweek(&self, flags: &[&str]) -> Result<i32> {
         let res: Value = self._get_request("events", None).await?;
 
         let events: &Vec<Value> = res
             .as_array()
             .ok_or_else(|| anyhow::anyhow!("/events/ return expected to be array"))?;
 
-        let gw: i64 = events
+        let missing = || anyhow::anyhow!("error finding gameweek with {} set", flags.join(" or "));
+        let gw: i64 = flags
             .iter()
-            .find(|event| event["is_current"].as_bool() == Some(true))
-            .ok_or_else(|| anyhow::anyhow!("error finding current gameweek"))?["id"]
+            .find_map(|flag| {
+                events
+                    .iter()
+                    .find(|event| event[*flag].as_bool() == Some(true))
+            })
+            .ok_or_else(missing)?["id"]
             .as_i64()
-            .ok_or_else(|| anyhow::anyhow!("error finding current gameweek"))?;
+            .ok_or_else(missing)?;
 
         Ok(gw as i32)
     }
 }
+
+/// Whether a failed response is worth retrying: rate limits and server errors.
+fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
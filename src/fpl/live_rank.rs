@@ -0,0 +1,246 @@
+//! Live overall rank estimates
+//!
+//! FPL only updates a manager's overall rank once bonus points are confirmed,
+//! hours or days after their players have finished. This module estimates it
+//! while the gameweek is live.
+//!
+//! Gameweek scores across every manager are treated as normally distributed.
+//! The mean is the event's `average_entry_score`, and the spread follows from
+//! `highest_score`: among N managers the best score sits about as many
+//! standard deviations above the mean as the top 1/N of a normal
+//! distribution. Season totals before the gameweek are treated the same way,
+//! centred on the sum of the finished gameweeks' averages and spread so the
+//! manager's starting total reproduces their starting rank. The live rank is
+//! where their starting total plus live points falls among everyone's.
+
+use std::collections::HashMap;
+
+use crate::fpl::models::general::Event;
+use crate::fpl::models::picks::{EntryHistory, ManagerPicks};
+
+/// Spread of gameweek scores used when an event's highest score is missing,
+/// in points.
+pub const DEFAULT_GAMEWEEK_DEVIATION: f64 = 15.0;
+
+/// Starting ranks closer to the median than this many standard deviations
+/// say little about the spread of season totals, which then comes from the
+/// gameweeks played instead.
+const MIN_STARTING_Z: f64 = 0.25;
+
+/// Standard normal cumulative distribution function
+///
+/// Uses the Abramowitz and Stegun approximation of the error function,
+/// accurate to about 1e-7.
+pub fn normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1.0 - poly * (-x * x).exp();
+    if z >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
+/// Inverse of [`normal_cdf`] for `p` strictly between 0 and 1
+///
+/// Uses Acklam's rational approximation, accurate to about 1e-9.
+pub fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const LOW: f64 = 0.024_25;
+
+    let p = p.clamp(f64::MIN_POSITIVE, 1.0 - f64::EPSILON);
+    if p < LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p > 1.0 - LOW {
+        -normal_quantile(1.0 - p)
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+/// Mean and spread of one gameweek's scores across every manager
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreDistribution {
+    pub mean: f64,
+
+    pub deviation: f64,
+}
+
+impl ScoreDistribution {
+    /// Reads an event's score distribution among `players` managers, or
+    /// `None` before it has an average.
+    pub fn from_event(event: &Event, players: i64) -> Option<Self> {
+        if event.average_entry_score <= 0 {
+            return None;
+        }
+        let mean = f64::from(event.average_entry_score);
+        let top = normal_quantile(1.0 - 1.0 / players.max(2) as f64);
+        let deviation = match event.highest_score {
+            Some(highest) if f64::from(highest) > mean => (f64::from(highest) - mean) / top,
+            _ => DEFAULT_GAMEWEEK_DEVIATION,
+        };
+        Some(Self { mean, deviation })
+    }
+}
+
+/// A manager's season total and overall rank going into a gameweek
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartingPosition {
+    pub total: i32,
+
+    pub rank: i32,
+}
+
+impl StartingPosition {
+    /// The position after the last gameweek before `gameweek`, if the manager
+    /// had a rank then.
+    pub fn before(gameweeks: &[EntryHistory], gameweek: i32) -> Option<Self> {
+        gameweeks
+            .iter()
+            .filter(|gw| gw.event < gameweek)
+            .max_by_key(|gw| gw.event)
+            .and_then(|gw| {
+                Some(Self {
+                    total: gw.total_points,
+                    rank: gw.overall_rank?,
+                })
+            })
+    }
+}
+
+/// Estimates live overall ranks in one gameweek
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankEstimator {
+    players: i64,
+
+    /// The live gameweek's scores, unknown before it has an average
+    gameweek: Option<ScoreDistribution>,
+
+    /// Average season total before the gameweek
+    season_mean: f64,
+
+    /// Spread of season totals if every gameweek were independent, used when
+    /// a starting rank cannot pin it down
+    season_deviation: f64,
+}
+
+impl RankEstimator {
+    /// Builds the estimator for a gameweek
+    ///
+    /// # Arguments
+    /// * `events` - Every event of the season, from bootstrap-static
+    /// * `gameweek` - The live gameweek
+    /// * `players` - Managers in the game
+    pub fn new(events: &[Event], gameweek: i32, players: i64) -> Self {
+        let played: Vec<ScoreDistribution> = events
+            .iter()
+            .filter(|event| event.id < gameweek)
+            .filter_map(|event| ScoreDistribution::from_event(event, players))
+            .collect();
+        Self {
+            players: players.max(1),
+            gameweek: events
+                .iter()
+                .find(|event| event.id == gameweek)
+                .and_then(|event| ScoreDistribution::from_event(event, players)),
+            season_mean: played.iter().map(|d| d.mean).sum(),
+            season_deviation: played
+                .iter()
+                .map(|d| d.deviation.powi(2))
+                .sum::<f64>()
+                .sqrt(),
+        }
+    }
+
+    /// Estimated overall rank with `live_points` so far this gameweek, after
+    /// hits
+    ///
+    /// Without a starting position (a manager's first gameweek) the rank is
+    /// the gameweek rank. Before the gameweek has an average, the starting
+    /// rank is returned unchanged.
+    pub fn estimate(&self, start: Option<StartingPosition>, live_points: i32) -> i64 {
+        let players = self.players as f64;
+        let Some(gameweek) = self.gameweek else {
+            return start.map_or(self.players, |s| i64::from(s.rank));
+        };
+        let gained = f64::from(live_points) - gameweek.mean;
+
+        let z = match start {
+            Some(start) => {
+                let ahead = f64::from(start.total) - self.season_mean;
+                let starting_z = normal_quantile(1.0 - f64::from(start.rank) / players);
+                let implied = ahead / starting_z;
+                let season_deviation = if starting_z.abs() >= MIN_STARTING_Z && implied > 0.0 {
+                    implied
+                } else {
+                    self.season_deviation
+                };
+                let spread = (season_deviation.powi(2) + gameweek.deviation.powi(2)).sqrt();
+                if spread > 0.0 {
+                    (season_deviation * starting_z + gained) / spread
+                } else {
+                    starting_z
+                }
+            }
+            None => gained / gameweek.deviation,
+        };
+        (1.0 + (players - 1.0) * (1.0 - normal_cdf(z))).round() as i64
+    }
+}
+
+/// A manager's live gameweek points, after transfer hits
+///
+/// Each pick scores its live points times its multiplier, so benched players
+/// only count under a bench boost and the armband follows any automatic
+/// substitution FPL has already made. Substitutions still pending for
+/// players who did not play are not projected.
+///
+/// # Arguments
+/// * `picks` - The manager's picks for the gameweek
+/// * `points` - Live points by element ID for the same gameweek
+pub fn live_points(picks: &ManagerPicks, points: &HashMap<i32, i32>) -> i32 {
+    picks
+        .picks
+        .iter()
+        .map(|pick| points.get(&pick.element).copied().unwrap_or(0) * pick.multiplier)
+        .sum::<i32>()
+        - picks.entry_history.event_transfers_cost
+}
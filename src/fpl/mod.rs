@@ -1,5 +1,16 @@
 pub mod api;
+pub mod calendar;
+pub mod captaincy;
+pub mod chips;
 pub mod client;
+pub mod live_rank;
 pub mod models;
+pub mod predictions;
+pub mod projection;
+pub mod simulation;
+pub mod squad;
+pub mod squad_builder;
+pub mod standings;
+pub mod transfers;
 
 pub use client::{fpl_client};
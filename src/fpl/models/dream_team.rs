@@ -0,0 +1,51 @@
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::from_value;
+
+use crate::fpl::fpl_client;
+
+// results for endpoint dream-team/{event_id}
+
+/// The official team of the week for a gameweek.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DreamTeam {
+    pub top_player: Option<TopPlayer>,
+
+    #[serde(default)]
+    pub team: Vec<DreamTeamPick>,
+}
+
+/// The highest scoring player of the gameweek.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TopPlayer {
+    pub id: i32,
+
+    pub points: i32,
+}
+
+/// A player in the team of the week, in formation order.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DreamTeamPick {
+    pub element: i32,
+
+    pub points: i32,
+
+    pub position: i32,
+}
+
+impl DreamTeam {
+    /// Fetches the team of the week for a gameweek.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use fplbot::fpl::models::dream_team::DreamTeam;
+    ///
+    /// let dream_team = DreamTeam::fetch(10).await?;
+    /// println!("{} players", dream_team.team.len());
+    /// ```
+    pub async fn fetch(gameweek: i32) -> Result<Self> {
+        let response = fpl_client().get_dream_team(gameweek).await?;
+        Ok(from_value(response)?)
+    }
+}
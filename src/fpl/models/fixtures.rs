@@ -38,7 +38,7 @@ pub struct Fixture {
 
     pub started: bool,
 
-    pub kickoff_time: DateTime<Utc>,
+    pub kickoff_time: Option<DateTime<Utc>>,
 
     pub minutes: u16,
 
@@ -53,6 +53,23 @@ pub struct Fixture {
     pub pulse_id: u64,
 }
 
+impl Fixture {
+    /// Returns the stat block with the given identifier (e.g. `"goals_scored"`).
+    pub fn stat(&self, identifier: &str) -> Option<&FixtureStat> {
+        self.stats.iter().find(|s| s.identifier == identifier)
+    }
+
+    /// Whether the match has finished, including provisionally.
+    pub fn is_over(&self) -> bool {
+        self.finished || self.finished_provisional
+    }
+
+    /// Whether the match is currently being played.
+    pub fn is_live(&self) -> bool {
+        self.started && !self.is_over()
+    }
+}
+
 /// Represents a statistic for a fixture (e.g., goals, assists).
 #[derive(Debug, Clone, Deserialize)]
 pub struct FixtureStat {
@@ -101,3 +118,26 @@ pub async fn fetch_fixtures(gameweek: i32) -> Result<GameweekFixtures> {
         fixtures: from_value(response)?,
     })
 }
+
+/// Fetches every fixture of the season.
+///
+/// Unlike [`fetch_fixtures`], this includes fixtures that have not been assigned
+/// to a gameweek yet (postponed or unscheduled matches have `event: None`).
+///
+/// # Returns
+///
+/// * `Ok(Vec<Fixture>)` - All fixtures in the season
+/// * `Err` - Network error, API error, or JSON parsing error
+///
+/// # Examples
+///
+/// ```ignore
+/// use fplbot::fpl::models::fixtures::fetch_all_fixtures;
+///
+/// let fixtures = fetch_all_fixtures().await?;
+/// let unscheduled = fixtures.iter().filter(|f| f.event.is_none()).count();
+/// ```
+pub async fn fetch_all_fixtures() -> Result<Vec<Fixture>> {
+    let response = fpl_client().get_fixtures(None).await?;
+    Ok(from_value(response)?)
+}
@@ -0,0 +1,153 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::from_value;
+
+use crate::fpl::calendar::season_name;
+use crate::fpl::fpl_client;
+use crate::fpl::models::player::Player;
+
+// results for endpoint bootstrap-static
+
+/// The typed subset of the bootstrap-static response used by the bot.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GeneralData {
+    pub events: Vec<Event>,
+
+    pub teams: Vec<Team>,
+
+    pub elements: Vec<Player>,
+
+    pub total_players: i64,
+}
+
+/// A gameweek as listed in bootstrap-static (and `/events`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Event {
+    pub id: i32,
+
+    pub name: String,
+
+    pub deadline_time: DateTime<Utc>,
+
+    pub average_entry_score: i32,
+
+    pub highest_score: Option<i32>,
+
+    pub finished: bool,
+
+    pub data_checked: bool,
+
+    pub is_previous: bool,
+
+    pub is_current: bool,
+
+    pub is_next: bool,
+}
+
+/// A Premier League team with FPL's strength ratings.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Team {
+    pub id: i32,
+
+    pub name: String,
+
+    pub short_name: String,
+
+    pub strength: i32,
+
+    pub strength_overall_home: i32,
+
+    pub strength_overall_away: i32,
+
+    pub strength_attack_home: i32,
+
+    pub strength_attack_away: i32,
+
+    pub strength_defence_home: i32,
+
+    pub strength_defence_away: i32,
+}
+
+impl GeneralData {
+    /// Fetches and parses bootstrap-static.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use fplbot::fpl::models::general::GeneralData;
+    ///
+    /// let general = GeneralData::fetch().await?;
+    /// println!("{} players registered", general.elements.len());
+    /// ```
+    pub async fn fetch() -> Result<Self> {
+        let response = fpl_client().get_general().await?;
+        Ok(from_value(response)?)
+    }
+
+    /// Looks up a player by element ID.
+    pub fn player(&self, id: i32) -> Option<&Player> {
+        self.elements.iter().find(|p| p.id == id)
+    }
+
+    /// A player's display name, or "Unknown" if the ID is not recognised.
+    pub fn player_name(&self, id: i32) -> &str {
+        self.player(id)
+            .map(|p| p.web_name.as_str())
+            .unwrap_or("Unknown")
+    }
+
+    /// Finds players by element ID, display name or full name, ignoring case.
+    ///
+    /// Several players may share a display name, so callers should treat more
+    /// than one match as ambiguous.
+    pub fn find_players(&self, query: &str) -> Vec<&Player> {
+        let query = query.trim();
+        if let Ok(id) = query.parse::<i32>() {
+            return self.player(id).into_iter().collect();
+        }
+        let by_name: Vec<&Player> = self
+            .elements
+            .iter()
+            .filter(|p| p.web_name.eq_ignore_ascii_case(query))
+            .collect();
+        if !by_name.is_empty() {
+            return by_name;
+        }
+        self.elements
+            .iter()
+            .filter(|p| format!("{} {}", p.first_name, p.second_name).eq_ignore_ascii_case(query))
+            .collect()
+    }
+
+    /// Looks up a team by ID.
+    pub fn team(&self, id: i32) -> Option<&Team> {
+        self.teams.iter().find(|t| t.id == id)
+    }
+
+    /// Looks up a gameweek by ID.
+    pub fn event(&self, id: i32) -> Option<&Event> {
+        self.events.iter().find(|e| e.id == id)
+    }
+
+    /// The gameweek currently flagged `is_current`, if the season has started.
+    pub fn current_event(&self) -> Option<&Event> {
+        self.events.iter().find(|e| e.is_current)
+    }
+
+    /// The gameweek currently flagged `is_next`, if any remain.
+    pub fn next_event(&self) -> Option<&Event> {
+        self.events.iter().find(|e| e.is_next)
+    }
+
+    /// The season's name, e.g. `2026/27`, from the first gameweek's deadline.
+    pub fn season(&self) -> String {
+        season_name(
+            self.events
+                .iter()
+                .map(|e| e.deadline_time)
+                .min()
+                .unwrap_or_else(Utc::now),
+        )
+    }
+}
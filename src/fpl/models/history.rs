@@ -0,0 +1,101 @@
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::from_value;
+
+use crate::fpl::fpl_client;
+use crate::fpl::models::picks::EntryHistory;
+use crate::utils::cache::TtlCache;
+
+// results for endpoint entry/{manager_id}/history
+
+/// How long a fetched history is reused before being fetched again.
+const CACHE_TTL: Duration = Duration::from_secs(600);
+
+/// Histories kept in the cache before expired ones are dropped.
+const MAX_CACHED_HISTORIES: usize = 2000;
+
+static HISTORY_CACHE: OnceLock<TtlCache<i32, Arc<ManagerHistory>>> = OnceLock::new();
+
+/// A manager's gameweek-by-gameweek record for the season, past seasons and
+/// chips played.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ManagerHistory {
+    /// This season's gameweeks, oldest first
+    pub current: Vec<EntryHistory>,
+
+    #[serde(default)]
+    pub past: Vec<SeasonHistory>,
+
+    #[serde(default)]
+    pub chips: Vec<ChipPlay>,
+}
+
+/// A manager's final result in a previous season.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SeasonHistory {
+    pub season_name: String,
+
+    pub total_points: i32,
+
+    pub rank: Option<i32>,
+}
+
+/// A chip played this season.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChipPlay {
+    /// Chip identifier, e.g. `wildcard`, `bboost`, `3xc` or `freehit`
+    pub name: String,
+
+    pub time: DateTime<Utc>,
+
+    /// The gameweek the chip was played in
+    pub event: i32,
+}
+
+impl ManagerHistory {
+    /// Fetches a manager's history.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use fplbot::fpl::models::history::ManagerHistory;
+    ///
+    /// let history = ManagerHistory::fetch(123456).await?;
+    /// println!("Played {} gameweeks", history.current.len());
+    /// ```
+    pub async fn fetch(manager_id: i32) -> Result<Self> {
+        let response = fpl_client().get_manager_history(manager_id).await?;
+        Ok(from_value(response)?)
+    }
+
+    /// Fetches a manager's history, reusing one fetched in the last few
+    /// minutes.
+    pub async fn fetch_cached(manager_id: i32) -> Result<Arc<Self>> {
+        let cache = HISTORY_CACHE.get_or_init(|| TtlCache::new(CACHE_TTL, MAX_CACHED_HISTORIES));
+        if let Some(history) = cache.get(&manager_id) {
+            return Ok(history);
+        }
+        let history = Arc::new(Self::fetch(manager_id).await?);
+        cache.insert(manager_id, history.clone());
+        Ok(history)
+    }
+
+    /// The most recent gameweek played, if any.
+    pub fn latest(&self) -> Option<&EntryHistory> {
+        self.current.last()
+    }
+
+    /// Points scored over the last `gameweeks` gameweeks, after transfer hits.
+    pub fn form(&self, gameweeks: usize) -> i32 {
+        self.current
+            .iter()
+            .rev()
+            .take(gameweeks)
+            .map(|gw| gw.points - gw.event_transfers_cost)
+            .sum()
+    }
+}
@@ -1,7 +1,9 @@
+use anyhow::Result;
 use serde::Deserialize;
 use serde::Deserializer;
 
 use crate::fpl::fpl_client;
+use crate::utils::deserializers::de_null_as_default;
 
 /// Represents an FPL manager with their team and performance data.
 #[derive(Debug, Deserialize)]
@@ -20,10 +22,17 @@ pub struct Manager {
     #[serde(deserialize_with = "deserialize_classic_leagues")]
     leagues: Vec<LeagueInfo>,
 
-    #[serde(rename = "summary_overall_points")]
+    // null until the manager's first gameweek has been played
+    #[serde(
+        rename = "summary_overall_points",
+        deserialize_with = "de_null_as_default"
+    )]
     pub total_points: i32,
 
-    #[serde(rename = "summary_event_points")]
+    #[serde(
+        rename = "summary_event_points",
+        deserialize_with = "de_null_as_default"
+    )]
     pub gw_points: i32,
 }
 
@@ -61,6 +70,48 @@ impl Manager {
         }
     }
 
+    /// Fetches a manager's summary from the FPL API.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - The FPL manager ID
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Manager)` - The manager's team, name and points
+    /// * `Err` - Network error, API error (404 if the ID does not exist), or
+    ///   JSON parsing error
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let manager = Manager::fetch(123456).await?;
+    /// println!("{} ({})", manager.team_name, manager.full_name());
+    /// ```
+    pub async fn fetch(id: i32) -> Result<Self> {
+        let response = fpl_client().get_manager_summary(id).await?;
+        Ok(serde_json::from_value(response)?)
+    }
+
+    /// The manager's first and last name.
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+
+    /// The manager's rank in one of their classic leagues
+    ///
+    /// # Returns
+    ///
+    /// * `None` - The manager is not in the league
+    /// * `Some(None)` - The manager is in the league but not ranked yet
+    /// * `Some(Some(rank))` - The manager's current rank
+    pub fn league_rank(&self, league_id: i32) -> Option<Option<i32>> {
+        self.leagues
+            .iter()
+            .find(|league| league.id == league_id)
+            .map(|league| league.entry_rank)
+    }
+
     /// Refreshes the manager's data from the FPL API.
     ///
     /// This method fetches the latest information about the manager
@@ -107,7 +158,7 @@ struct LeagueInfo {
 
     closed: bool,
 
-    admin_entry: i32,
+    admin_entry: Option<i32>,
 
     start_event: i32,
 
@@ -117,13 +168,13 @@ struct LeagueInfo {
 
     entry_can_invite: bool,
 
-    rank_count: i32,
+    rank_count: Option<i32>,
 
-    entry_percentile_rank: i32,
+    entry_percentile_rank: Option<i32>,
 
-    entry_rank: i32,
+    entry_rank: Option<i32>,
 
-    entry_last_rank: i32,
+    entry_last_rank: Option<i32>,
 }
 
 /// Custom deserializer for classic leagues data.
@@ -1,6 +1,11 @@
+pub mod dream_team;
 pub mod fixtures;
 pub mod gameweek;
+pub mod history;
+pub mod general;
 pub mod league;
 pub mod manager;
+pub mod picks;
 pub mod player;
 pub mod teams;
+pub mod transfers;
@@ -0,0 +1,121 @@
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::from_value;
+
+use crate::fpl::fpl_client;
+
+// results for endpoint entry/{manager_id}/event/{event_id}/picks
+
+/// A manager's squad selection for one gameweek.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ManagerPicks {
+    pub active_chip: Option<String>,
+
+    #[serde(default)]
+    pub automatic_subs: Vec<AutomaticSub>,
+
+    pub entry_history: EntryHistory,
+
+    pub picks: Vec<Pick>,
+}
+
+/// One of the fifteen players in a squad.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Pick {
+    pub element: i32,
+
+    /// Slot in the squad: 1-11 start, 12-15 are the ordered bench
+    pub position: i32,
+
+    pub multiplier: i32,
+
+    pub is_captain: bool,
+
+    pub is_vice_captain: bool,
+}
+
+/// The manager's gameweek summary returned alongside their picks.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EntryHistory {
+    pub event: i32,
+
+    pub points: i32,
+
+    pub total_points: i32,
+
+    pub rank: Option<i32>,
+
+    pub overall_rank: Option<i32>,
+
+    pub bank: i32,
+
+    pub value: i32,
+
+    pub event_transfers: i32,
+
+    pub event_transfers_cost: i32,
+
+    pub points_on_bench: i32,
+}
+
+/// An automatic substitution made after the gameweek.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AutomaticSub {
+    pub element_in: i32,
+
+    pub element_out: i32,
+
+    pub event: i32,
+}
+
+impl ManagerPicks {
+    /// Fetches a manager's picks for a gameweek.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use fplbot::fpl::models::picks::ManagerPicks;
+    ///
+    /// let picks = ManagerPicks::fetch(123456, 10).await?;
+    /// println!("Chip: {:?}", picks.active_chip);
+    /// ```
+    pub async fn fetch(manager_id: i32, gameweek: i32) -> Result<Self> {
+        let response = fpl_client().get_manager_team(manager_id, gameweek).await?;
+        Ok(from_value(response)?)
+    }
+
+    /// Fetches the squad a manager takes into the next gameweek.
+    ///
+    /// A free hit squad only lasts its gameweek, so after one the squad from
+    /// the gameweek before is returned instead, with the current bank.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use fplbot::fpl::models::picks::ManagerPicks;
+    ///
+    /// let picks = ManagerPicks::fetch_upcoming(123456, 10).await?;
+    /// println!("{} in the bank", picks.entry_history.bank);
+    /// ```
+    pub async fn fetch_upcoming(manager_id: i32, current_gameweek: i32) -> Result<Self> {
+        let picks = Self::fetch(manager_id, current_gameweek).await?;
+        if picks.active_chip.as_deref() == Some("freehit") && current_gameweek > 1 {
+            let mut reverted = Self::fetch(manager_id, current_gameweek - 1).await?;
+            reverted.entry_history.bank = picks.entry_history.bank;
+            return Ok(reverted);
+        }
+        Ok(picks)
+    }
+
+    /// Whether the player was one of the eleven starters.
+    pub fn is_starter(&self, element: i32) -> bool {
+        self.picks
+            .iter()
+            .any(|p| p.element == element && p.position <= 11)
+    }
+
+    /// The captain's element ID, if one was picked.
+    pub fn captain(&self) -> Option<i32> {
+        self.picks.iter().find(|p| p.is_captain).map(|p| p.element)
+    }
+}
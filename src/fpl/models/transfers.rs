@@ -0,0 +1,44 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::from_value;
+
+use crate::fpl::fpl_client;
+
+// results for endpoint entry/{manager_id}/transfers
+
+/// A transfer made by a manager, with the prices paid and received.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ManagerTransfer {
+    pub element_in: i32,
+
+    /// Price paid for the player bought, in tenths of a million
+    pub element_in_cost: i32,
+
+    pub element_out: i32,
+
+    /// Price received for the player sold, in tenths of a million
+    pub element_out_cost: i32,
+
+    pub entry: i32,
+
+    /// The gameweek the transfer counted towards
+    pub event: i32,
+
+    pub time: DateTime<Utc>,
+}
+
+/// Fetches every transfer a manager has made this season, newest first.
+///
+/// # Examples
+///
+/// ```ignore
+/// use fplbot::fpl::models::transfers::fetch_transfers;
+///
+/// let transfers = fetch_transfers(123456).await?;
+/// println!("{} transfers made", transfers.len());
+/// ```
+pub async fn fetch_transfers(manager_id: i32) -> Result<Vec<ManagerTransfer>> {
+    let response = fpl_client().get_manager_transfers(manager_id).await?;
+    Ok(from_value(response)?)
+}
@@ -0,0 +1,89 @@
+//! Fixture prediction scoring
+//!
+//! Members predict the score of upcoming fixtures. Once a fixture is finished
+//! each prediction earns points for the exact score or, failing that, the
+//! correct result, and the points add up to a season leaderboard.
+
+use std::collections::HashMap;
+
+/// Points for predicting the exact score.
+pub const EXACT_SCORE_POINTS: i32 = 3;
+
+/// Points for predicting the winner, or a draw, with the wrong score.
+pub const CORRECT_RESULT_POINTS: i32 = 1;
+
+/// Most goals accepted for one team in a prediction.
+pub const MAX_PREDICTED_GOALS: u8 = 20;
+
+/// Scores a prediction against the final score
+///
+/// # Arguments
+/// * `predicted` - Predicted home and away goals
+/// * `actual` - Final home and away goals
+///
+/// # Returns
+/// * `i32` - [`EXACT_SCORE_POINTS`], [`CORRECT_RESULT_POINTS`] or 0
+pub fn score_prediction(predicted: (u8, u8), actual: (u8, u8)) -> i32 {
+    if predicted == actual {
+        EXACT_SCORE_POINTS
+    } else if predicted.0.cmp(&predicted.1) == actual.0.cmp(&actual.1) {
+        CORRECT_RESULT_POINTS
+    } else {
+        0
+    }
+}
+
+/// A member's season in the prediction game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PredictionStanding {
+    pub user_id: u64,
+
+    pub points: i32,
+
+    /// Scored predictions
+    pub predictions: usize,
+
+    /// Predictions with the exact score
+    pub exact_scores: usize,
+
+    /// Predictions with the correct result but not the exact score
+    pub correct_results: usize,
+}
+
+/// Ranks members by their scored predictions
+///
+/// # Arguments
+/// * `scored` - Each scored prediction's Discord user ID and points
+///
+/// # Returns
+/// * `Vec<PredictionStanding>` - Members ordered by points, then exact scores,
+///   then fewest predictions
+pub fn standings(scored: &[(u64, i32)]) -> Vec<PredictionStanding> {
+    let mut standings: HashMap<u64, PredictionStanding> = HashMap::new();
+    for (user_id, points) in scored {
+        let standing = standings.entry(*user_id).or_insert(PredictionStanding {
+            user_id: *user_id,
+            points: 0,
+            predictions: 0,
+            exact_scores: 0,
+            correct_results: 0,
+        });
+        standing.points += points;
+        standing.predictions += 1;
+        match *points {
+            EXACT_SCORE_POINTS => standing.exact_scores += 1,
+            CORRECT_RESULT_POINTS => standing.correct_results += 1,
+            _ => {}
+        }
+    }
+
+    let mut standings: Vec<PredictionStanding> = standings.into_values().collect();
+    standings.sort_by(|a, b| {
+        b.points
+            .cmp(&a.points)
+            .then(b.exact_scores.cmp(&a.exact_scores))
+            .then(a.predictions.cmp(&b.predictions))
+            .then(a.user_id.cmp(&b.user_id))
+    });
+    standings
+}
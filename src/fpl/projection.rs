@@ -0,0 +1,535 @@
+//! Expected points projection
+//!
+//! A transparent alternative to FPL's own `ep_next`. Each player's projected
+//! points in a fixture are built from a handful of visible parts: how likely
+//! they are to play and to last 60 minutes, their per-90 expected goals and
+//! assists scaled by the opponent, the chance of a clean sheet from the two
+//! teams' strengths and the fixture difficulty, and FPL's scoring rules for
+//! their position. A gameweek's projection sums the player's fixtures, so
+//! doubles count twice and blanks project nothing.
+//!
+//! [`backtest`] replays the model over stored gameweek stats, projecting each
+//! gameweek from the gameweeks before it and comparing with what was scored.
+
+use std::collections::{BTreeSet, HashMap};
+use std::ops::RangeInclusive;
+
+use crate::fpl::models::fixtures::Fixture;
+use crate::fpl::models::general::{GeneralData, Team};
+use crate::fpl::models::player::Player;
+use crate::fpl::squad::Position;
+
+/// Goals an average Premier League team scores per match.
+pub const LEAGUE_GOALS_PER_TEAM: f64 = 1.4;
+
+/// Change in expected goals against for each step of fixture difficulty away
+/// from an average fixture (3).
+const DIFFICULTY_STEP: f64 = 0.1;
+
+/// Average minutes played by a starter.
+const START_MINUTES: f64 = 85.0;
+
+/// Average minutes played by a substitute.
+const SUB_MINUTES: f64 = 20.0;
+
+/// Points for an assist, in every position.
+pub const ASSIST_POINTS: i32 = 3;
+
+/// Goals conceded for each point lost by goalkeepers and defenders.
+pub const GOALS_PER_CONCEDED_POINT: u32 = 2;
+
+/// Saves for each point earned by goalkeepers.
+pub const SAVES_PER_POINT: f64 = 3.0;
+
+/// Most goals conceded considered when working out the expected penalty.
+const MAX_CONCEDED: u32 = 12;
+
+/// Points for scoring a goal
+pub fn goal_points(position: Position) -> i32 {
+    match position {
+        Position::Goalkeeper => 10,
+        Position::Defender => 6,
+        Position::Midfielder => 5,
+        Position::Forward => 4,
+    }
+}
+
+/// Points for a clean sheet after playing at least 60 minutes
+pub fn clean_sheet_points(position: Position) -> i32 {
+    match position {
+        Position::Goalkeeper | Position::Defender => 4,
+        Position::Midfielder => 1,
+        Position::Forward => 0,
+    }
+}
+
+/// Points lost for every [`GOALS_PER_CONCEDED_POINT`] goals conceded
+pub fn conceded_penalty(position: Position) -> i32 {
+    match position {
+        Position::Goalkeeper | Position::Defender => 1,
+        Position::Midfielder | Position::Forward => 0,
+    }
+}
+
+/// How much a player is expected to play in a fixture
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinutesOutlook {
+    /// Probability of coming on at all
+    pub appearance: f64,
+
+    /// Probability of playing at least 60 minutes
+    pub sixty: f64,
+
+    /// Expected minutes played
+    pub expected_minutes: f64,
+}
+
+impl MinutesOutlook {
+    /// Estimates playing time from the player's season so far
+    ///
+    /// Starts are taken to last 60 minutes or more. Minutes beyond what the
+    /// starts account for are put down to substitute appearances.
+    ///
+    /// # Arguments
+    /// * `minutes` - Minutes played this season
+    /// * `starts` - Matches started this season
+    /// * `games` - Gameweeks played this season
+    /// * `availability` - Probability (0-1) the player is fit and eligible
+    pub fn from_history(minutes: i32, starts: i32, games: i32, availability: f64) -> Self {
+        if games <= 0 {
+            return MinutesOutlook {
+                appearance: 0.0,
+                sixty: 0.0,
+                expected_minutes: 0.0,
+            };
+        }
+        let games = f64::from(games);
+        let availability = availability.clamp(0.0, 1.0);
+        let start_rate = (f64::from(starts) / games).clamp(0.0, 1.0);
+        let minutes_per_game = (f64::from(minutes) / games).clamp(0.0, 90.0);
+        let sub_minutes = (minutes_per_game - start_rate * START_MINUTES).max(0.0);
+        let sub_rate = (sub_minutes / SUB_MINUTES).min(1.0 - start_rate);
+
+        MinutesOutlook {
+            appearance: availability * (start_rate + sub_rate),
+            sixty: availability * start_rate,
+            expected_minutes: availability * minutes_per_game,
+        }
+    }
+}
+
+/// Probability (0-1) that a player is fit and eligible for the next round
+///
+/// Uses FPL's `chance_of_playing_next_round` where it is set. Otherwise
+/// available players are certain, doubtful players are a coin flip and
+/// injured, suspended or unavailable players will not play.
+pub fn availability(player: &Player) -> f64 {
+    if let Some(chance) = player.chance_of_playing_next_round {
+        return f64::from(chance.clamp(0, 100)) / 100.0;
+    }
+    match player.status.as_str() {
+        "a" => 1.0,
+        "d" => 0.5,
+        _ => 0.0,
+    }
+}
+
+/// How a fixture looks from one team's side
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixtureOutlook {
+    /// Multiplier on the team's usual attacking output, 1 for an average
+    /// opponent
+    pub attack: f64,
+
+    /// Goals the team is expected to concede
+    pub goals_against: f64,
+}
+
+impl FixtureOutlook {
+    /// Rates a fixture from FPL's team strengths and difficulty
+    ///
+    /// # Arguments
+    /// * `team` - The team being projected
+    /// * `opponent` - The team they face
+    /// * `home` - Whether `team` plays at home
+    /// * `difficulty` - FPL's difficulty (1-5) of the fixture for `team`
+    pub fn new(team: &Team, opponent: &Team, home: bool, difficulty: u8) -> Self {
+        let (attack, defence, opponent_attack, opponent_defence) = if home {
+            (
+                team.strength_attack_home,
+                team.strength_defence_home,
+                opponent.strength_attack_away,
+                opponent.strength_defence_away,
+            )
+        } else {
+            (
+                team.strength_attack_away,
+                team.strength_defence_away,
+                opponent.strength_attack_home,
+                opponent.strength_defence_home,
+            )
+        };
+        let difficulty = f64::from(i32::from(difficulty).clamp(1, 5) - 3) * DIFFICULTY_STEP;
+
+        FixtureOutlook {
+            attack: strength_ratio(attack, opponent_defence) * (1.0 - difficulty),
+            goals_against: LEAGUE_GOALS_PER_TEAM
+                * strength_ratio(opponent_attack, defence)
+                * (1.0 + difficulty),
+        }
+    }
+
+    /// Probability of conceding nothing, with goals conceded following a
+    /// Poisson distribution
+    pub fn clean_sheet_probability(&self) -> f64 {
+        (-self.goals_against).exp()
+    }
+
+    /// Expected number of [`GOALS_PER_CONCEDED_POINT`] blocks conceded
+    pub fn expected_conceded_blocks(&self) -> f64 {
+        let mut probability = self.clean_sheet_probability();
+        let mut expected = 0.0;
+        for goals in 1..=MAX_CONCEDED {
+            probability *= self.goals_against / f64::from(goals);
+            expected += probability * f64::from(goals / GOALS_PER_CONCEDED_POINT);
+        }
+        expected
+    }
+}
+
+/// Ratio of two FPL strengths, treating missing ratings as average
+fn strength_ratio(numerator: i32, denominator: i32) -> f64 {
+    if numerator <= 0 || denominator <= 0 {
+        return 1.0;
+    }
+    f64::from(numerator) / f64::from(denominator)
+}
+
+/// Everything about a player the projection needs, independent of fixtures
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayerOutlook {
+    pub position: Position,
+
+    pub expected_goals_per_90: f64,
+
+    pub expected_assists_per_90: f64,
+
+    pub saves_per_90: f64,
+
+    pub minutes: MinutesOutlook,
+}
+
+impl PlayerOutlook {
+    /// Builds the outlook from bootstrap data
+    ///
+    /// # Arguments
+    /// * `player` - The player
+    /// * `games` - Gameweeks played so far this season
+    ///
+    /// # Returns
+    /// * `Option<PlayerOutlook>` - `None` if the player's position is unknown
+    pub fn from_player(player: &Player, games: i32) -> Option<Self> {
+        Some(PlayerOutlook {
+            position: Position::from_element_type(player.element_type)?,
+            expected_goals_per_90: player.expected_goals_per_90,
+            expected_assists_per_90: player.expected_assists_per_90,
+            saves_per_90: player.saves_per_90,
+            minutes: MinutesOutlook::from_history(
+                player.minutes,
+                player.starts,
+                games,
+                availability(player),
+            ),
+        })
+    }
+
+    /// Projected points in one fixture
+    ///
+    /// Appearance points come from the minutes outlook. Goals and assists are
+    /// the per-90 rates over the expected minutes, scaled by the fixture's
+    /// attack multiplier. Clean sheets and goals conceded only count when the
+    /// player lasts 60 minutes, and saves only for goalkeepers.
+    pub fn fixture_points(&self, fixture: &FixtureOutlook) -> f64 {
+        let position = self.position;
+        let minutes = self.minutes;
+        let share = minutes.expected_minutes / 90.0;
+
+        let appearance = minutes.appearance + minutes.sixty;
+        let goals = self.expected_goals_per_90 * share * fixture.attack;
+        let assists = self.expected_assists_per_90 * share * fixture.attack;
+        let clean_sheet = minutes.sixty * fixture.clean_sheet_probability();
+        let conceded = minutes.sixty * fixture.expected_conceded_blocks();
+        let saves = if position == Position::Goalkeeper {
+            self.saves_per_90 * share / SAVES_PER_POINT
+        } else {
+            0.0
+        };
+
+        appearance
+            + goals * f64::from(goal_points(position))
+            + assists * f64::from(ASSIST_POINTS)
+            + clean_sheet * f64::from(clean_sheet_points(position))
+            - conceded * f64::from(conceded_penalty(position))
+            + saves
+    }
+
+    /// Projected points in a gameweek, summed over the team's fixtures
+    ///
+    /// # Arguments
+    /// * `team` - The player's team ID
+    /// * `teams` - Every team, for strengths
+    /// * `fixtures` - Fixtures to search, usually the whole season
+    /// * `gameweek` - The gameweek to project
+    pub fn gameweek_points(
+        &self,
+        team: i32,
+        teams: &HashMap<i32, &Team>,
+        fixtures: &[Fixture],
+        gameweek: i32,
+    ) -> f64 {
+        fixtures
+            .iter()
+            .filter(|fixture| fixture.event.map(i32::from) == Some(gameweek))
+            .filter_map(|fixture| {
+                let (opponent, home, difficulty) = if fixture.team_h == team {
+                    (fixture.team_a, true, fixture.team_h_difficulty)
+                } else if fixture.team_a == team {
+                    (fixture.team_h, false, fixture.team_a_difficulty)
+                } else {
+                    return None;
+                };
+                let outlook =
+                    FixtureOutlook::new(teams.get(&team)?, teams.get(&opponent)?, home, difficulty);
+                Some(self.fixture_points(&outlook))
+            })
+            .sum()
+    }
+}
+
+/// A player's projected points in each of a run of gameweeks
+#[derive(Debug, Clone, PartialEq)]
+pub struct Projection {
+    pub element: i32,
+
+    /// Each gameweek and its projected points, in gameweek order
+    pub gameweeks: Vec<(i32, f64)>,
+}
+
+impl Projection {
+    /// Projected points across every gameweek.
+    pub fn total(&self) -> f64 {
+        self.gameweeks.iter().map(|(_, points)| points).sum()
+    }
+
+    /// Projected points in one gameweek, or 0 outside the projected run.
+    pub fn points(&self, gameweek: i32) -> f64 {
+        self.gameweeks
+            .iter()
+            .find(|(gw, _)| *gw == gameweek)
+            .map_or(0.0, |(_, points)| *points)
+    }
+}
+
+/// Projects every player over a run of upcoming gameweeks
+///
+/// # Arguments
+/// * `general` - Bootstrap data with players, teams and gameweeks
+/// * `fixtures` - The season's fixtures
+/// * `gameweeks` - Gameweeks to project
+///
+/// # Returns
+/// * `Vec<Projection>` - One projection per player with a known position, in
+///   bootstrap order
+pub fn project(
+    general: &GeneralData,
+    fixtures: &[Fixture],
+    gameweeks: RangeInclusive<i32>,
+) -> Vec<Projection> {
+    let games = general.events.iter().filter(|event| event.finished).count() as i32;
+    let teams: HashMap<i32, &Team> = general.teams.iter().map(|team| (team.id, team)).collect();
+
+    general
+        .elements
+        .iter()
+        .filter_map(|player| {
+            let outlook = PlayerOutlook::from_player(player, games)?;
+            Some(Projection {
+                element: player.id,
+                gameweeks: gameweeks
+                    .clone()
+                    .map(|gw| {
+                        (
+                            gw,
+                            outlook.gameweek_points(player.team, &teams, fixtures, gw),
+                        )
+                    })
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// A player's stored stats for one gameweek, as used by [`backtest`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatLine {
+    pub gameweek: i32,
+
+    pub element: i32,
+
+    pub minutes: i32,
+
+    pub starts: i32,
+
+    pub expected_goals: f64,
+
+    pub expected_assists: f64,
+
+    pub saves: i32,
+
+    pub total_points: i32,
+}
+
+/// A player's position and team, as used by [`backtest`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayerRole {
+    pub element: i32,
+
+    pub position: Position,
+
+    pub team: i32,
+}
+
+/// How well projections matched the points actually scored
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BacktestReport {
+    /// Gameweeks projected
+    pub gameweeks: usize,
+
+    /// Player gameweeks compared
+    pub samples: usize,
+
+    /// Mean absolute difference between projected and actual points
+    pub mean_absolute_error: f64,
+
+    /// Mean of projected minus actual points; positive means the model is
+    /// too generous
+    pub bias: f64,
+
+    /// Pearson correlation between projected and actual points
+    pub correlation: f64,
+
+    /// Mean absolute error of simply projecting each player's points per
+    /// gameweek so far, for comparison
+    pub baseline_error: f64,
+}
+
+/// Replays the projection over stored gameweek stats
+///
+/// Each gameweek after the first stored one is projected from the stored
+/// gameweeks before it, with every player assumed available. Players are
+/// compared once they have played this season.
+///
+/// # Arguments
+/// * `lines` - Stored stats, any order
+/// * `roles` - Every player's position and team
+/// * `teams` - Every team, for strengths
+/// * `fixtures` - The season's fixtures
+///
+/// # Returns
+/// * `BacktestReport` - Accuracy across every projected gameweek, all zero if
+///   fewer than two gameweeks are stored
+pub fn backtest(
+    lines: &[StatLine],
+    roles: &[PlayerRole],
+    teams: &[Team],
+    fixtures: &[Fixture],
+) -> BacktestReport {
+    let teams: HashMap<i32, &Team> = teams.iter().map(|team| (team.id, team)).collect();
+    let roles: HashMap<i32, &PlayerRole> = roles.iter().map(|role| (role.element, role)).collect();
+    let stored: BTreeSet<i32> = lines.iter().map(|line| line.gameweek).collect();
+
+    let mut totals: HashMap<i32, StatLine> = HashMap::new();
+    let mut games = 0;
+    let mut pairs: Vec<(f64, f64, f64)> = Vec::new();
+    let mut gameweeks = 0;
+    for gameweek in stored {
+        let actual: Vec<&StatLine> = lines.iter().filter(|l| l.gameweek == gameweek).collect();
+        if games > 0 {
+            gameweeks += 1;
+            for line in &actual {
+                let (Some(total), Some(role)) =
+                    (totals.get(&line.element), roles.get(&line.element))
+                else {
+                    continue;
+                };
+                if total.minutes <= 0 {
+                    continue;
+                }
+                let per_90 = |value: f64| value * 90.0 / f64::from(total.minutes);
+                let outlook = PlayerOutlook {
+                    position: role.position,
+                    expected_goals_per_90: per_90(total.expected_goals),
+                    expected_assists_per_90: per_90(total.expected_assists),
+                    saves_per_90: per_90(f64::from(total.saves)),
+                    minutes: MinutesOutlook::from_history(total.minutes, total.starts, games, 1.0),
+                };
+                pairs.push((
+                    outlook.gameweek_points(role.team, &teams, fixtures, gameweek),
+                    f64::from(line.total_points),
+                    f64::from(total.total_points) / f64::from(games),
+                ));
+            }
+        }
+
+        for line in actual {
+            let total = totals.entry(line.element).or_insert(StatLine {
+                gameweek,
+                element: line.element,
+                minutes: 0,
+                starts: 0,
+                expected_goals: 0.0,
+                expected_assists: 0.0,
+                saves: 0,
+                total_points: 0,
+            });
+            total.minutes += line.minutes;
+            total.starts += line.starts;
+            total.expected_goals += line.expected_goals;
+            total.expected_assists += line.expected_assists;
+            total.saves += line.saves;
+            total.total_points += line.total_points;
+        }
+        games += 1;
+    }
+
+    if pairs.is_empty() {
+        return BacktestReport {
+            gameweeks,
+            ..BacktestReport::default()
+        };
+    }
+    let n = pairs.len() as f64;
+    let mean = |f: fn(&(f64, f64, f64)) -> f64| pairs.iter().map(f).sum::<f64>() / n;
+    let projected_mean = mean(|p| p.0);
+    let actual_mean = mean(|p| p.1);
+    let (mut covariance, mut projected_var, mut actual_var) = (0.0, 0.0, 0.0);
+    for (projected, actual, _) in &pairs {
+        covariance += (projected - projected_mean) * (actual - actual_mean);
+        projected_var += (projected - projected_mean).powi(2);
+        actual_var += (actual - actual_mean).powi(2);
+    }
+    let correlation = if projected_var > 0.0 && actual_var > 0.0 {
+        covariance / (projected_var * actual_var).sqrt()
+    } else {
+        0.0
+    };
+
+    BacktestReport {
+        gameweeks,
+        samples: pairs.len(),
+        mean_absolute_error: mean(|p| (p.0 - p.1).abs()),
+        bias: projected_mean - actual_mean,
+        correlation,
+        baseline_error: mean(|p| (p.2 - p.1).abs()),
+    }
+}
@@ -0,0 +1,238 @@
+//! Season-end simulation of mini-league outcomes
+//!
+//! Plays out the remaining gameweeks many times to estimate each manager's
+//! chance of winning their league, finishing in the top three or coming
+//! last. A manager's gameweek score is drawn from a normal distribution: its
+//! mean starts from their current squad's projection and drifts towards their
+//! season average further out, as squads change, and its spread is the
+//! variation of their own gameweek scores so far.
+//!
+//! Draws come from a small seeded generator, so a seed always gives the same
+//! odds, and the number of runs is capped both by the work involved and by a
+//! time budget.
+
+use std::time::{Duration, Instant};
+
+use crate::fpl::models::picks::EntryHistory;
+
+/// How much of the squad projection is kept for each gameweek further out;
+/// the rest of the mean comes from the manager's season average.
+pub const SQUAD_WEIGHT_DECAY: f64 = 0.8;
+
+/// Gameweeks needed before a manager's own spread of scores is trusted.
+pub const MIN_GAMEWEEKS: usize = 3;
+
+/// Spread of gameweek scores used until then, in points.
+pub const DEFAULT_DEVIATION: f64 = 15.0;
+
+/// Smallest spread of gameweek scores used, in points.
+pub const MIN_DEVIATION: f64 = 8.0;
+
+/// Most gameweek scores drawn in one simulation, bounding its CPU time
+/// whatever the league size and runs asked for.
+pub const MAX_DRAWS: usize = 40_000_000;
+
+/// Runs between checks of the time budget.
+const CHECK_EVERY: usize = 256;
+
+/// A small, fast and seedable random number generator (SplitMix64)
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform draw from `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Standard normal draw (Box-Muller).
+    pub fn normal(&mut self) -> f64 {
+        let u = 1.0 - self.next_f64();
+        let v = self.next_f64();
+        (-2.0 * u.ln()).sqrt() * (std::f64::consts::TAU * v).cos()
+    }
+}
+
+/// A manager as they enter the simulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Contender {
+    pub entry: i32,
+
+    /// Points so far this season
+    pub total: i32,
+
+    /// Mean score in each remaining gameweek
+    pub expected: Vec<f64>,
+
+    /// Spread (standard deviation) of a gameweek score
+    pub deviation: f64,
+}
+
+impl Contender {
+    /// Seeds a manager from their squad's projection and their season so far
+    ///
+    /// # Arguments
+    /// * `entry` - The manager's entry ID
+    /// * `total` - Points so far this season
+    /// * `squad_points` - Projected points of the current squad's best XI and
+    ///   captain in each remaining gameweek
+    /// * `gameweeks` - The manager's gameweeks this season, used for their
+    ///   average and spread net of hits
+    pub fn new(entry: i32, total: i32, squad_points: &[f64], gameweeks: &[EntryHistory]) -> Self {
+        let scores: Vec<f64> = gameweeks
+            .iter()
+            .map(|gw| f64::from(gw.points - gw.event_transfers_cost))
+            .collect();
+        let count = scores.len() as f64;
+        let average = if scores.is_empty() {
+            None
+        } else {
+            Some(scores.iter().sum::<f64>() / count)
+        };
+        let deviation = match average {
+            Some(mean) if scores.len() >= MIN_GAMEWEEKS => {
+                let variance =
+                    scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (count - 1.0);
+                variance.sqrt().max(MIN_DEVIATION)
+            }
+            _ => DEFAULT_DEVIATION,
+        };
+
+        let mut weight = 1.0;
+        let expected = squad_points
+            .iter()
+            .map(|projected| {
+                let mean = match average {
+                    Some(average) => weight * projected + (1.0 - weight) * average,
+                    None => *projected,
+                };
+                weight *= SQUAD_WEIGHT_DECAY;
+                mean
+            })
+            .collect();
+
+        Self {
+            entry,
+            total,
+            expected,
+            deviation,
+        }
+    }
+
+    /// Projected season total.
+    pub fn expected_total(&self) -> f64 {
+        f64::from(self.total) + self.expected.iter().sum::<f64>()
+    }
+}
+
+/// One manager's simulated chances, each between 0 and 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct Odds {
+    pub entry: i32,
+
+    pub win: f64,
+
+    pub top_three: f64,
+
+    pub last: f64,
+}
+
+/// Outcome of a simulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Simulation {
+    /// Runs completed, fewer than asked for if a bound was reached
+    pub runs: usize,
+
+    /// Odds per contender, in the order they were given
+    pub odds: Vec<Odds>,
+}
+
+/// Simulates the rest of the season
+///
+/// Every run draws each contender's remaining gameweek scores and ranks the
+/// final totals; tied totals go to the contender given first. Each run
+/// continues the same random sequence, so a seed's first runs are the same
+/// however many are completed.
+///
+/// # Arguments
+/// * `contenders` - The league's managers
+/// * `runs` - Runs wanted, capped so no more than [`MAX_DRAWS`] scores are
+///   drawn
+/// * `seed` - Seed of the random draws
+/// * `budget` - Time after which no further runs are started
+///
+/// # Returns
+/// * `Simulation` - The runs completed and each contender's odds
+pub fn simulate(contenders: &[Contender], runs: usize, seed: u64, budget: Duration) -> Simulation {
+    let draws_per_run = contenders
+        .iter()
+        .map(|c| c.expected.len())
+        .sum::<usize>()
+        .max(1);
+    let runs = runs.min(MAX_DRAWS / draws_per_run).max(1);
+
+    let mut rng = Rng::new(seed);
+    let mut wins = vec![0usize; contenders.len()];
+    let mut top_threes = vec![0usize; contenders.len()];
+    let mut lasts = vec![0usize; contenders.len()];
+    let mut totals = vec![0.0; contenders.len()];
+    let mut order: Vec<usize> = (0..contenders.len()).collect();
+
+    let start = Instant::now();
+    let mut completed = 0;
+    while completed < runs {
+        if completed > 0 && completed % CHECK_EVERY == 0 && start.elapsed() >= budget {
+            break;
+        }
+        for (total, contender) in totals.iter_mut().zip(contenders) {
+            *total = f64::from(contender.total)
+                + contender
+                    .expected
+                    .iter()
+                    .map(|mean| (mean + contender.deviation * rng.normal()).max(0.0))
+                    .sum::<f64>();
+        }
+        order.sort_by(|a, b| totals[*b].total_cmp(&totals[*a]).then(a.cmp(b)));
+        for (place, index) in order.iter().enumerate() {
+            if place == 0 {
+                wins[*index] += 1;
+            }
+            if place < 3 {
+                top_threes[*index] += 1;
+            }
+        }
+        if let Some(index) = order.last() {
+            lasts[*index] += 1;
+        }
+        completed += 1;
+    }
+
+    let share = |count: usize| count as f64 / completed.max(1) as f64;
+    Simulation {
+        runs: completed,
+        odds: contenders
+            .iter()
+            .enumerate()
+            .map(|(i, contender)| Odds {
+                entry: contender.entry,
+                win: share(wins[i]),
+                top_three: share(top_threes[i]),
+                last: share(lasts[i]),
+            })
+            .collect(),
+    }
+}
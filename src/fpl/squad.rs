@@ -0,0 +1,211 @@
+//! FPL squad and lineup rules
+//!
+//! Positions, formation limits and best-XI selection shared by every feature
+//! that needs to pick a valid starting lineup from a pool of players, whether
+//! scored by actual points or by projections.
+
+use std::fmt;
+
+/// Minimum and maximum starters per position in a valid FPL formation.
+const XI_LIMITS: [(Position, usize, usize); 4] = [
+    (Position::Goalkeeper, 1, 1),
+    (Position::Defender, 3, 5),
+    (Position::Midfielder, 2, 5),
+    (Position::Forward, 1, 3),
+];
+
+/// Number of players in a starting lineup.
+pub const XI_SIZE: usize = 11;
+
+/// A player's position, as encoded by `element_type` in the FPL API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Position {
+    Goalkeeper,
+    Defender,
+    Midfielder,
+    Forward,
+}
+
+impl Position {
+    /// All positions, goalkeeper first.
+    pub const ALL: [Position; 4] = [
+        Position::Goalkeeper,
+        Position::Defender,
+        Position::Midfielder,
+        Position::Forward,
+    ];
+
+    /// Converts an FPL `element_type` (1-4) into a position.
+    pub fn from_element_type(element_type: i32) -> Option<Self> {
+        match element_type {
+            1 => Some(Position::Goalkeeper),
+            2 => Some(Position::Defender),
+            3 => Some(Position::Midfielder),
+            4 => Some(Position::Forward),
+            _ => None,
+        }
+    }
+
+    /// Three letter abbreviation used by the FPL site.
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Position::Goalkeeper => "GKP",
+            Position::Defender => "DEF",
+            Position::Midfielder => "MID",
+            Position::Forward => "FWD",
+        }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.short_name())
+    }
+}
+
+/// A player that can be picked into a lineup, with the score to maximise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub id: i32,
+
+    pub position: Position,
+
+    pub score: f64,
+}
+
+/// A starting XI and the ordered bench left over from the pool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lineup {
+    pub starters: Vec<Candidate>,
+
+    /// Remaining players: goalkeepers first, then outfielders by score
+    pub bench: Vec<Candidate>,
+}
+
+impl Lineup {
+    /// Sum of the starters' scores.
+    pub fn total_score(&self) -> f64 {
+        self.starters.iter().map(|c| c.score).sum()
+    }
+
+    /// Starters in one position, best first.
+    pub fn starters_in(&self, position: Position) -> impl Iterator<Item = &Candidate> {
+        self.starters.iter().filter(move |c| c.position == position)
+    }
+
+    /// Formation string such as `3-4-3`.
+    pub fn formation(&self) -> String {
+        formation(&self.starters)
+    }
+
+    /// Captain and vice-captain: the two highest scoring starters.
+    pub fn captains(&self) -> (Option<i32>, Option<i32>) {
+        let mut starters: Vec<&Candidate> = self.starters.iter().collect();
+        starters.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.id.cmp(&b.id)));
+        (
+            starters.first().map(|c| c.id),
+            starters.get(1).map(|c| c.id),
+        )
+    }
+}
+
+/// Score of a set of starters with the captain's score counted twice.
+pub fn captained_score(starters: &[Candidate], captain: Option<i32>) -> f64 {
+    let captain_score = starters
+        .iter()
+        .find(|c| Some(c.id) == captain)
+        .map_or(0.0, |c| c.score);
+    starters.iter().map(|c| c.score).sum::<f64>() + captain_score
+}
+
+/// Formation string (defenders-midfielders-forwards) for a set of starters.
+pub fn formation(starters: &[Candidate]) -> String {
+    let count = |position| starters.iter().filter(|c| c.position == position).count();
+    format!(
+        "{}-{}-{}",
+        count(Position::Defender),
+        count(Position::Midfielder),
+        count(Position::Forward)
+    )
+}
+
+/// Whether a set of starters forms a legal FPL lineup.
+pub fn is_valid_xi(starters: &[Candidate]) -> bool {
+    starters.len() == XI_SIZE
+        && XI_LIMITS.iter().all(|(position, min, max)| {
+            let n = starters.iter().filter(|c| c.position == *position).count();
+            (*min..=*max).contains(&n)
+        })
+}
+
+/// Picks the highest scoring legal XI from a pool of candidates
+///
+/// Fills each position's minimum with its best players first, then spends the
+/// remaining slots on the best players left whose position is not yet full.
+/// Because the formation limits only cap each position independently, this
+/// greedy choice is optimal.
+///
+/// Returns `None` if the pool cannot form a legal XI (e.g. no goalkeeper).
+///
+/// # Arguments
+/// * `candidates` - The player pool; may contain any number of players
+pub fn best_xi(candidates: &[Candidate]) -> Option<Lineup> {
+    let mut pool: Vec<Candidate> = candidates.to_vec();
+    pool.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.id.cmp(&b.id)));
+
+    let mut starters: Vec<Candidate> = Vec::with_capacity(XI_SIZE);
+    let mut rest: Vec<Candidate> = Vec::new();
+
+    for (position, min, _) in XI_LIMITS {
+        let mut taken = 0;
+        for candidate in pool.iter().filter(|c| c.position == position) {
+            if taken < min {
+                starters.push(candidate.clone());
+                taken += 1;
+            }
+        }
+        if taken < min {
+            return None;
+        }
+    }
+
+    for candidate in pool {
+        if starters.iter().any(|s| s.id == candidate.id) {
+            continue;
+        }
+        let max = XI_LIMITS
+            .iter()
+            .find(|(position, _, _)| *position == candidate.position)
+            .map(|(_, _, max)| *max)
+            .unwrap_or(0);
+        let in_position = starters
+            .iter()
+            .filter(|s| s.position == candidate.position)
+            .count();
+        if starters.len() < XI_SIZE && in_position < max {
+            starters.push(candidate);
+        } else {
+            rest.push(candidate);
+        }
+    }
+
+    if starters.len() < XI_SIZE {
+        return None;
+    }
+
+    starters.sort_by(|a, b| {
+        a.position
+            .cmp(&b.position)
+            .then(b.score.total_cmp(&a.score))
+    });
+    rest.sort_by(|a, b| {
+        (a.position != Position::Goalkeeper)
+            .cmp(&(b.position != Position::Goalkeeper))
+            .then(b.score.total_cmp(&a.score))
+    });
+
+    Some(Lineup {
+        starters,
+        bench: rest,
+    })
+}
@@ -0,0 +1,324 @@
+//! Squad building
+//!
+//! Builds the fifteen-man squad with the most projected points for a
+//! wildcard or free hit: two goalkeepers, five defenders, five midfielders and
+//! three forwards, no more than three from a club and within the budget,
+//! keeping any locked players and leaving out any excluded ones.
+//!
+//! The search starts from the cheapest legal squad and keeps making the swap
+//! that adds the most projected points until none is left. When no single
+//! swap helps, pairs of swaps are tried so money freed by one downgrade can
+//! pay for an upgrade elsewhere.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+
+use crate::fpl::squad::{best_xi, Candidate, Lineup, Position};
+use crate::fpl::transfers::{shortlist, TransferPlayer, MAX_PER_CLUB};
+
+/// Players per position in a squad.
+pub const SQUAD_QUOTAS: [(Position, usize); 4] = [
+    (Position::Goalkeeper, 2),
+    (Position::Defender, 5),
+    (Position::Midfielder, 5),
+    (Position::Forward, 3),
+];
+
+/// Number of players in a squad.
+pub const SQUAD_SIZE: usize = 15;
+
+/// Weight of bench points in a squad's score, so a squad with a useful bench
+/// beats an otherwise equal one.
+const BENCH_WEIGHT: f64 = 0.1;
+
+/// Players per position tried in pairs of swaps, on top of the best value
+/// players at each price.
+const SHORTLIST: usize = 20;
+
+/// Most improving swaps made before the search stops.
+const MAX_ROUNDS: usize = 200;
+
+/// Smallest score change counted as an improvement.
+const EPSILON: f64 = 1e-9;
+
+/// A built squad and its lineup for the first gameweek
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuiltSquad {
+    /// The fifteen players, by position and then projected points
+    pub players: Vec<TransferPlayer>,
+
+    /// Total price, in tenths of a million
+    pub cost: i32,
+
+    /// Projected points across the horizon from each gameweek's best XI and
+    /// captain
+    pub points: f64,
+
+    /// Best XI and bench for the first gameweek of the horizon
+    pub lineup: Lineup,
+
+    /// The first gameweek's captain, the starter projected highest
+    pub captain: i32,
+}
+
+/// Score maximised by the search
+///
+/// Each gameweek counts the best legal XI plus the captain again, and a small
+/// share of the bench.
+pub fn squad_score(squad: &[&TransferPlayer]) -> f64 {
+    gameweek_lineups(squad)
+        .iter()
+        .map(|lineup| {
+            let bench: f64 = lineup.bench.iter().map(|c| c.score).sum();
+            lineup.total_score() + captain_score(lineup) + BENCH_WEIGHT * bench
+        })
+        .sum()
+}
+
+/// Best lineup of each gameweek in the horizon
+fn gameweek_lineups(squad: &[&TransferPlayer]) -> Vec<Lineup> {
+    let horizon = squad.iter().map(|p| p.points.len()).max().unwrap_or(0);
+    (0..horizon)
+        .filter_map(|gw| {
+            let candidates: Vec<Candidate> = squad
+                .iter()
+                .map(|player| Candidate {
+                    id: player.element,
+                    position: player.position,
+                    score: player.points.get(gw).copied().unwrap_or(0.0),
+                })
+                .collect();
+            best_xi(&candidates)
+        })
+        .collect()
+}
+
+/// The highest projected starter's points
+fn captain_score(lineup: &Lineup) -> f64 {
+    lineup.starters.iter().map(|c| c.score).fold(0.0, f64::max)
+}
+
+/// Builds the squad with the highest [`squad_score`]
+///
+/// # Arguments
+/// * `pool` - Every player that could be picked, at their current prices
+/// * `budget` - Most the squad may cost, in tenths of a million
+/// * `locks` - Element IDs that must be in the squad
+/// * `excludes` - Element IDs that must not be
+///
+/// # Returns
+/// * `Result<BuiltSquad>` - The best squad found
+///
+/// # Errors
+/// Returns error if:
+/// - A locked player is excluded or not in the pool
+/// - The locked players break a position quota or club limit
+/// - No legal squad fits the budget
+pub fn build_squad(
+    pool: &[TransferPlayer],
+    budget: i32,
+    locks: &[i32],
+    excludes: &[i32],
+) -> Result<BuiltSquad> {
+    let pool: Vec<&TransferPlayer> = pool
+        .iter()
+        .filter(|p| !excludes.contains(&p.element))
+        .collect();
+
+    let mut squad: Vec<&TransferPlayer> = Vec::with_capacity(SQUAD_SIZE);
+    for element in locks {
+        let player = pool
+            .iter()
+            .find(|p| p.element == *element)
+            .ok_or_else(|| anyhow!("Locked player {} is excluded or unavailable", element))?;
+        if !squad.iter().any(|p| p.element == *element) {
+            squad.push(player);
+        }
+    }
+    for (position, quota) in SQUAD_QUOTAS {
+        if squad.iter().filter(|p| p.position == position).count() > quota {
+            return Err(anyhow!("More than {} {} are locked", quota, position));
+        }
+    }
+    if let Some(team) = squad
+        .iter()
+        .map(|p| p.team)
+        .find(|team| club_count(&squad, *team) > MAX_PER_CLUB)
+    {
+        return Err(anyhow!(
+            "More than {} locked players play for team {}",
+            MAX_PER_CLUB,
+            team
+        ));
+    }
+
+    // the cheapest legal squad around the locked players
+    let mut by_price: Vec<&TransferPlayer> = pool.clone();
+    by_price.sort_by(|a, b| {
+        a.price
+            .cmp(&b.price)
+            .then(b.total().total_cmp(&a.total()))
+            .then(a.element.cmp(&b.element))
+    });
+    for (position, quota) in SQUAD_QUOTAS {
+        for player in by_price.iter().filter(|p| p.position == position) {
+            if squad.iter().filter(|p| p.position == position).count() >= quota {
+                break;
+            }
+            if !squad.iter().any(|p| p.element == player.element)
+                && club_count(&squad, player.team) < MAX_PER_CLUB
+            {
+                squad.push(player);
+            }
+        }
+    }
+    if squad.len() < SQUAD_SIZE {
+        return Err(anyhow!("There are not enough players to fill a squad"));
+    }
+    if cost(&squad) > budget {
+        return Err(anyhow!(
+            "The cheapest squad costs £{:.1}m, over the budget",
+            f64::from(cost(&squad)) / 10.0
+        ));
+    }
+
+    let shortlists: HashMap<Position, Vec<&TransferPlayer>> = Position::ALL
+        .into_iter()
+        .map(|position| (position, shortlist(&pool, position, SHORTLIST)))
+        .collect();
+    let mut score = squad_score(&squad);
+    for _ in 0..MAX_ROUNDS {
+        let improved = best_single_swap(&squad, &pool, budget, locks, score)
+            .or_else(|| best_double_swap(&squad, &shortlists, budget, locks, score));
+        match improved {
+            Some((next, next_score)) => {
+                squad = next;
+                score = next_score;
+            }
+            None => break,
+        }
+    }
+
+    let lineup = gameweek_lineups(&squad)
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("There are no gameweeks to build a squad for"))?;
+    let captain = lineup
+        .starters
+        .iter()
+        .max_by(|a, b| a.score.total_cmp(&b.score))
+        .map_or(0, |c| c.id);
+    let mut players: Vec<TransferPlayer> = squad.iter().map(|p| (*p).clone()).collect();
+    players.sort_by(|a, b| {
+        a.position
+            .cmp(&b.position)
+            .then(b.total().total_cmp(&a.total()))
+    });
+
+    Ok(BuiltSquad {
+        cost: cost(&squad),
+        points: gameweek_lineups(&squad)
+            .iter()
+            .map(|lineup| lineup.total_score() + captain_score(lineup))
+            .sum(),
+        players,
+        lineup,
+        captain,
+    })
+}
+
+/// The squad after the best improving swap of one player, if any
+fn best_single_swap<'a>(
+    squad: &[&'a TransferPlayer],
+    pool: &[&'a TransferPlayer],
+    budget: i32,
+    locks: &[i32],
+    score: f64,
+) -> Option<(Vec<&'a TransferPlayer>, f64)> {
+    let mut best: Option<(Vec<&TransferPlayer>, f64)> = None;
+    for i in 0..squad.len() {
+        if locks.contains(&squad[i].element) {
+            continue;
+        }
+        for target in pool.iter().filter(|p| p.position == squad[i].position) {
+            if let Some(next) = swap(squad, &[i], &[target], budget) {
+                let next_score = squad_score(&next);
+                if next_score > best.as_ref().map_or(score, |(_, s)| *s) + EPSILON {
+                    best = Some((next, next_score));
+                }
+            }
+        }
+    }
+    best
+}
+
+/// The squad after the best improving swap of two players, if any
+fn best_double_swap<'a>(
+    squad: &[&'a TransferPlayer],
+    shortlists: &HashMap<Position, Vec<&'a TransferPlayer>>,
+    budget: i32,
+    locks: &[i32],
+    score: f64,
+) -> Option<(Vec<&'a TransferPlayer>, f64)> {
+    let mut best: Option<(Vec<&TransferPlayer>, f64)> = None;
+    for i in 0..squad.len() {
+        for j in (i + 1)..squad.len() {
+            if locks.contains(&squad[i].element) || locks.contains(&squad[j].element) {
+                continue;
+            }
+            for a in &shortlists[&squad[i].position] {
+                for b in &shortlists[&squad[j].position] {
+                    if a.element == b.element
+                        || (squad[i].position == squad[j].position && a.element > b.element)
+                    {
+                        continue;
+                    }
+                    if let Some(next) = swap(squad, &[i, j], &[a, b], budget) {
+                        let next_score = squad_score(&next);
+                        if next_score > best.as_ref().map_or(score, |(_, s)| *s) + EPSILON {
+                            best = Some((next, next_score));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    best
+}
+
+/// The squad with the players at `outs` replaced by `ins`, or `None` if it
+/// would repeat a player, go over budget or break a club limit
+fn swap<'a>(
+    squad: &[&'a TransferPlayer],
+    outs: &[usize],
+    ins: &[&'a TransferPlayer],
+    budget: i32,
+) -> Option<Vec<&'a TransferPlayer>> {
+    let mut next: Vec<&TransferPlayer> = squad
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !outs.contains(i))
+        .map(|(_, p)| *p)
+        .collect();
+    for target in ins {
+        if next.iter().any(|p| p.element == target.element) {
+            return None;
+        }
+        next.push(target);
+    }
+    if cost(&next) > budget || ins.iter().any(|p| club_count(&next, p.team) > MAX_PER_CLUB) {
+        return None;
+    }
+    Some(next)
+}
+
+/// Total price of a squad
+fn cost(squad: &[&TransferPlayer]) -> i32 {
+    squad.iter().map(|p| p.price).sum()
+}
+
+/// Players in a squad from one club
+fn club_count(squad: &[&TransferPlayer], team: i32) -> usize {
+    squad.iter().filter(|p| p.team == team).count()
+}
@@ -0,0 +1,342 @@
+//! Paging through classic league standings
+//!
+//! The FPL API serves standings 50 entries at a time and only says whether
+//! another page follows. This module caches the API pages it fetches, slices
+//! them into display pages of any size, finds the real number of entries with
+//! a handful of probes, and locates a manager's position in the league. It
+//! also defines the alternative ways a league can be ranked ([`StandingsView`]).
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use log::info;
+
+use crate::fpl::models::league::{LeagueStandings, StandingsManager};
+use crate::utils::cache::TtlCache;
+
+/// Gameweeks counted by the form view.
+pub const FORM_GAMEWEEKS: usize = 5;
+
+/// Entries per page of the FPL standings endpoint.
+pub const API_PAGE_SIZE: usize = 50;
+
+/// How long a fetched API page is reused before being fetched again.
+const CACHE_TTL: Duration = Duration::from_secs(120);
+
+/// API pages kept in the cache before expired pages are dropped.
+const MAX_CACHED_PAGES: usize = 500;
+
+/// Highest API page probed when counting entries (about 3.2M entries). Larger
+/// leagues, such as the overall league, are reported without a total.
+const MAX_PROBED_PAGE: i32 = 1 << 16;
+
+/// API pages scanned past a manager's rank when finding them, since tied
+/// managers share a rank but not a position.
+const MAX_FIND_PAGES: i32 = 3;
+
+/// Cached API pages by league and page.
+static PAGE_CACHE: OnceLock<TtlCache<(i32, i32), Arc<LeagueStandings>>> = OnceLock::new();
+
+fn cache() -> &'static TtlCache<(i32, i32), Arc<LeagueStandings>> {
+    PAGE_CACHE.get_or_init(|| TtlCache::new(CACHE_TTL, MAX_CACHED_PAGES))
+}
+
+/// Fetches a page of the FPL standings endpoint, reusing recent fetches
+///
+/// # Arguments
+/// * `league_id` - The classic league ID
+/// * `page` - The API page (1-based, 50 entries each)
+///
+/// # Errors
+/// Returns error if the FPL API request fails
+pub async fn api_page(league_id: i32, page: i32) -> Result<Arc<LeagueStandings>> {
+    let key = (league_id, page);
+    if let Some(standings) = cache().get(&key) {
+        return Ok(standings);
+    }
+
+    let standings = Arc::new(LeagueStandings::fetch_page(league_id, page).await?);
+    cache().insert(key, standings.clone());
+    Ok(standings)
+}
+
+/// Drops a league's cached pages, so the next request fetches fresh standings.
+pub fn invalidate(league_id: i32) {
+    cache().retain(|(league, _)| *league != league_id);
+}
+
+/// API pages holding the entries `start..start + count` (0-based positions).
+pub fn api_pages_for(start: usize, count: usize) -> std::ops::RangeInclusive<i32> {
+    let first = start / API_PAGE_SIZE;
+    let last = (start + count.max(1) - 1) / API_PAGE_SIZE;
+    (first as i32 + 1)..=(last as i32 + 1)
+}
+
+/// Number of display pages needed for a number of entries (at least one).
+pub fn page_count(total_entries: usize, per_page: usize) -> usize {
+    total_entries.div_ceil(per_page).max(1)
+}
+
+/// Fetches the entries at positions `start..start + count`
+///
+/// # Arguments
+/// * `league_id` - The classic league ID
+/// * `start` - Position of the first entry (0-based)
+/// * `count` - Number of entries wanted
+///
+/// # Returns
+/// * `Result<(Arc<LeagueStandings>, Vec<StandingsManager>)>` - The first API
+///   page fetched, for league details, and the entries that exist in the range
+///
+/// # Errors
+/// Returns error if the FPL API request fails
+pub async fn entries(
+    league_id: i32,
+    start: usize,
+    count: usize,
+) -> Result<(Arc<LeagueStandings>, Vec<StandingsManager>)> {
+    let pages = api_pages_for(start, count);
+    let first = api_page(league_id, *pages.start()).await?;
+    let mut entries = first.standings.managers.clone();
+    if first.standings.has_next {
+        for page in pages.skip(1) {
+            let standings = api_page(league_id, page).await?;
+            entries.extend(standings.standings.managers.iter().cloned());
+            if !standings.standings.has_next {
+                break;
+            }
+        }
+    }
+
+    let entries = entries
+        .into_iter()
+        .skip(start % API_PAGE_SIZE)
+        .take(count)
+        .collect();
+    Ok((first, entries))
+}
+
+/// Fetches a league's entries from the top, up to a limit
+///
+/// # Arguments
+/// * `league_id` - The classic league ID
+/// * `limit` - Most entries to fetch
+///
+/// # Returns
+/// * `Result<(Arc<LeagueStandings>, Vec<StandingsManager>, bool)>` - The first
+///   API page, the entries in standings order, and whether the league had more
+///   entries than the limit
+///
+/// # Errors
+/// Returns error if the FPL API request fails
+pub async fn top_entries(
+    league_id: i32,
+    limit: usize,
+) -> Result<(Arc<LeagueStandings>, Vec<StandingsManager>, bool)> {
+    let first = api_page(league_id, 1).await?;
+    let mut entries = first.standings.managers.clone();
+    let mut has_next = first.standings.has_next;
+    let mut page = 1;
+    while has_next && entries.len() < limit {
+        page += 1;
+        let standings = api_page(league_id, page).await?;
+        entries.extend(standings.standings.managers.iter().cloned());
+        has_next = standings.standings.has_next;
+    }
+
+    let truncated = has_next || entries.len() > limit;
+    entries.truncate(limit);
+    Ok((first, entries, truncated))
+}
+
+/// Counts the entries in a league
+///
+/// Probes API pages 1, 2, 4, 8… until one has no next page, then binary
+/// searches for the last page, so counting costs about two requests per
+/// doubling of the league's size.
+///
+/// # Returns
+/// * `Result<Option<usize>>` - The number of entries, or `None` for leagues
+///   too large to count
+///
+/// # Errors
+/// Returns error if the FPL API request fails
+pub async fn total_entries(league_id: i32) -> Result<Option<usize>> {
+    // `known` has a next page, `beyond` does not
+    let first = api_page(league_id, 1).await?;
+    if !first.standings.has_next {
+        return Ok(Some(first.standings.managers.len()));
+    }
+    let mut known = 1;
+    let mut beyond = 2;
+    while api_page(league_id, beyond).await?.standings.has_next {
+        known = beyond;
+        beyond *= 2;
+        if beyond > MAX_PROBED_PAGE {
+            info!("League {} is too large to count", league_id);
+            return Ok(None);
+        }
+    }
+    while beyond - known > 1 {
+        let middle = known + (beyond - known) / 2;
+        if api_page(league_id, middle).await?.standings.has_next {
+            known = middle;
+        } else {
+            beyond = middle;
+        }
+    }
+
+    let last = api_page(league_id, beyond).await?;
+    Ok(Some(
+        known as usize * API_PAGE_SIZE + last.standings.managers.len(),
+    ))
+}
+
+/// Finds a manager's position in a league
+///
+/// # Arguments
+/// * `league_id` - The classic league ID
+/// * `manager_id` - The manager to find
+/// * `rank` - The manager's league rank, if known, to start the search from
+///
+/// # Returns
+/// * `Result<Option<usize>>` - The manager's 0-based position, or `None` if
+///   they were not found near their rank (or in the first pages without one)
+///
+/// # Errors
+/// Returns error if the FPL API request fails
+pub async fn find_manager(
+    league_id: i32,
+    manager_id: i32,
+    rank: Option<i32>,
+) -> Result<Option<usize>> {
+    let start = rank.map_or(0, |r| (r.max(1) - 1) as usize);
+    let first_page = *api_pages_for(start, 1).start();
+    for page in first_page..first_page + MAX_FIND_PAGES {
+        let standings = api_page(league_id, page).await?;
+        if let Some(index) = standings
+            .standings
+            .managers
+            .iter()
+            .position(|m| m.manager_id == manager_id)
+        {
+            return Ok(Some((page as usize - 1) * API_PAGE_SIZE + index));
+        }
+        if !standings.standings.has_next {
+            break;
+        }
+    }
+    Ok(None)
+}
+
+/// A way of ranking a league's entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StandingsView {
+    /// The league table: total points
+    #[default]
+    Total,
+
+    /// Points in the current gameweek
+    Gameweek,
+
+    /// Points over the last [`FORM_GAMEWEEKS`] gameweeks, after hits
+    Form,
+
+    /// The league table with points behind the leader and the place above
+    Gaps,
+
+    /// Team value
+    Value,
+}
+
+impl StandingsView {
+    /// Every view, in menu order.
+    pub const ALL: [StandingsView; 5] = [
+        StandingsView::Total,
+        StandingsView::Gameweek,
+        StandingsView::Form,
+        StandingsView::Gaps,
+        StandingsView::Value,
+    ];
+
+    /// Identifier used in command options and custom IDs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StandingsView::Total => "total",
+            StandingsView::Gameweek => "gameweek",
+            StandingsView::Form => "form",
+            StandingsView::Gaps => "gaps",
+            StandingsView::Value => "value",
+        }
+    }
+
+    /// Human readable name for menus.
+    pub fn label(&self) -> &'static str {
+        match self {
+            StandingsView::Total => "League table",
+            StandingsView::Gameweek => "Gameweek ranking",
+            StandingsView::Form => "Form table",
+            StandingsView::Gaps => "Points gaps",
+            StandingsView::Value => "Team value",
+        }
+    }
+
+    /// Whether the view re-ranks the league, rather than following the table.
+    pub fn is_reranked(&self) -> bool {
+        matches!(
+            self,
+            StandingsView::Gameweek | StandingsView::Form | StandingsView::Value
+        )
+    }
+
+    /// Whether the view needs each manager's season history.
+    pub fn needs_history(&self) -> bool {
+        matches!(self, StandingsView::Form | StandingsView::Value)
+    }
+}
+
+impl fmt::Display for StandingsView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StandingsView {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        StandingsView::ALL
+            .into_iter()
+            .find(|view| view.as_str() == s)
+            .ok_or_else(|| anyhow!("Unknown standings view {:?}", s))
+    }
+}
+
+/// Orders items by a metric, highest first
+///
+/// Items without a metric go last, and items with equal metrics keep their
+/// original order and share a rank ("1, 2, 2, 4").
+///
+/// # Arguments
+/// * `items` - Items and their metric, in standings order
+///
+/// # Returns
+/// * `Vec<(usize, T, Option<i32>)>` - Rank (1-based), item and metric, best first
+pub fn rank_by_metric<T>(items: Vec<(T, Option<i32>)>) -> Vec<(usize, T, Option<i32>)> {
+    let mut items = items;
+    // stable, so ties stay in standings order
+    items.sort_by_key(|(_, metric)| std::cmp::Reverse(metric.map_or(i64::MIN, i64::from)));
+
+    let mut ranked: Vec<(usize, T, Option<i32>)> = Vec::with_capacity(items.len());
+    for (i, (item, metric)) in items.into_iter().enumerate() {
+        let rank = match ranked.last() {
+            Some((previous_rank, _, previous)) if *previous == metric => *previous_rank,
+            _ => i + 1,
+        };
+        ranked.push((rank, item, metric));
+    }
+    ranked
+}
@@ -0,0 +1,371 @@
+//! Transfer suggestions
+//!
+//! Searches for the one or two transfers that add the most projected points
+//! to a squad over the next few gameweeks, within FPL's rules: players are
+//! swapped like for like so the 2/5/5/3 squad structure holds, the bank plus
+//! the selling prices of the players sold must cover the players bought, and
+//! no club may have more than three players. Transfers beyond the free ones
+//! cost a hit, which is taken off the plan's projected points.
+
+use std::collections::HashMap;
+
+use crate::fpl::models::history::ChipPlay;
+use crate::fpl::models::picks::EntryHistory;
+use crate::fpl::models::transfers::ManagerTransfer;
+use crate::fpl::squad::{best_xi, Candidate, Position};
+
+/// Most players a squad may have from one club.
+pub const MAX_PER_CLUB: usize = 3;
+
+/// Points deducted for each transfer beyond the free ones.
+pub const HIT_POINTS: i32 = 4;
+
+/// Most free transfers a manager can bank.
+pub const MAX_FREE_TRANSFERS: i32 = 5;
+
+/// Most transfers searched together.
+pub const MAX_TRANSFERS: usize = 2;
+
+/// Players per position considered for each half of a double transfer, on
+/// top of the best value players at each price.
+const SHORTLIST: usize = 15;
+
+/// A player in the squad or the transfer pool, with their projections
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferPlayer {
+    pub element: i32,
+
+    pub position: Position,
+
+    pub team: i32,
+
+    /// Selling price for squad players and current price for everyone else,
+    /// in tenths of a million
+    pub price: i32,
+
+    /// Projected points in each gameweek of the horizon
+    pub points: Vec<f64>,
+}
+
+impl TransferPlayer {
+    /// Projected points across the horizon.
+    pub fn total(&self) -> f64 {
+        self.points.iter().sum()
+    }
+}
+
+/// What FPL pays for a player, in tenths of a million
+///
+/// Price rises are shared half and half with FPL, rounded down in FPL's
+/// favour, while falls are passed on in full.
+pub fn selling_price(purchase_price: i32, now_cost: i32) -> i32 {
+    if now_cost <= purchase_price {
+        now_cost
+    } else {
+        purchase_price + (now_cost - purchase_price) / 2
+    }
+}
+
+/// Free transfers left for the next gameweek
+///
+/// A manager gets one free transfer after each gameweek, banking up to
+/// [`MAX_FREE_TRANSFERS`]. Transfers made before a manager's first gameweek,
+/// or in a gameweek with a wildcard or free hit, do not use any up. Transfers
+/// already made for the next deadline use up the ones left.
+///
+/// # Arguments
+/// * `gameweeks` - The manager's gameweeks this season, oldest first
+/// * `chips` - Chips played this season, including any already played for
+///   the next gameweek
+/// * `next_gameweek` - The gameweek of the next deadline
+/// * `pending` - Transfers already made for the next deadline
+pub fn free_transfers(
+    gameweeks: &[EntryHistory],
+    chips: &[ChipPlay],
+    next_gameweek: i32,
+    pending: i32,
+) -> i32 {
+    let unlimited = |event: i32| {
+        chips
+            .iter()
+            .any(|chip| chip.event == event && matches!(chip.name.as_str(), "wildcard" | "freehit"))
+    };
+
+    let mut free = 0;
+    for (i, gameweek) in gameweeks.iter().enumerate() {
+        if i > 0 && !unlimited(gameweek.event) {
+            free = (free - gameweek.event_transfers).max(0);
+        }
+        free = (free + 1).min(MAX_FREE_TRANSFERS);
+    }
+    if unlimited(next_gameweek) {
+        free
+    } else {
+        (free - pending).max(0)
+    }
+}
+
+/// Applies the transfers already made for the next deadline to a squad
+///
+/// FPL's picks only include them once the deadline has passed, so until then
+/// the squad still holds the players sold and the bank is as it was before.
+/// Transfers are applied oldest first, so a player bought and sold again
+/// before the deadline ends up out of the squad.
+///
+/// # Arguments
+/// * `squad` - Element IDs of the squad from the latest picks, updated in place
+/// * `bank` - The bank from the latest picks, in tenths of a million
+/// * `transfers` - The manager's transfers this season, in any order
+/// * `next_gameweek` - The gameweek of the next deadline
+///
+/// # Returns
+/// * `i32` - The bank after the transfers
+pub fn apply_pending_transfers(
+    squad: &mut [i32],
+    bank: i32,
+    transfers: &[ManagerTransfer],
+    next_gameweek: i32,
+) -> i32 {
+    let mut pending: Vec<&ManagerTransfer> = transfers
+        .iter()
+        .filter(|transfer| transfer.event == next_gameweek)
+        .collect();
+    pending.sort_by_key(|transfer| transfer.time);
+
+    let mut bank = bank;
+    for transfer in pending {
+        if let Some(slot) = squad.iter_mut().find(|e| **e == transfer.element_out) {
+            *slot = transfer.element_in;
+            bank += transfer.element_out_cost - transfer.element_in_cost;
+        }
+    }
+    bank
+}
+
+/// Projected points of a squad across the horizon
+///
+/// Each gameweek counts the best legal XI from the squad plus the top
+/// starter again as captain. Bench points are ignored.
+pub fn squad_points(squad: &[&TransferPlayer]) -> f64 {
+    let horizon = squad.iter().map(|p| p.points.len()).max().unwrap_or(0);
+    (0..horizon)
+        .map(|gw| {
+            let candidates: Vec<Candidate> = squad
+                .iter()
+                .map(|player| Candidate {
+                    id: player.element,
+                    position: player.position,
+                    score: player.points.get(gw).copied().unwrap_or(0.0),
+                })
+                .collect();
+            best_xi(&candidates).map_or(0.0, |lineup| {
+                let captain = lineup.starters.iter().map(|c| c.score).fold(0.0, f64::max);
+                lineup.total_score() + captain
+            })
+        })
+        .sum()
+}
+
+/// One player sold and another bought in their place
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transfer {
+    pub element_out: i32,
+
+    pub element_in: i32,
+}
+
+/// A set of transfers and what the squad projects to after them
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferPlan {
+    pub transfers: Vec<Transfer>,
+
+    /// Projected squad points across the horizon, before any hit
+    pub points: f64,
+
+    /// Points deducted for transfers beyond the free ones
+    pub hit: i32,
+
+    /// Money in the bank afterwards, in tenths of a million
+    pub bank: i32,
+}
+
+impl TransferPlan {
+    /// Projected points after the hit.
+    pub fn net_points(&self) -> f64 {
+        self.points - f64::from(self.hit)
+    }
+}
+
+/// Searches for the best transfers
+///
+/// Every single transfer into the pool is tried. Double transfers try every
+/// pair of squad players against a shortlist per position: the most
+/// projected points plus the best value at each price, so downgrading one
+/// player to afford another is still found.
+///
+/// # Arguments
+/// * `squad` - The fifteen squad players, priced at their selling prices
+/// * `pool` - Players that could be bought; squad players are skipped
+/// * `bank` - Money in the bank, in tenths of a million
+/// * `free_transfers` - Free transfers available
+/// * `max_transfers` - Most transfers in a plan, up to [`MAX_TRANSFERS`]
+/// * `per_count` - Plans kept for each number of transfers
+///
+/// # Returns
+/// * `Vec<TransferPlan>` - The plan with no transfers, then the best plans
+///   for each number of transfers, each group best first by net points
+pub fn suggest(
+    squad: &[TransferPlayer],
+    pool: &[TransferPlayer],
+    bank: i32,
+    free_transfers: i32,
+    max_transfers: usize,
+    per_count: usize,
+) -> Vec<TransferPlan> {
+    let owned: Vec<i32> = squad.iter().map(|p| p.element).collect();
+    let pool: Vec<&TransferPlayer> = pool
+        .iter()
+        .filter(|p| !owned.contains(&p.element))
+        .collect();
+    let hit = |count: usize| (count as i32 - free_transfers).max(0) * HIT_POINTS;
+
+    let mut plans = vec![TransferPlan {
+        transfers: Vec::new(),
+        points: squad_points(&squad.iter().collect::<Vec<_>>()),
+        hit: 0,
+        bank,
+    }];
+
+    if max_transfers >= 1 {
+        let mut singles = Vec::new();
+        for (i, out) in squad.iter().enumerate() {
+            for target in pool.iter().filter(|p| p.position == out.position) {
+                if let Some(plan) = try_plan(squad, bank, &[i], &[target]) {
+                    singles.push(TransferPlan {
+                        hit: hit(1),
+                        ..plan
+                    });
+                }
+            }
+        }
+        plans.extend(best_plans(singles, per_count));
+    }
+
+    if max_transfers >= 2 {
+        let shortlists: HashMap<Position, Vec<&TransferPlayer>> = Position::ALL
+            .into_iter()
+            .map(|position| (position, shortlist(&pool, position, SHORTLIST)))
+            .collect();
+        let mut doubles = Vec::new();
+        for i in 0..squad.len() {
+            for j in (i + 1)..squad.len() {
+                let (first, second) = (
+                    &shortlists[&squad[i].position],
+                    &shortlists[&squad[j].position],
+                );
+                for a in first {
+                    for b in second {
+                        // the same pair bought the other way round is the same squad
+                        if a.element == b.element
+                            || (squad[i].position == squad[j].position && a.element > b.element)
+                        {
+                            continue;
+                        }
+                        if let Some(plan) = try_plan(squad, bank, &[i, j], &[a, b]) {
+                            doubles.push(TransferPlan {
+                                hit: hit(2),
+                                ..plan
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        plans.extend(best_plans(doubles, per_count));
+    }
+
+    plans
+}
+
+/// Builds the plan of selling `outs` (squad indices) for `ins`, or `None` if
+/// the bank cannot cover it or a club would have too many players
+fn try_plan(
+    squad: &[TransferPlayer],
+    bank: i32,
+    outs: &[usize],
+    ins: &[&TransferPlayer],
+) -> Option<TransferPlan> {
+    let sold: i32 = outs.iter().map(|i| squad[*i].price).sum();
+    let bought: i32 = ins.iter().map(|p| p.price).sum();
+    let bank = bank + sold - bought;
+    if bank < 0 {
+        return None;
+    }
+
+    let mut after: Vec<&TransferPlayer> = squad
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !outs.contains(i))
+        .map(|(_, p)| p)
+        .collect();
+    after.extend(ins.iter().copied());
+    for target in ins {
+        if after.iter().filter(|p| p.team == target.team).count() > MAX_PER_CLUB {
+            return None;
+        }
+    }
+
+    Some(TransferPlan {
+        transfers: outs
+            .iter()
+            .zip(ins)
+            .map(|(i, target)| Transfer {
+                element_out: squad[*i].element,
+                element_in: target.element,
+            })
+            .collect(),
+        points: squad_points(&after),
+        hit: 0,
+        bank,
+    })
+}
+
+/// The players in a position worth trying in a multi-player swap: the top
+/// `size` by projected points, and every player who projects more than anyone
+/// cheaper
+pub(crate) fn shortlist<'a>(
+    pool: &[&'a TransferPlayer],
+    position: Position,
+    size: usize,
+) -> Vec<&'a TransferPlayer> {
+    let mut players: Vec<&TransferPlayer> = pool
+        .iter()
+        .copied()
+        .filter(|p| p.position == position)
+        .collect();
+    players.sort_by(|a, b| {
+        b.total()
+            .total_cmp(&a.total())
+            .then(a.element.cmp(&b.element))
+    });
+    let mut shortlist: Vec<&TransferPlayer> = players.iter().copied().take(size).collect();
+
+    players.sort_by(|a, b| a.price.cmp(&b.price).then(b.total().total_cmp(&a.total())));
+    let mut best = f64::NEG_INFINITY;
+    for player in players {
+        if player.total() > best {
+            best = player.total();
+            if !shortlist.iter().any(|p| p.element == player.element) {
+                shortlist.push(player);
+            }
+        }
+    }
+    shortlist
+}
+
+/// The `limit` best plans by net points
+fn best_plans(mut plans: Vec<TransferPlan>, limit: usize) -> Vec<TransferPlan> {
+    plans.sort_by(|a, b| b.net_points().total_cmp(&a.net_points()));
+    plans.truncate(limit);
+    plans
+}
@@ -2,5 +2,6 @@
 
 pub mod database;
 pub mod bot;
+pub mod config;
 pub mod fpl;
 pub mod utils;
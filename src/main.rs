@@ -3,35 +3,61 @@
 use dotenvy::dotenv;
 use crate::database::service::init_db_service;
 use serenity::prelude::*;
-use std::env;
 use log::{error, info};
+use tracing_subscriber::EnvFilter;
 
 mod bot;
+mod config;
 mod fpl;
 mod utils;
 mod database;
 
 use bot::handlers::Handler;
-use fpl::client::init_fpl_service;
+use config::{init_config, Config, LoggingConfig};
+use fpl::client::init_fpl_service_with;
+
+/// Installs the log subscriber described by the `[logging]` config section.
+/// `RUST_LOG`, when set, takes precedence over the configured level.
+fn init_logging(logging: &LoggingConfig) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(&logging.level));
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+    match logging.format.as_str() {
+        "json" => builder.json().init(),
+        "pretty" => builder.pretty().init(),
+        "compact" => builder.compact().init(),
+        _ => builder.init(),
+    }
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv().ok();
-    
-    tracing_subscriber::fmt::init();
 
-    let token = env::var("BOT_TOKEN")
-        .expect("Expected BOT_TOKEN in environment");
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{:#}", e);
+            std::process::exit(1);
+        }
+    };
+    init_logging(&config.logging);
 
     let intents = GatewayIntents::GUILD_MESSAGES 
         | GatewayIntents::DIRECT_MESSAGES
         | GatewayIntents::MESSAGE_CONTENT;
 
-    init_db_service().await?;
-    init_fpl_service()?;
+    init_db_service(&config.database.url).await?;
+    init_fpl_service_with(&config.fpl)?;
+
+    let registration = config.discord.registration_mode()?;
+    info!("Registering commands: {:?}", registration);
+
+    let token = config.discord.token.clone();
+    init_config(config)?;
 
     let mut client = Client::builder(&token, intents)
-        .event_handler(Handler)
+        .event_handler(Handler::new(registration))
         .await
         .expect("Error creating client");
 
@@ -0,0 +1,64 @@
+//! In-memory caching of FPL responses
+//!
+//! Several features fetch the same FPL data for many interactions in a row,
+//! e.g. every page turn of a standings message. [`TtlCache`] keeps recent
+//! responses for a short time so those requests are not repeated.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A size-bounded map whose entries expire after a fixed time
+///
+/// When the cache is full, expired entries are dropped, and if that does not
+/// free any room the cache is emptied. This keeps it cheap and bounded without
+/// tracking usage.
+pub struct TtlCache<K, V> {
+    ttl: Duration,
+
+    capacity: usize,
+
+    entries: Mutex<HashMap<K, (Instant, V)>>,
+}
+
+impl<K: Eq + Hash, V: Clone> TtlCache<K, V> {
+    /// Creates an empty cache
+    ///
+    /// # Arguments
+    /// * `ttl` - How long entries are returned after being inserted
+    /// * `capacity` - Entries kept before the cache is pruned
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            ttl,
+            capacity,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The value for a key, unless it is missing or expired.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .get(key)
+            .filter(|(inserted, _)| inserted.elapsed() < self.ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    /// Stores a value, pruning the cache first if it is full.
+    pub fn insert(&self, key: K, value: V) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= self.capacity {
+            entries.retain(|_, (inserted, _)| inserted.elapsed() < self.ttl);
+            if entries.len() >= self.capacity {
+                entries.clear();
+            }
+        }
+        entries.insert(key, (Instant::now(), value));
+    }
+
+    /// Drops every entry whose key does not satisfy the predicate.
+    pub fn retain(&self, mut keep: impl FnMut(&K) -> bool) {
+        self.entries.lock().unwrap().retain(|key, _| keep(key));
+    }
+}
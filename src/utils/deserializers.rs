@@ -11,3 +11,12 @@ where
         other => Err(serde::de::Error::custom(format!("expected string, got {}", other))),
     }
 }
+
+/// Deserializes `null` as the type's default value.
+pub fn de_null_as_default<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(d)?.unwrap_or_default())
+}
@@ -16,4 +16,12 @@ pub enum FplBotError {
     
     #[error("API error: {0}")]
     ApiError(String),
-}
\ No newline at end of file
+    
+    #[error("HTTP Error: 404 Not Found ({0})")]
+    NotFound(String),
+}
+
+/// Whether an error was caused by the FPL API answering 404 Not Found.
+pub fn is_not_found(error: &anyhow::Error) -> bool {
+    matches!(error.downcast_ref::<FplBotError>(), Some(FplBotError::NotFound(_)))
+}
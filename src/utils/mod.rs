@@ -1,3 +1,5 @@
+pub mod cache;
 pub mod deserializers;
 pub mod error;
+pub mod render;
 pub mod type_conversion;
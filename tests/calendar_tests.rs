@@ -0,0 +1,165 @@
+use fplbot::bot::tasks::schedule_watch::build_alert_embed;
+use fplbot::fpl::calendar::{diff_schedules, FixtureSlot, ScheduleChange, SeasonCalendar};
+use fplbot::fpl::models::fixtures::Fixture;
+use serde_json::json;
+
+fn fixture(id: u32, event: Option<u8>, team_h: i32, team_a: i32, kickoff: Option<&str>) -> Fixture {
+    serde_json::from_value(json!({
+        "id": id,
+        "code": 2_500_000 + id as u64,
+        "event": event,
+        "team_h": team_h,
+        "team_a": team_a,
+        "team_h_score": null,
+        "team_a_score": null,
+        "finished": false,
+        "finished_provisional": false,
+        "started": false,
+        "kickoff_time": kickoff,
+        "minutes": 0,
+        "team_h_difficulty": 3,
+        "team_a_difficulty": 4,
+        "stats": [],
+        "provisional_start_time": false,
+        "pulse_id": 100_000 + id as u64
+    }))
+    .unwrap()
+}
+
+fn season() -> Vec<Fixture> {
+    vec![
+        // GW1: everyone plays once
+        fixture(1, Some(1), 1, 2, Some("2025-08-16T14:00:00Z")),
+        fixture(2, Some(1), 3, 4, Some("2025-08-16T14:00:00Z")),
+        // GW2: a second 1 v 2 meeting was postponed and has no gameweek yet
+        fixture(3, Some(2), 3, 1, Some("2025-08-23T14:00:00Z")),
+        fixture(4, Some(2), 4, 2, Some("2025-08-23T14:00:00Z")),
+        fixture(5, None, 1, 2, None),
+        // GW3: teams 3 and 4 double
+        fixture(6, Some(3), 1, 3, Some("2025-08-30T14:00:00Z")),
+        fixture(7, Some(3), 2, 4, Some("2025-08-30T14:00:00Z")),
+        fixture(8, Some(3), 3, 4, Some("2025-09-02T19:00:00Z")),
+    ]
+}
+
+#[test]
+fn test_fixture_with_null_event_and_kickoff_parses() {
+    let unscheduled = fixture(5, None, 1, 2, None);
+    assert!(unscheduled.event.is_none());
+    assert!(unscheduled.kickoff_time.is_none());
+}
+
+#[test]
+fn test_blanks_and_doubles() {
+    let calendar = SeasonCalendar::new(season());
+
+    assert!(calendar.summary(1).is_regular());
+    assert_eq!(calendar.blanks(3), Vec::<i32>::new());
+    assert_eq!(calendar.doubles(3), vec![3, 4]);
+    assert_eq!(calendar.team_fixtures(3, 3).len(), 2);
+    assert_eq!(calendar.team_fixtures(1, 3).len(), 1);
+}
+
+#[test]
+fn test_postponed_fixture_is_unscheduled_not_blank() {
+    let calendar = SeasonCalendar::new(season());
+
+    let unscheduled: Vec<u32> = calendar.unscheduled().map(|f| f.id).collect();
+    assert_eq!(unscheduled, vec![5]);
+    assert_eq!(calendar.blanks(2), Vec::<i32>::new());
+
+    // GW2 has teams 1-4 playing once each via fixtures 3 and 4
+    assert!(calendar.summary(2).is_regular());
+}
+
+#[test]
+fn test_irregular_gameweeks_lists_only_blanks_and_doubles() {
+    let calendar = SeasonCalendar::new(season());
+    let irregular: Vec<i32> = calendar
+        .irregular_gameweeks()
+        .iter()
+        .map(|s| s.gameweek)
+        .collect();
+
+    // GW4-38 have no fixtures in this tiny season, so every team blanks there
+    assert_eq!(irregular.first(), Some(&3));
+    assert!(!irregular.contains(&1));
+    assert!(!irregular.contains(&2));
+}
+
+#[test]
+fn test_diff_detects_fixture_moved_into_gameweek() {
+    let before = SeasonCalendar::new(season()).slots();
+
+    let mut after_fixtures = season();
+    after_fixtures[4] = fixture(5, Some(3), 1, 2, Some("2025-09-03T19:00:00Z"));
+    let after_calendar = SeasonCalendar::new(after_fixtures);
+    let after = after_calendar.slots();
+
+    let changes = diff_schedules(&before, &after);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].current.fixture_id, 5);
+    assert_eq!(changes[0].previous.event, None);
+    assert_eq!(changes[0].current.event, Some(3));
+    assert!(changes[0].gameweek_changed());
+
+    // The move turns GW3 into a double for teams 1 and 2 as well
+    assert_eq!(after_calendar.doubles(3), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_diff_detects_kickoff_change_within_gameweek() {
+    let before = SeasonCalendar::new(season()).slots();
+
+    let mut after_fixtures = season();
+    after_fixtures[0] = fixture(1, Some(1), 1, 2, Some("2025-08-17T16:30:00Z"));
+    let after = SeasonCalendar::new(after_fixtures).slots();
+
+    let changes = diff_schedules(&before, &after);
+    assert_eq!(changes.len(), 1);
+    assert!(!changes[0].gameweek_changed());
+}
+
+#[test]
+fn test_diff_against_empty_snapshot_reports_nothing() {
+    let current = SeasonCalendar::new(season()).slots();
+    assert!(diff_schedules(&[], &current).is_empty());
+    assert!(diff_schedules(&current, &current).is_empty());
+}
+
+#[test]
+fn test_diff_treats_reused_fixture_ids_as_new_fixtures() {
+    let before = SeasonCalendar::new(season()).slots();
+
+    // next season's fixture 1 is a different match in a different gameweek
+    let mut after_fixtures = season();
+    after_fixtures[0] = fixture(1, Some(2), 4, 1, Some("2026-08-22T14:00:00Z"));
+    let after = SeasonCalendar::new(after_fixtures).slots();
+
+    assert!(diff_schedules(&before, &after).is_empty());
+}
+
+#[test]
+fn test_alert_embed_is_capped_after_mass_rescheduling() {
+    let slot = |event: u8| FixtureSlot {
+        fixture_id: u32::from(event),
+        team_h: 1,
+        team_a: 2,
+        event: Some(event),
+        kickoff_time: None,
+    };
+    let changes: Vec<ScheduleChange> = (1..=30)
+        .map(|gw| ScheduleChange {
+            previous: slot(gw),
+            current: slot(gw + 1),
+        })
+        .collect();
+
+    let embed = build_alert_embed(&changes, &SeasonCalendar::new(season()));
+    let json = serde_json::to_value(embed).unwrap();
+    let description = json["description"].as_str().unwrap();
+    assert_eq!(description.matches("**ARS v AVL**").count(), 15);
+    assert!(description.contains("…and 15 more"));
+    assert!(description.contains("first 12 of 31 affected gameweeks"));
+    assert_eq!(json["fields"].as_array().unwrap().len(), 12);
+}
@@ -0,0 +1,47 @@
+use fplbot::fpl::captaincy::{leaderboard, top_candidates, CaptainCandidate, PollResult};
+
+fn candidate(element: i32, ownership: f64, difficulties: &[u8]) -> CaptainCandidate {
+    CaptainCandidate {
+        element,
+        ownership,
+        difficulties: difficulties.to_vec(),
+    }
+}
+
+#[test]
+fn test_candidates_weigh_ownership_by_fixture_ease() {
+    let candidates = vec![
+        candidate(1, 60.0, &[5]),
+        candidate(2, 30.0, &[2]),
+        candidate(3, 20.0, &[2, 3]),
+        candidate(4, 80.0, &[]),
+    ];
+
+    let top: Vec<i32> = top_candidates(candidates, 3)
+        .iter()
+        .map(|c| c.element)
+        .collect();
+    // a blank never makes the poll, and easy fixtures outweigh ownership
+    assert_eq!(top, vec![3, 2, 1]);
+}
+
+#[test]
+fn test_leaderboard_ranks_best_picks_then_points() {
+    let results = vec![
+        PollResult {
+            options: vec![(10, 12), (20, 12), (30, 2)],
+            votes: vec![(1, 10), (2, 20), (3, 30)],
+        },
+        PollResult {
+            options: vec![(10, 3), (20, 8)],
+            votes: vec![(1, 10), (2, 20), (4, 20)],
+        },
+    ];
+
+    let board = leaderboard(&results);
+    let order: Vec<(u64, usize, i32)> = board
+        .iter()
+        .map(|e| (e.user_id, e.best_picks, e.points))
+        .collect();
+    assert_eq!(order, vec![(2, 2, 20), (1, 1, 15), (4, 1, 8), (3, 0, 2)]);
+}
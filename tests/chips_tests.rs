@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use fplbot::fpl::chips::{chip_return, remaining_chips, Chip, PlayedChip, SeasonHalf};
+use fplbot::fpl::models::picks::ManagerPicks;
+use serde_json::json;
+
+fn played(chip: Chip, gameweek: i32) -> PlayedChip {
+    PlayedChip { chip, gameweek }
+}
+
+#[test]
+fn test_first_half_chips_expire_at_the_split() {
+    let used = vec![played(Chip::Wildcard, 4), played(Chip::BenchBoost, 22)];
+
+    let early = remaining_chips(&used, 10);
+    assert!(!early.contains(&(Chip::Wildcard, SeasonHalf::First)));
+    assert!(early.contains(&(Chip::FreeHit, SeasonHalf::First)));
+    assert!(early.contains(&(Chip::Wildcard, SeasonHalf::Second)));
+    assert_eq!(early.len(), 6);
+
+    // unused first-half chips are lost once gameweek 20 is next
+    let late = remaining_chips(&used, 25);
+    assert_eq!(
+        late,
+        vec![
+            (Chip::Wildcard, SeasonHalf::Second),
+            (Chip::FreeHit, SeasonHalf::Second),
+            (Chip::TripleCaptain, SeasonHalf::Second),
+        ]
+    );
+}
+
+#[test]
+fn test_chip_returns_count_bench_and_tripled_captain() {
+    let picks = |chip: &str, captain_multiplier: i32| -> ManagerPicks {
+        let squad: Vec<_> = (1..=15)
+            .map(|position| {
+                json!({
+                    "element": position * 10,
+                    "position": position,
+                    "multiplier": if position == 3 { captain_multiplier } else { 1 },
+                    "is_captain": position == 3,
+                    "is_vice_captain": position == 4
+                })
+            })
+            .collect();
+        serde_json::from_value(json!({
+            "active_chip": chip,
+            "entry_history": {
+                "event": 8, "points": 80, "total_points": 500, "rank": null,
+                "overall_rank": null, "bank": 0, "value": 1000, "event_transfers": 1,
+                "event_transfers_cost": 4, "points_on_bench": 9
+            },
+            "picks": squad
+        }))
+        .unwrap()
+    };
+    let points: HashMap<i32, i32> = (1..=15).map(|p| (p * 10, p)).collect();
+
+    let bench_boost = chip_return(&picks("bboost", 2), &points).unwrap();
+    assert_eq!(bench_boost.chip, Chip::BenchBoost);
+    assert_eq!(bench_boost.chip_points, Some(12 + 13 + 14 + 15));
+    assert_eq!(bench_boost.gameweek_points, 76);
+
+    let triple = chip_return(&picks("3xc", 3), &points).unwrap();
+    assert_eq!(triple.captain, Some(30));
+    assert_eq!(triple.chip_points, Some(3));
+
+    assert!(chip_return(&picks("manager", 2), &points).is_none());
+}
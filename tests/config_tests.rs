@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use fplbot::bot::sync::RegistrationMode;
+use fplbot::config::Config;
+use serenity::all::GuildId;
+
+fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: HashMap<String, String> = vars
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    move |key| vars.get(key).cloned()
+}
+
+#[test]
+fn test_example_config_matches_defaults() {
+    let example = Config::from_toml(include_str!("../config.example.toml")).unwrap();
+    let defaults = Config::default();
+    assert_eq!(example.database.url, defaults.database.url);
+    assert_eq!(example.fpl.base_url, defaults.fpl.base_url);
+    assert_eq!(example.fpl.retries, defaults.fpl.retries);
+    assert_eq!(
+        example.polling.schedule_watch_secs,
+        defaults.polling.schedule_watch_secs
+    );
+    assert_eq!(
+        example.polling.captain_scoring_secs,
+        defaults.polling.captain_scoring_secs
+    );
+    assert_eq!(
+        example.polling.prediction_scoring_secs,
+        defaults.polling.prediction_scoring_secs
+    );
+    assert_eq!(example.logging.format, defaults.logging.format);
+}
+
+#[test]
+fn test_env_overrides_file() {
+    let mut config = Config::from_toml(
+        r#"
+        [discord]
+        token = "from-file"
+        guild_ids = [1]
+
+        [fpl]
+        timeout_secs = 10
+        "#,
+    )
+    .unwrap();
+    config
+        .apply_env(env(&[
+            ("BOT_TOKEN", "from-env"),
+            ("COMMAND_GUILD_IDS", "10, 20"),
+            ("FPLBOT_FPL_RETRIES", "4"),
+        ]))
+        .unwrap();
+
+    assert_eq!(config.discord.token, "from-env");
+    assert_eq!(config.fpl.timeout_secs, 10);
+    assert_eq!(config.fpl.retries, 4);
+    assert_eq!(
+        config.discord.registration_mode().unwrap(),
+        RegistrationMode::Guilds(vec![GuildId::new(10), GuildId::new(20)])
+    );
+    config.validate().unwrap();
+
+    config.apply_env(env(&[("BOT_ENV", "production")])).unwrap();
+    assert_eq!(
+        config.discord.registration_mode().unwrap(),
+        RegistrationMode::Global
+    );
+}
+
+#[test]
+fn test_invalid_config_is_rejected() {
+    assert!(Config::from_toml("[fpl]\ntimeout = 5").is_err());
+    assert!(Config::from_toml("[fpl]\nretries = \"many\"").is_err());
+    assert!(Config::default()
+        .apply_env(env(&[("FPLBOT_FPL_RETRIES", "lots")]))
+        .is_err());
+
+    let mut config = Config::from_toml(
+        r#"
+        [fpl]
+        base_url = "ftp://example.com/"
+
+        [logging]
+        format = "xml"
+        "#,
+    )
+    .unwrap();
+    config.discord.token = "token".to_string();
+    let message = config.validate().unwrap_err().to_string();
+    assert!(
+        message.contains("fpl.base_url must be an http(s) URL"),
+        "{}",
+        message
+    );
+    assert!(message.contains("must not end with '/'"), "{}", message);
+    assert!(message.contains("logging.format"), "{}", message);
+    assert!(!message.contains("discord.token"), "{}", message);
+}
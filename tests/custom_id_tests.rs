@@ -0,0 +1,66 @@
+use fplbot::bot::custom_id::{ComponentId, MAX_CUSTOM_ID_LENGTH};
+
+#[test]
+fn test_custom_id_round_trips_state() {
+    let id = ComponentId::new("standings", 2, "next")
+        .league(314)
+        .page(3)
+        .sort("gameweek")
+        .gameweek(38);
+
+    let encoded = id.encode().unwrap();
+    assert_eq!(encoded, "standings:2:next:l=314,p=3,s=gameweek,g=38");
+    assert!(encoded.len() <= MAX_CUSTOM_ID_LENGTH);
+
+    let parsed = ComponentId::parse(&encoded, "standings", 2).unwrap();
+    assert_eq!(parsed, id);
+    assert_eq!(parsed.league_id().unwrap(), 314);
+
+    let vote = ComponentId::new("captain_poll", 1, "vote")
+        .record(7)
+        .player(355);
+    let encoded = vote.encode().unwrap();
+    assert_eq!(encoded, "captain_poll:1:vote:e=355,r=7");
+    assert_eq!(ComponentId::decode(&encoded).unwrap(), vote);
+
+    let confirm = ComponentId::new("managers", 2, "confirm")
+        .user(42)
+        .requester(7)
+        .manager(1234);
+    let encoded = confirm.encode().unwrap();
+    assert_eq!(encoded, "managers:2:confirm:m=1234,u=42,q=7");
+    assert_eq!(
+        ComponentId::parse(&encoded, "managers", 2)
+            .unwrap()
+            .state
+            .requester_id,
+        Some(7)
+    );
+}
+
+#[test]
+fn test_stale_and_foreign_custom_ids_are_rejected() {
+    let encoded = ComponentId::new("standings", 1, "next")
+        .league(314)
+        .encode()
+        .unwrap();
+
+    for custom_id in [encoded.as_str(), "standings_next_0", "standings:2:next:x=1"] {
+        let error = ComponentId::parse(custom_id, "standings", 2).unwrap_err();
+        assert!(error.to_string().contains("Run /standings again"));
+    }
+    assert!(ComponentId::parse(&encoded, "match", 1).is_err());
+}
+
+#[test]
+fn test_invalid_custom_ids_are_not_encoded() {
+    assert!(ComponentId::new("standings", 1, "next")
+        .sort("has,comma")
+        .encode()
+        .is_err());
+    assert!(
+        ComponentId::new(&"a".repeat(MAX_CUSTOM_ID_LENGTH), 1, "next")
+            .encode()
+            .is_err()
+    );
+}
@@ -0,0 +1,87 @@
+use fplbot::bot::commands::fdr::{build_ticker, TeamTicker, TickerFixture};
+use fplbot::fpl::calendar::SeasonCalendar;
+use fplbot::fpl::models::fixtures::Fixture;
+use serde_json::json;
+
+fn fixture(id: u32, event: u8, team_h: i32, team_a: i32, difficulty: (u8, u8)) -> Fixture {
+    serde_json::from_value(json!({
+        "id": id,
+        "code": 2_500_000 + id as u64,
+        "event": event,
+        "team_h": team_h,
+        "team_a": team_a,
+        "team_h_score": null,
+        "team_a_score": null,
+        "finished": false,
+        "finished_provisional": false,
+        "started": false,
+        "kickoff_time": "2025-08-16T14:00:00Z",
+        "minutes": 0,
+        "team_h_difficulty": difficulty.0,
+        "team_a_difficulty": difficulty.1,
+        "stats": [],
+        "provisional_start_time": false,
+        "pulse_id": 100_000 + id as u64
+    }))
+    .unwrap()
+}
+
+fn played(difficulty: u8) -> TickerFixture {
+    TickerFixture {
+        opponent: 1,
+        is_home: true,
+        difficulty,
+    }
+}
+
+#[test]
+fn test_average_difficulty_counts_blanks_and_doubles() {
+    let regular = TeamTicker {
+        team_id: 1,
+        gameweeks: vec![vec![played(2)], vec![played(4)]],
+    };
+    assert_eq!(regular.average_difficulty(), 3.0);
+
+    // a blank scores 5, and both games of a double count
+    let irregular = TeamTicker {
+        team_id: 2,
+        gameweeks: vec![vec![], vec![played(2), played(3)]],
+    };
+    assert!((irregular.average_difficulty() - 10.0 / 3.0).abs() < 1e-9);
+
+    let empty = TeamTicker {
+        team_id: 3,
+        gameweeks: vec![],
+    };
+    assert_eq!(empty.average_difficulty(), 0.0);
+}
+
+#[test]
+fn test_build_ticker_sorts_easiest_run_first() {
+    let calendar = SeasonCalendar::new(vec![
+        fixture(1, 1, 1, 2, (2, 5)),
+        fixture(2, 1, 3, 4, (3, 3)),
+        fixture(3, 2, 2, 1, (4, 2)),
+        // teams 3 and 4 double in GW2
+        fixture(4, 2, 3, 4, (3, 3)),
+        fixture(5, 2, 4, 3, (2, 2)),
+        fixture(6, 3, 1, 3, (5, 5)),
+    ]);
+
+    let ticker = build_ticker(&calendar, 1, 2);
+    let order: Vec<i32> = ticker.iter().map(|t| t.team_id).collect();
+    assert_eq!(order, vec![1, 3, 4, 2]);
+
+    let first = &ticker[0];
+    assert_eq!(first.gameweeks.len(), 2);
+    assert_eq!(first.gameweeks[0][0].opponent, 2);
+    assert!(first.gameweeks[0][0].is_home);
+    assert!(!first.gameweeks[1][0].is_home);
+    assert_eq!(first.gameweeks[1][0].difficulty, 2);
+    assert_eq!(ticker[1].gameweeks[1].len(), 2);
+
+    // the window only covers the gameweeks asked for
+    let later = build_ticker(&calendar, 3, 1);
+    let team_2 = later.iter().find(|t| t.team_id == 2).unwrap();
+    assert!(team_2.gameweeks[0].is_empty());
+}
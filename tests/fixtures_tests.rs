@@ -0,0 +1,115 @@
+use fplbot::bot::commands::fixtures::{build_fixtures_embed, build_team_fixtures_embed};
+use fplbot::fpl::models::fixtures::{Fixture, GameweekFixtures};
+use serde_json::{json, Value};
+
+/// A fixture between two teams; `score` and `minutes` mark it as started
+fn fixture(
+    id: u32,
+    event: Option<u8>,
+    teams: (i32, i32),
+    score: Option<(u8, u8)>,
+    minutes: u16,
+    finished: bool,
+) -> Fixture {
+    serde_json::from_value(json!({
+        "id": id,
+        "code": 2_500_000 + id as u64,
+        "event": event,
+        "team_h": teams.0,
+        "team_a": teams.1,
+        "team_h_score": score.map(|s| s.0),
+        "team_a_score": score.map(|s| s.1),
+        "finished": finished,
+        "finished_provisional": finished,
+        "started": score.is_some(),
+        "kickoff_time": event.map(|_| "2025-08-16T14:00:00Z"),
+        "minutes": minutes,
+        "team_h_difficulty": 3,
+        "team_a_difficulty": 3,
+        "stats": [],
+        "provisional_start_time": false,
+        "pulse_id": 100_000 + id as u64
+    }))
+    .unwrap()
+}
+
+fn fields(json: &Value) -> Vec<(String, String)> {
+    json["fields"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| {
+            (
+                f["name"].as_str().unwrap().to_string(),
+                f["value"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_gameweek_fixtures_show_scores_and_status() {
+    let gameweek = GameweekFixtures {
+        gameweek: 3,
+        fixtures: vec![
+            fixture(1, Some(3), (1, 2), Some((2, 1)), 90, true),
+            fixture(2, Some(3), (3, 4), Some((0, 0)), 67, false),
+            fixture(3, Some(3), (5, 6), None, 0, false),
+        ],
+    };
+
+    let json = serde_json::to_value(build_fixtures_embed(&gameweek, None)).unwrap();
+    assert_eq!(json["title"], "Gameweek 3");
+    assert_eq!(
+        fields(&json),
+        vec![
+            ("Arsenal 2 - 1 Aston Villa".to_string(), "FT".to_string()),
+            (
+                "Burnley 0 - 0 Bournemouth".to_string(),
+                "🔴 67'".to_string()
+            ),
+            (
+                "Brentford v Brighton".to_string(),
+                "16.08 14:00".to_string()
+            ),
+        ]
+    );
+
+    let empty = GameweekFixtures {
+        gameweek: 4,
+        fixtures: vec![],
+    };
+    let json = serde_json::to_value(build_fixtures_embed(&empty, None)).unwrap();
+    assert_eq!(json["description"], "No fixtures scheduled.");
+}
+
+#[test]
+fn test_team_fixtures_are_ordered_by_gameweek_from_the_clubs_side() {
+    let fixtures = vec![
+        fixture(4, None, (2, 1), None, 0, false),
+        fixture(3, Some(3), (1, 3), Some((1, 1)), 30, false),
+        fixture(1, Some(1), (1, 2), Some((2, 1)), 90, true),
+        fixture(2, Some(2), (4, 1), Some((3, 0)), 90, true),
+        fixture(5, Some(4), (1, 5), None, 0, false),
+    ];
+
+    let json = serde_json::to_value(build_team_fixtures_embed(1, &fixtures)).unwrap();
+    assert_eq!(json["title"], "Arsenal fixtures");
+    let lines: Vec<&str> = json["description"]
+        .as_str()
+        .unwrap()
+        .trim_matches('`')
+        .lines()
+        .skip(1)
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            "GW1   H AVL W 2-1",
+            "GW2   A BOU L 0-3",
+            "GW3   H BUR D 1-1 (live)",
+            "GW4   H BRE 16.08 14:00",
+            "TBC   A AVL TBC",
+        ]
+    );
+}
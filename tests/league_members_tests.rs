@@ -0,0 +1,56 @@
+use fplbot::bot::commands::league_members::partition_members;
+use fplbot::database::models::DBUser;
+use fplbot::fpl::models::league::StandingsManager;
+use serde_json::json;
+
+fn entry(manager_id: i32, team_name: &str) -> StandingsManager {
+    serde_json::from_value(json!({
+        "id": manager_id * 10,
+        "event_total": 50,
+        "player_name": "Sam Jones",
+        "rank": 1,
+        "last_rank": 1,
+        "rank_sort": 1,
+        "total": 500,
+        "entry": manager_id,
+        "entry_name": team_name,
+        "has_played": true
+    }))
+    .unwrap()
+}
+
+fn user(discord_id: i64, manager_id: Option<i32>) -> DBUser {
+    DBUser {
+        discord_id,
+        manager_id,
+        team_name: None,
+        manager_name: None,
+    }
+}
+
+#[test]
+fn test_partition_members_keeps_standings_order() {
+    let entries = vec![entry(1, "First"), entry(2, "Second"), entry(3, "Third")];
+    let users = vec![user(300, Some(3)), user(100, Some(1))];
+
+    let (linked, unlinked) = partition_members(&entries, &users);
+
+    let linked: Vec<(&str, i64)> = linked
+        .iter()
+        .map(|(e, id)| (e.team_name.as_str(), *id))
+        .collect();
+    assert_eq!(linked, vec![("First", 100), ("Third", 300)]);
+    assert_eq!(unlinked.len(), 1);
+    assert_eq!(unlinked[0].manager_id, 2);
+}
+
+#[test]
+fn test_partition_members_ignores_users_without_manager() {
+    let entries = vec![entry(7, "Lucky Seven")];
+    let users = vec![user(1, None), user(2, Some(99))];
+
+    let (linked, unlinked) = partition_members(&entries, &users);
+
+    assert!(linked.is_empty());
+    assert_eq!(unlinked.len(), 1);
+}
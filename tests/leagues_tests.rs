@@ -0,0 +1,32 @@
+use fplbot::bot::leagues::choose_league;
+use fplbot::database::models::DBChannelLeague;
+
+fn league(name: &str, league_id: i32, is_primary: bool) -> DBChannelLeague {
+    DBChannelLeague {
+        channel_id: 1,
+        name: name.to_string(),
+        league_id,
+        is_primary,
+    }
+}
+
+#[test]
+fn test_primary_then_first_then_guild_default() {
+    let leagues = vec![league("cup", 20, false), league("main", 10, true)];
+    assert_eq!(choose_league(&leagues, Some(99), None).unwrap(), 10);
+
+    let no_primary = vec![league("cup", 20, false), league("h2h", 30, false)];
+    assert_eq!(choose_league(&no_primary, Some(99), None).unwrap(), 20);
+
+    assert_eq!(choose_league(&[], Some(99), None).unwrap(), 99);
+    assert!(choose_league(&[], None, None).is_err());
+}
+
+#[test]
+fn test_named_league_is_matched_case_insensitively() {
+    let leagues = vec![league("main", 10, true), league("Cup", 20, false)];
+    assert_eq!(choose_league(&leagues, None, Some("cup")).unwrap(), 20);
+
+    let error = choose_league(&leagues, Some(99), Some("h2h")).unwrap_err();
+    assert!(error.to_string().contains("main, Cup"), "{}", error);
+}
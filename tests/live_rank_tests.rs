@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use fplbot::fpl::live_rank::{
+    live_points, normal_cdf, normal_quantile, RankEstimator, ScoreDistribution, StartingPosition,
+    DEFAULT_GAMEWEEK_DEVIATION,
+};
+use fplbot::fpl::models::general::Event;
+use fplbot::fpl::models::picks::{EntryHistory, ManagerPicks};
+use serde_json::json;
+
+const PLAYERS: i64 = 10_000_000;
+
+fn event(id: i32, average_entry_score: i32, highest_score: Option<i32>) -> Event {
+    Event {
+        id,
+        name: format!("Gameweek {}", id),
+        deadline_time: Utc::now(),
+        average_entry_score,
+        highest_score,
+        finished: highest_score.is_some(),
+        data_checked: highest_score.is_some(),
+        is_previous: false,
+        is_current: false,
+        is_next: false,
+    }
+}
+
+fn gameweek(event: i32, total_points: i32, overall_rank: Option<i32>) -> EntryHistory {
+    EntryHistory {
+        event,
+        points: 50,
+        total_points,
+        rank: None,
+        overall_rank,
+        bank: 0,
+        value: 1000,
+        event_transfers: 0,
+        event_transfers_cost: 0,
+        points_on_bench: 0,
+    }
+}
+
+#[test]
+fn test_normal_distribution_and_gameweek_spread() {
+    assert!((normal_cdf(0.0) - 0.5).abs() < 1e-7);
+    assert!((normal_cdf(1.96) - 0.975).abs() < 1e-4);
+    assert!((normal_cdf(-1.0) + normal_cdf(1.0) - 1.0).abs() < 1e-7);
+    for p in [1e-7, 0.01, 0.3, 0.5, 0.9, 0.999] {
+        assert!((normal_cdf(normal_quantile(p)) - p).abs() < 2e-7);
+    }
+
+    // the best of ten million managers sits about 5.2 deviations above the mean
+    let spread = ScoreDistribution::from_event(&event(1, 50, Some(130)), PLAYERS).unwrap();
+    assert_eq!(spread.mean, 50.0);
+    assert!((spread.deviation - 80.0 / 5.199).abs() < 0.05);
+    let no_highest = ScoreDistribution::from_event(&event(2, 50, None), PLAYERS).unwrap();
+    assert_eq!(no_highest.deviation, DEFAULT_GAMEWEEK_DEVIATION);
+    assert!(ScoreDistribution::from_event(&event(3, 0, None), PLAYERS).is_none());
+}
+
+#[test]
+fn test_live_rank_moves_with_live_points() {
+    let history = [
+        gameweek(1, 70, Some(2_000_000)),
+        gameweek(2, 140, Some(400_000)),
+        gameweek(3, 200, Some(100_000)),
+        gameweek(4, 230, None),
+    ];
+    let start = StartingPosition::before(&history, 4);
+    assert_eq!(
+        start,
+        Some(StartingPosition {
+            total: 200,
+            rank: 100_000
+        })
+    );
+    assert_eq!(StartingPosition::before(&history, 1), None);
+
+    let mut events: Vec<Event> = (1..=3).map(|id| event(id, 50, Some(130))).collect();
+    events.push(event(4, 30, None));
+    let estimator = RankEstimator::new(&events, 4, PLAYERS);
+    let good = estimator.estimate(start, 60);
+    let bad = estimator.estimate(start, 10);
+    assert!(good < 100_000 && bad > 100_000);
+    assert!(estimator.estimate(start, 90) < good);
+
+    // a first gameweek is ranked on the gameweek alone
+    let first = estimator.estimate(None, 30);
+    assert!((first - PLAYERS / 2).abs() < PLAYERS / 100);
+
+    // before the live gameweek has an average the rank stands still
+    events[3] = event(4, 0, None);
+    let waiting = RankEstimator::new(&events, 4, PLAYERS);
+    assert_eq!(waiting.estimate(start, 12), 100_000);
+}
+
+#[test]
+fn test_live_points_follow_multipliers_and_hits() {
+    let picks = |chip: Option<&str>| -> ManagerPicks {
+        let bench_multiplier = if chip == Some("bboost") { 1 } else { 0 };
+        let squad: Vec<_> = (1..=15)
+            .map(|position| {
+                json!({
+                    "element": position * 10,
+                    "position": position,
+                    "multiplier": match position {
+                        3 => 2,
+                        12..=15 => bench_multiplier,
+                        _ => 1,
+                    },
+                    "is_captain": position == 3,
+                    "is_vice_captain": position == 4
+                })
+            })
+            .collect();
+        serde_json::from_value(json!({
+            "active_chip": chip,
+            "entry_history": {
+                "event": 8, "points": 0, "total_points": 500, "rank": null,
+                "overall_rank": null, "bank": 0, "value": 1000, "event_transfers": 1,
+                "event_transfers_cost": 4, "points_on_bench": 0
+            },
+            "picks": squad
+        }))
+        .unwrap()
+    };
+    // element 50 has no live data yet
+    let points: HashMap<i32, i32> = (1..=15).filter(|p| *p != 5).map(|p| (p * 10, p)).collect();
+
+    let starters = (1..=11).sum::<i32>() - 5 + 3;
+    assert_eq!(live_points(&picks(None), &points), starters - 4);
+    assert_eq!(
+        live_points(&picks(Some("bboost")), &points),
+        starters + 12 + 13 + 14 + 15 - 4
+    );
+}
@@ -0,0 +1,51 @@
+use fplbot::fpl::models::manager::Manager;
+use fplbot::utils::error::{is_not_found, FplBotError};
+use serde_json::json;
+
+#[test]
+fn test_new_manager_summary_deserializes() {
+    // a team created before the first deadline: no points or league ranks yet
+    let manager: Manager = serde_json::from_value(json!({
+        "id": 42,
+        "name": "Klopp Dogg",
+        "player_first_name": "Sam",
+        "player_last_name": "Jones",
+        "summary_overall_points": null,
+        "summary_event_points": null,
+        "leagues": {
+            "classic": [{
+                "id": 314,
+                "name": "Overall",
+                "created": "2025-07-01T10:00:00Z",
+                "closed": false,
+                "admin_entry": null,
+                "start_event": 1,
+                "entry_can_leave": false,
+                "entry_can_admin": false,
+                "entry_can_invite": false,
+                "rank_count": null,
+                "entry_percentile_rank": null,
+                "entry_rank": null,
+                "entry_last_rank": null
+            }],
+            "h2h": []
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(manager.team_name, "Klopp Dogg");
+    assert_eq!(manager.full_name(), "Sam Jones");
+    assert_eq!(manager.total_points, 0);
+    assert_eq!(
+        manager.get_league_ids().collect::<Vec<_>>(),
+        vec![(314, "Overall")]
+    );
+}
+
+#[test]
+fn test_not_found_errors_are_recognised() {
+    let not_found = anyhow::Error::from(FplBotError::NotFound("entry/1/".to_string()));
+    assert!(is_not_found(&not_found));
+    assert!(not_found.to_string().contains("HTTP Error"));
+    assert!(!is_not_found(&anyhow::anyhow!("HTTP Error: 500")));
+}
@@ -0,0 +1,94 @@
+use fplbot::bot::commands::match_report::build_match_embed;
+use fplbot::fpl::models::fixtures::Fixture;
+use fplbot::fpl::models::general::GeneralData;
+use serde_json::{json, Value};
+
+fn general() -> GeneralData {
+    GeneralData {
+        events: vec![],
+        teams: vec![],
+        elements: vec![],
+        total_players: 0,
+    }
+}
+
+fn fixture(started: bool, finished: bool, stats: Value) -> Fixture {
+    serde_json::from_value(json!({
+        "id": 42,
+        "code": 2_500_042,
+        "event": 7,
+        "team_h": 1,
+        "team_a": 2,
+        "team_h_score": if started { json!(2) } else { Value::Null },
+        "team_a_score": if started { json!(1) } else { Value::Null },
+        "finished": finished,
+        "finished_provisional": finished,
+        "started": started,
+        "kickoff_time": "2025-10-04T14:00:00Z",
+        "minutes": if started { 55 } else { 0 },
+        "team_h_difficulty": 3,
+        "team_a_difficulty": 3,
+        "stats": stats,
+        "provisional_start_time": false,
+        "pulse_id": 100_042
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_report_header_follows_match_status() {
+    let upcoming = serde_json::to_value(build_match_embed(
+        &fixture(false, false, json!([])),
+        None,
+        &general(),
+    ))
+    .unwrap();
+    assert_eq!(upcoming["title"], "Arsenal v Aston Villa");
+    assert_eq!(upcoming["description"], "Kickoff 04.10 14:00");
+    assert_eq!(upcoming["footer"]["text"], "Fixture ID: 42 • GW7");
+
+    let live = serde_json::to_value(build_match_embed(
+        &fixture(true, false, json!([])),
+        None,
+        &general(),
+    ))
+    .unwrap();
+    assert_eq!(live["title"], "Arsenal 2 - 1 Aston Villa");
+    assert_eq!(live["description"], "🔴 Live · 55'");
+
+    let finished = serde_json::to_value(build_match_embed(
+        &fixture(true, true, json!([])),
+        None,
+        &general(),
+    ))
+    .unwrap();
+    assert_eq!(finished["description"], "Full time");
+}
+
+#[test]
+fn test_report_lists_stats_by_side_and_top_bps_last() {
+    let stats = json!([
+        {
+            "identifier": "goals_scored",
+            "h": [{"value": 2, "element": 10}],
+            "a": [{"value": 1, "element": 20}]
+        },
+        {"identifier": "red_cards", "h": [], "a": []},
+        {"identifier": "clean_sheets", "h": [], "a": [{"value": 1, "element": 21}]},
+        {
+            "identifier": "bps",
+            "h": [{"value": 30, "element": 10}, {"value": 12, "element": 11}],
+            "a": [{"value": 25, "element": 20}]
+        }
+    ]);
+    let embed = build_match_embed(&fixture(true, true, stats), None, &general());
+    let json = serde_json::to_value(embed).unwrap();
+    let fields = json["fields"].as_array().unwrap();
+
+    // empty stats are left out, unknown ones get a readable label
+    let names: Vec<&str> = fields.iter().map(|f| f["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["⚽ Goals", "Clean sheets", "📊 BPS"]);
+    assert_eq!(fields[0]["value"], "**ARS:** Unknown (2)\n**AVL:** Unknown");
+    assert_eq!(fields[1]["value"], "**AVL:** Unknown");
+    assert_eq!(fields[2]["value"], "Unknown 30\nUnknown 25\nUnknown 12");
+}
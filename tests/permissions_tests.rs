@@ -0,0 +1,34 @@
+use fplbot::bot::permissions::{is_allowed, Access};
+use serenity::all::{Permissions, RoleId};
+
+#[test]
+fn test_access_levels() {
+    let admin_roles = [RoleId::new(7)];
+    let nobody = Permissions::empty();
+
+    assert!(is_allowed(Access::Everyone, nobody, &[], &admin_roles));
+    assert!(!is_allowed(Access::Managers, nobody, &[], &admin_roles));
+
+    let manager = Permissions::MANAGE_CHANNELS;
+    assert!(is_allowed(Access::Managers, manager, &[], &admin_roles));
+    assert!(!is_allowed(Access::Admins, manager, &[], &admin_roles));
+
+    // a configured admin role is enough for every level
+    let roles = [RoleId::new(3), RoleId::new(7)];
+    assert!(is_allowed(Access::Admins, nobody, &roles, &admin_roles));
+    assert!(is_allowed(Access::Managers, nobody, &roles, &admin_roles));
+    assert!(is_allowed(
+        Access::Admins,
+        Permissions::MANAGE_GUILD,
+        &[],
+        &[]
+    ));
+}
+
+#[test]
+fn test_access_round_trips_through_its_name() {
+    for access in Access::ALL {
+        assert_eq!(access.as_str().parse::<Access>().unwrap(), access);
+    }
+    assert!("moderators".parse::<Access>().is_err());
+}
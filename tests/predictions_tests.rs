@@ -0,0 +1,41 @@
+use chrono::{TimeZone, Utc};
+use fplbot::fpl::calendar::season_name;
+use fplbot::fpl::predictions::{
+    score_prediction, standings, CORRECT_RESULT_POINTS, EXACT_SCORE_POINTS,
+};
+
+#[test]
+fn test_predictions_score_exact_scores_and_results() {
+    assert_eq!(score_prediction((2, 1), (2, 1)), EXACT_SCORE_POINTS);
+    assert_eq!(score_prediction((3, 0), (1, 0)), CORRECT_RESULT_POINTS);
+    assert_eq!(score_prediction((0, 0), (2, 2)), CORRECT_RESULT_POINTS);
+    assert_eq!(score_prediction((1, 2), (2, 1)), 0);
+    assert_eq!(score_prediction((1, 1), (0, 1)), 0);
+
+    // a season runs from July to June
+    let august = Utc.with_ymd_and_hms(2026, 8, 15, 14, 0, 0).unwrap();
+    let may = Utc.with_ymd_and_hms(2027, 5, 23, 15, 0, 0).unwrap();
+    assert_eq!(season_name(august), "2026/27");
+    assert_eq!(season_name(may), "2026/27");
+}
+
+#[test]
+fn test_prediction_standings_break_ties_on_exact_scores() {
+    let scored = vec![
+        (1, 3),
+        (1, 0),
+        (2, 1),
+        (2, 1),
+        (2, 1),
+        (3, 3),
+        (3, 0),
+        (3, 0),
+    ];
+
+    let table: Vec<(u64, i32, usize, usize)> = standings(&scored)
+        .iter()
+        .map(|s| (s.user_id, s.points, s.exact_scores, s.correct_results))
+        .collect();
+    // 1 and 3 both have one exact score, so 1 leads for predicting less
+    assert_eq!(table, vec![(1, 3, 1, 0), (3, 3, 1, 0), (2, 3, 0, 3)]);
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use fplbot::fpl::models::fixtures::Fixture;
+use fplbot::fpl::models::general::Team;
+use fplbot::fpl::projection::{
+    backtest, FixtureOutlook, MinutesOutlook, PlayerOutlook, PlayerRole, StatLine,
+};
+use fplbot::fpl::squad::Position;
+use serde_json::json;
+
+fn team(id: i32, attack: i32, defence: i32) -> Team {
+    Team {
+        id,
+        name: format!("Team {}", id),
+        short_name: format!("T{}", id),
+        strength: 3,
+        strength_overall_home: attack + defence,
+        strength_overall_away: attack + defence,
+        strength_attack_home: attack,
+        strength_attack_away: attack,
+        strength_defence_home: defence,
+        strength_defence_away: defence,
+    }
+}
+
+fn fixture(id: u32, event: u8, team_h: i32, team_a: i32, difficulty: (u8, u8)) -> Fixture {
+    serde_json::from_value(json!({
+        "id": id,
+        "code": 2_500_000 + id as u64,
+        "event": event,
+        "team_h": team_h,
+        "team_a": team_a,
+        "team_h_score": null,
+        "team_a_score": null,
+        "finished": false,
+        "finished_provisional": false,
+        "started": false,
+        "kickoff_time": null,
+        "minutes": 0,
+        "team_h_difficulty": difficulty.0,
+        "team_a_difficulty": difficulty.1,
+        "stats": [],
+        "provisional_start_time": false,
+        "pulse_id": 100_000 + id as u64
+    }))
+    .unwrap()
+}
+
+fn line(gameweek: i32, element: i32, minutes: i32, xg: f64, total_points: i32) -> StatLine {
+    StatLine {
+        gameweek,
+        element,
+        minutes,
+        starts: i32::from(minutes >= 60),
+        expected_goals: xg,
+        expected_assists: 0.0,
+        saves: 0,
+        total_points,
+    }
+}
+
+#[test]
+fn test_projection_follows_minutes_fixtures_and_position() {
+    // a nailed starter always lasts 60, a bench player only comes on
+    let starter = MinutesOutlook::from_history(900, 10, 10, 1.0);
+    assert_eq!(starter.sixty, 1.0);
+    assert_eq!(starter.appearance, 1.0);
+    let bench = MinutesOutlook::from_history(200, 0, 10, 1.0);
+    assert_eq!(bench.sixty, 0.0);
+    assert_eq!(bench.appearance, 1.0);
+    assert_eq!(
+        MinutesOutlook::from_history(900, 10, 10, 0.0).appearance,
+        0.0
+    );
+
+    // a strong defence at home to a weak attack keeps more clean sheets
+    let strong = team(1, 1300, 1300);
+    let weak = team(2, 1000, 1000);
+    let easy = FixtureOutlook::new(&strong, &weak, true, 2);
+    let hard = FixtureOutlook::new(&weak, &strong, false, 5);
+    assert!(easy.clean_sheet_probability() > hard.clean_sheet_probability());
+    assert!(easy.attack > 1.0 && hard.attack < 1.0);
+    assert!(hard.expected_conceded_blocks() > easy.expected_conceded_blocks());
+
+    // defenders earn more from a clean sheet than midfielders with no xG
+    let outlook = |position| PlayerOutlook {
+        position,
+        expected_goals_per_90: 0.0,
+        expected_assists_per_90: 0.0,
+        saves_per_90: 0.0,
+        minutes: starter,
+    };
+    let defender = outlook(Position::Defender);
+    let midfielder = outlook(Position::Midfielder);
+    assert!(defender.fixture_points(&easy) > midfielder.fixture_points(&easy));
+    assert!(defender.fixture_points(&hard) < midfielder.fixture_points(&hard));
+
+    // a double gameweek sums both fixtures and a blank projects nothing
+    let teams = [strong, weak, team(3, 1150, 1150)];
+    let teams: HashMap<i32, &Team> = teams.iter().map(|t| (t.id, t)).collect();
+    let fixtures = vec![
+        fixture(1, 1, 1, 2, (2, 4)),
+        fixture(2, 2, 1, 2, (2, 4)),
+        fixture(3, 2, 3, 1, (3, 3)),
+    ];
+    let single = defender.gameweek_points(1, &teams, &fixtures, 1);
+    let double = defender.gameweek_points(1, &teams, &fixtures, 2);
+    assert!(double > single && single > 0.0);
+    assert_eq!(defender.gameweek_points(3, &teams, &fixtures, 1), 0.0);
+}
+
+#[test]
+fn test_backtest_projects_each_gameweek_from_earlier_ones() {
+    let teams = vec![team(1, 1200, 1200), team(2, 1200, 1200)];
+    let fixtures: Vec<Fixture> = (1..=3)
+        .map(|gw| fixture(gw as u32, gw, 1, 2, (3, 3)))
+        .collect();
+    let roles = vec![
+        PlayerRole {
+            element: 10,
+            position: Position::Forward,
+            team: 1,
+        },
+        PlayerRole {
+            element: 20,
+            position: Position::Forward,
+            team: 2,
+        },
+    ];
+    let lines = vec![
+        line(1, 10, 90, 1.0, 6),
+        line(1, 20, 0, 0.0, 0),
+        line(2, 10, 90, 1.0, 6),
+        line(2, 20, 90, 0.0, 2),
+        line(3, 10, 90, 1.0, 6),
+        line(3, 20, 90, 0.0, 2),
+    ];
+
+    let report = backtest(&lines, &roles, &teams, &fixtures);
+    assert_eq!(report.gameweeks, 2);
+    // player 20 has not played before GW2, so only counts in GW3
+    assert_eq!(report.samples, 3);
+    assert!(report.correlation > 0.9);
+    assert!(report.mean_absolute_error < 2.0);
+
+    // one stored gameweek leaves nothing to project from
+    let report = backtest(&lines[..2], &roles, &teams, &fixtures);
+    assert_eq!(report.samples, 0);
+}
@@ -0,0 +1,74 @@
+use anyhow::Result;
+use fplbot::bot::commands;
+use fplbot::bot::registry::{CommandRegistry, SlashCommand};
+use fplbot::bot::response::{Progress, Reply};
+use serenity::all::{CommandInteraction, Context, CreateCommand};
+use serenity::async_trait;
+
+struct Dummy {
+    name: &'static str,
+    prefixes: &'static [&'static str],
+}
+
+#[async_trait]
+impl SlashCommand for Dummy {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn register(&self) -> CreateCommand {
+        CreateCommand::new(self.name).description("dummy")
+    }
+
+    async fn run(
+        &self,
+        _ctx: &Context,
+        _command: &CommandInteraction,
+        _progress: &Progress,
+    ) -> Result<Reply> {
+        unimplemented!()
+    }
+
+    fn component_prefixes(&self) -> &'static [&'static str] {
+        self.prefixes
+    }
+}
+
+fn dummy(name: &'static str, prefixes: &'static [&'static str]) -> Box<dyn SlashCommand> {
+    Box::new(Dummy { name, prefixes })
+}
+
+#[test]
+fn test_default_registry_is_valid() {
+    let registry = CommandRegistry::new(commands::all()).unwrap();
+
+    // Every definition registers under the name the registry dispatches on
+    let names: Vec<&str> = registry.names().collect();
+    for (definition, name) in registry.definitions().into_iter().zip(&names) {
+        let json = serde_json::to_value(definition).unwrap();
+        assert_eq!(json["name"], *name);
+    }
+
+    assert!(registry.get("standings").is_some());
+    assert!(registry.get("match").is_some());
+    assert!(registry.get("track_fixture").is_none());
+}
+
+#[test]
+fn test_components_route_to_owner() {
+    let registry = CommandRegistry::new(commands::all()).unwrap();
+
+    let owner = |id: &str| registry.component_owner(id).map(|c| c.name());
+    assert_eq!(owner("standings:1:next:l=314,p=0"), Some("standings"));
+    assert_eq!(owner("match:1:refresh:f=42"), Some("match"));
+    assert_eq!(owner("unknown_button"), None);
+    // unversioned IDs from before the custom ID encoding are left unclaimed
+    assert_eq!(owner("standings_next_0"), None);
+}
+
+#[test]
+fn test_duplicate_names_and_overlapping_prefixes_are_rejected() {
+    assert!(CommandRegistry::new(vec![dummy("a", &[]), dummy("a", &[])]).is_err());
+    assert!(CommandRegistry::new(vec![dummy("a", &["x_"]), dummy("b", &["x_y"])]).is_err());
+    assert!(CommandRegistry::new(vec![dummy("a", &["x_"]), dummy("b", &["y_"])]).is_ok());
+}
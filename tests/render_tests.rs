@@ -0,0 +1,51 @@
+use fplbot::utils::render::{contrasting_text, text_width, Canvas, Rect};
+
+fn decode(png: &[u8]) -> (u32, u32, Vec<u8>) {
+    let decoder = png::Decoder::new(png);
+    let mut reader = decoder.read_info().unwrap();
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels).unwrap();
+    pixels.truncate(info.buffer_size());
+    (info.width, info.height, pixels)
+}
+
+fn pixel(pixels: &[u8], width: u32, x: u32, y: u32) -> [u8; 3] {
+    let idx = ((y * width + x) * 3) as usize;
+    [pixels[idx], pixels[idx + 1], pixels[idx + 2]]
+}
+
+#[test]
+fn test_text_width_and_contrast() {
+    assert_eq!(text_width("", 2), 0);
+    // five pixels per glyph with one of spacing between them
+    assert_eq!(text_width("A", 1), 5);
+    assert_eq!(text_width("ARS", 2), 34);
+
+    assert_eq!(contrasting_text([255, 255, 255]), [0, 0, 0]);
+    assert_eq!(contrasting_text([0xff, 0xe0, 0x82]), [0, 0, 0]);
+    assert_eq!(contrasting_text([0x37, 0x00, 0x3c]), [255, 255, 255]);
+}
+
+#[test]
+fn test_canvas_fills_clipped_rects_and_encodes_png() {
+    let mut canvas = Canvas::new(4, 3, [10, 20, 30]);
+    // runs off the right and bottom edges, which are clipped
+    canvas.fill_rect(Rect::new(2, 1, 10, 10), [255, 0, 0]);
+    assert_eq!((canvas.width(), canvas.height()), (4, 3));
+
+    let (width, height, pixels) = decode(&canvas.encode_png().unwrap());
+    assert_eq!((width, height), (4, 3));
+    assert_eq!(pixel(&pixels, width, 0, 0), [10, 20, 30]);
+    assert_eq!(pixel(&pixels, width, 1, 2), [10, 20, 30]);
+    assert_eq!(pixel(&pixels, width, 2, 1), [255, 0, 0]);
+    assert_eq!(pixel(&pixels, width, 3, 2), [255, 0, 0]);
+
+    let mut text = Canvas::new(20, 20, [0, 0, 0]);
+    text.draw_text_centered(Rect::new(0, 0, 20, 20), "I", 1, [255, 255, 255]);
+    let (width, _, pixels) = decode(&text.encode_png().unwrap());
+    let lit = pixels.chunks(3).filter(|p| p == &[255, 255, 255]).count();
+    assert!(lit > 0);
+    // nothing is drawn outside the centred glyph box
+    assert_eq!(pixel(&pixels, width, 0, 0), [0, 0, 0]);
+    assert_eq!(pixel(&pixels, width, 19, 19), [0, 0, 0]);
+}
@@ -0,0 +1,35 @@
+use fplbot::bot::response::Reply;
+use serenity::all::{CreateButton, CreateEmbed};
+
+#[test]
+fn test_buttons_wrap_into_rows_of_five() {
+    let mut reply = Reply::new();
+    for i in 0..7 {
+        reply = reply.button(CreateButton::new(format!("b{}", i)).label("x"));
+    }
+
+    let json = serde_json::to_value(reply.into_message()).unwrap();
+    let rows = json["components"].as_array().unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["components"].as_array().unwrap().len(), 5);
+    assert_eq!(rows[1]["components"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_edit_clears_progress_text() {
+    let reply = Reply::new().embed(CreateEmbed::new().title("Done"));
+
+    let json = serde_json::to_value(reply.into_edit()).unwrap();
+    assert_eq!(json["content"], "");
+    assert_eq!(json["embeds"][0]["title"], "Done");
+}
+
+#[test]
+fn test_followup_keeps_ephemeral_flag() {
+    let reply = Reply::new().content("Only you can see this").ephemeral(true);
+    assert!(reply.is_ephemeral());
+
+    let json = serde_json::to_value(reply.into_followup()).unwrap();
+    assert_eq!(json["content"], "Only you can see this");
+    assert_eq!(json["flags"], 64);
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use fplbot::fpl::models::picks::EntryHistory;
+use fplbot::fpl::simulation::{simulate, Contender, DEFAULT_DEVIATION, MAX_DRAWS, MIN_DEVIATION};
+
+fn gameweek(event: i32, points: i32) -> EntryHistory {
+    EntryHistory {
+        event,
+        points,
+        total_points: 0,
+        rank: None,
+        overall_rank: None,
+        bank: 0,
+        value: 1000,
+        event_transfers: 0,
+        event_transfers_cost: 0,
+        points_on_bench: 0,
+    }
+}
+
+fn contender(entry: i32, total: i32, gameweeks: usize) -> Contender {
+    Contender {
+        entry,
+        total,
+        expected: vec![50.0; gameweeks],
+        deviation: 15.0,
+    }
+}
+
+#[test]
+fn test_contenders_blend_projection_into_season_average() {
+    let history = [gameweek(1, 40), gameweek(2, 60), gameweek(3, 50)];
+    let seeded = Contender::new(7, 150, &[80.0, 80.0, 80.0], &history);
+    // the next gameweek is all projection, later ones drift to the average of 50
+    assert_eq!(seeded.expected[0], 80.0);
+    assert!((seeded.expected[1] - (0.8 * 80.0 + 0.2 * 50.0)).abs() < 1e-9);
+    assert!(seeded.expected[2] < seeded.expected[1]);
+    // scores 40/60/50 spread by 10, while steadier managers get the floor
+    assert!((seeded.deviation - 10.0).abs() < 1e-9);
+    let steady = [gameweek(1, 50), gameweek(2, 51), gameweek(3, 50)];
+    assert_eq!(
+        Contender::new(7, 151, &[], &steady).deviation,
+        MIN_DEVIATION
+    );
+    // too few gameweeks to measure a spread
+    let deviation = Contender::new(7, 40, &[60.0], &history[..1]).deviation;
+    assert_eq!(deviation, DEFAULT_DEVIATION);
+}
+
+#[test]
+fn test_simulation_is_seeded_and_bounded() {
+    let league = [
+        contender(1, 1000, 10),
+        contender(2, 990, 10),
+        contender(3, 900, 10),
+        contender(4, 600, 10),
+    ];
+    let budget = Duration::from_secs(60);
+    let first = simulate(&league, 5000, 42, budget);
+    assert_eq!(first.runs, 5000);
+    assert_eq!(first, simulate(&league, 5000, 42, budget));
+    assert_ne!(first, simulate(&league, 5000, 43, budget));
+
+    let wins: f64 = first.odds.iter().map(|o| o.win).sum();
+    let top_threes: f64 = first.odds.iter().map(|o| o.top_three).sum();
+    assert!((wins - 1.0).abs() < 1e-9);
+    assert!((top_threes - 3.0).abs() < 1e-9);
+    // a close race at the top, and no way back for the manager 300 behind
+    assert!(first.odds[0].win > first.odds[1].win && first.odds[1].win > 0.3);
+    assert_eq!(first.odds[3].win, 0.0);
+    assert_eq!(first.odds[3].last, 1.0);
+
+    // with nothing left to play the standings are final
+    let finished = [contender(1, 1000, 0), contender(2, 1000, 0)];
+    let settled = simulate(&finished, 100, 1, budget);
+    assert_eq!((settled.odds[0].win, settled.odds[1].last), (1.0, 1.0));
+
+    // huge requests stop at the time budget, well short of the draw limit
+    let capped = simulate(&league, usize::MAX, 1, Duration::ZERO);
+    assert!(capped.runs > 0 && capped.runs < MAX_DRAWS / 40);
+    assert_eq!(capped.odds.len(), 4);
+}
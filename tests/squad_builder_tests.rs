@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use fplbot::fpl::squad::Position;
+use fplbot::fpl::squad_builder::{build_squad, BuiltSquad, SQUAD_QUOTAS, SQUAD_SIZE};
+use fplbot::fpl::transfers::{TransferPlayer, MAX_PER_CLUB};
+
+/// Ten players per position on distinct clubs, where higher IDs project more
+/// and cost more
+fn pool() -> Vec<TransferPlayer> {
+    let mut pool = Vec::new();
+    for (p, position) in Position::ALL.into_iter().enumerate() {
+        for i in 0..10 {
+            let element = (p as i32 + 1) * 100 + i;
+            pool.push(TransferPlayer {
+                element,
+                position,
+                team: element,
+                price: 40 + i * 5,
+                points: vec![f64::from(i) + 1.0],
+            });
+        }
+    }
+    pool
+}
+
+fn assert_legal(squad: &BuiltSquad, budget: i32) {
+    assert_eq!(squad.players.len(), SQUAD_SIZE);
+    for (position, quota) in SQUAD_QUOTAS {
+        let count = squad
+            .players
+            .iter()
+            .filter(|p| p.position == position)
+            .count();
+        assert_eq!(count, quota);
+    }
+    let mut clubs: HashMap<i32, usize> = HashMap::new();
+    for player in &squad.players {
+        *clubs.entry(player.team).or_default() += 1;
+    }
+    assert!(clubs.values().all(|n| *n <= MAX_PER_CLUB));
+    assert!(squad.cost <= budget);
+    assert_eq!(
+        squad.cost,
+        squad.players.iter().map(|p| p.price).sum::<i32>()
+    );
+}
+
+#[test]
+fn test_builder_picks_the_best_squad_within_budget_and_club_limits() {
+    // with money to spare the top players in each position are picked
+    let squad = build_squad(&pool(), 2000, &[], &[]).unwrap();
+    assert_legal(&squad, 2000);
+    let mut picked: Vec<i32> = squad.players.iter().map(|p| p.element).collect();
+    picked.sort();
+    let mut expected: Vec<i32> = vec![108, 109];
+    expected.extend(205..=209);
+    expected.extend(305..=309);
+    expected.extend(407..=409);
+    assert_eq!(picked, expected);
+    assert_eq!(squad.captain, 409);
+    assert_eq!(squad.lineup.starters.len(), 11);
+
+    // four top midfielders from one club, only three may be picked
+    let mut crowded = pool();
+    for player in crowded
+        .iter_mut()
+        .filter(|p| p.element >= 306 && p.element < 310)
+    {
+        player.team = 1;
+    }
+    let squad = build_squad(&crowded, 2000, &[], &[]).unwrap();
+    assert_legal(&squad, 2000);
+    assert!(squad.players.iter().any(|p| p.element == 305));
+    assert!(squad.players.iter().any(|p| p.element == 304));
+
+    // a tight budget still gives a legal squad that beats the cheapest one
+    let squad = build_squad(&pool(), 800, &[], &[]).unwrap();
+    assert_legal(&squad, 800);
+    assert!(squad.points > 15.0);
+}
+
+#[test]
+fn test_builder_keeps_locks_and_leaves_out_excludes() {
+    let squad = build_squad(&pool(), 2000, &[100, 300], &[409, 309]).unwrap();
+    assert_legal(&squad, 2000);
+    let picked: Vec<i32> = squad.players.iter().map(|p| p.element).collect();
+    assert!(picked.contains(&100) && picked.contains(&300));
+    assert!(!picked.contains(&409) && !picked.contains(&309));
+    assert_eq!(squad.captain, 209);
+
+    // three locked goalkeepers, a locked exclusion and an impossible budget
+    assert!(build_squad(&pool(), 2000, &[100, 101, 102], &[]).is_err());
+    assert!(build_squad(&pool(), 2000, &[100], &[100]).is_err());
+    assert!(build_squad(&pool(), 500, &[], &[]).is_err());
+}
@@ -0,0 +1,69 @@
+use fplbot::fpl::squad::{best_xi, captained_score, is_valid_xi, Candidate, Position};
+
+fn player(id: i32, position: Position, score: f64) -> Candidate {
+    Candidate {
+        id,
+        position,
+        score,
+    }
+}
+
+fn pool() -> Vec<Candidate> {
+    vec![
+        player(1, Position::Goalkeeper, 6.0),
+        player(2, Position::Goalkeeper, 9.0),
+        player(10, Position::Defender, 2.0),
+        player(11, Position::Defender, 1.0),
+        player(12, Position::Defender, 1.0),
+        player(13, Position::Defender, 8.0),
+        player(14, Position::Defender, 0.0),
+        player(20, Position::Midfielder, 12.0),
+        player(21, Position::Midfielder, 10.0),
+        player(22, Position::Midfielder, 7.0),
+        player(23, Position::Midfielder, 3.0),
+        player(30, Position::Forward, 15.0),
+        player(31, Position::Forward, 13.0),
+        player(32, Position::Forward, 11.0),
+        player(33, Position::Forward, 9.5),
+    ]
+}
+
+#[test]
+fn test_best_xi_respects_formation_limits() {
+    let lineup = best_xi(&pool()).unwrap();
+
+    assert!(is_valid_xi(&lineup.starters));
+    // Best goalkeeper starts, the other leads the bench
+    assert_eq!(lineup.starters[0].id, 2);
+    assert_eq!(lineup.bench[0].id, 1);
+    // Only three forwards may start even though the fourth outscores defenders
+    assert_eq!(lineup.formation(), "3-4-3");
+    assert!(lineup.bench.iter().any(|c| c.id == 33));
+}
+
+#[test]
+fn test_best_xi_maximises_total() {
+    let lineup = best_xi(&pool()).unwrap();
+    // GK 9, DEF 8+2+1, MID 12+10+7+3, FWD 15+13+11
+    assert_eq!(lineup.total_score(), 91.0);
+}
+
+#[test]
+fn test_best_xi_needs_a_goalkeeper() {
+    let outfield: Vec<Candidate> = pool()
+        .into_iter()
+        .filter(|c| c.position != Position::Goalkeeper)
+        .collect();
+    assert!(best_xi(&outfield).is_none());
+}
+
+#[test]
+fn test_captains_and_captained_score() {
+    let lineup = best_xi(&pool()).unwrap();
+    // the two highest scoring starters wear the armbands
+    assert_eq!(lineup.captains(), (Some(30), Some(31)));
+    assert_eq!(captained_score(&lineup.starters, Some(30)), 91.0 + 15.0);
+    // a captain outside the XI adds nothing
+    assert_eq!(captained_score(&lineup.starters, Some(33)), 91.0);
+    assert_eq!(captained_score(&lineup.starters, None), 91.0);
+}
@@ -0,0 +1,108 @@
+use fplbot::bot::commands::standings::{gap_rows, gap_window, PAGE_SIZE};
+use fplbot::fpl::models::history::ManagerHistory;
+use fplbot::fpl::models::league::StandingsManager;
+use fplbot::fpl::standings::{api_pages_for, page_count, rank_by_metric, StandingsView};
+use serde_json::json;
+
+#[test]
+fn test_display_pages_map_onto_api_pages() {
+    // 25-entry pages split each 50-entry API page in two
+    assert_eq!(api_pages_for(0, 25), 1..=1);
+    assert_eq!(api_pages_for(25, 25), 1..=1);
+    assert_eq!(api_pages_for(50, 25), 2..=2);
+    assert_eq!(api_pages_for(975, 25), 20..=20);
+    // ranges crossing an API page boundary need both pages
+    assert_eq!(api_pages_for(40, 20), 1..=2);
+    assert_eq!(api_pages_for(140, 0), 3..=3);
+}
+
+#[test]
+fn test_page_count_covers_every_entry() {
+    assert_eq!(page_count(0, 25), 1);
+    assert_eq!(page_count(25, 25), 1);
+    assert_eq!(page_count(26, 25), 2);
+    assert_eq!(page_count(1234, 25), 50);
+}
+
+#[test]
+fn test_rank_by_metric_shares_ranks_and_puts_missing_last() {
+    let ranked = rank_by_metric(vec![
+        ("a", Some(40)),
+        ("b", None),
+        ("c", Some(55)),
+        ("d", Some(40)),
+        ("e", Some(12)),
+    ]);
+    let order: Vec<_> = ranked
+        .iter()
+        .map(|(rank, name, _)| (*rank, *name))
+        .collect();
+    assert_eq!(
+        order,
+        vec![(1, "c"), (2, "a"), (2, "d"), (4, "e"), (5, "b")]
+    );
+
+    for view in StandingsView::ALL {
+        assert_eq!(view.as_str().parse::<StandingsView>().unwrap(), view);
+    }
+    assert!("points".parse::<StandingsView>().is_err());
+}
+
+#[test]
+fn test_form_counts_recent_gameweeks_after_hits() {
+    let gameweek = |event: i32, points: i32, cost: i32| {
+        json!({
+            "event": event, "points": points, "total_points": 0, "rank": null,
+            "overall_rank": null, "bank": 5, "value": 1003 + event,
+            "event_transfers": 0, "event_transfers_cost": cost, "points_on_bench": 0
+        })
+    };
+    let history: ManagerHistory = serde_json::from_value(json!({
+        "current": [gameweek(1, 90, 0), gameweek(2, 50, 4), gameweek(3, 60, 0), gameweek(4, 70, 8)]
+    }))
+    .unwrap();
+
+    assert_eq!(history.form(2), 60 + 70 - 8);
+    assert_eq!(history.form(10), 90 + 46 + 60 + 62);
+    assert_eq!(history.latest().map(|gw| gw.value), Some(1007));
+}
+
+#[test]
+fn test_gap_pages_hold_one_page_of_distinct_rows() {
+    let league: Vec<StandingsManager> = (1..=60)
+        .map(|rank| {
+            serde_json::from_value(json!({
+                "id": rank,
+                "event_total": 50,
+                "player_name": format!("Manager {}", rank),
+                "rank": rank,
+                "last_rank": rank,
+                "rank_sort": rank,
+                "total": 1000 - rank * 3,
+                "entry": 100 + rank,
+                "entry_name": format!("Team {}", rank),
+                "has_played": true
+            }))
+            .unwrap()
+        })
+        .collect();
+
+    let mut shown = Vec::new();
+    for page in 0..2 {
+        let start = page * PAGE_SIZE;
+        let (first, count) = gap_window(start);
+        let rows = gap_rows(&league[first..first + count], start > 0, 997);
+        assert_eq!(rows.len(), PAGE_SIZE);
+        assert_eq!(rows[0].manager_id, league[start].manager_id);
+        // the first row's gap to the place above is known on later pages
+        let above_gap = if start > 0 { "-3" } else { "-" };
+        assert_eq!(rows[0].right[2], above_gap);
+        shown.extend(rows.iter().map(|row| row.manager_id));
+    }
+
+    let expected: Vec<i32> = league[..2 * PAGE_SIZE]
+        .iter()
+        .map(|entry| entry.manager_id)
+        .collect();
+    assert_eq!(shown, expected);
+}
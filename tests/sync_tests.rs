@@ -0,0 +1,75 @@
+use fplbot::bot::sync::{diff_commands, RegistrationMode};
+use serde_json::{json, Value};
+use serenity::all::{CommandOptionType, CreateCommand, CreateCommandOption, GuildId};
+
+fn local(command: CreateCommand) -> Value {
+    serde_json::to_value(command).unwrap()
+}
+
+fn fixtures_definition() -> CreateCommand {
+    CreateCommand::new("fixtures")
+        .description("Get a given weeks fixtures")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "gameweek",
+                "The gameweek number",
+            )
+            .required(false),
+        )
+}
+
+/// The same command as Discord returns it once registered
+fn fixtures_registered() -> Value {
+    json!({
+        "id": "1",
+        "application_id": "2",
+        "guild_id": "3",
+        "version": "4",
+        "type": 1,
+        "name": "fixtures",
+        "description": "Get a given weeks fixtures",
+        "default_member_permissions": null,
+        "dm_permission": true,
+        "nsfw": false,
+        "options": [
+            { "type": 4, "name": "gameweek", "description": "The gameweek number" }
+        ]
+    })
+}
+
+#[test]
+fn test_identical_commands_are_up_to_date() {
+    let diff = diff_commands(&[local(fixtures_definition())], &[fixtures_registered()]);
+    assert!(diff.is_empty(), "{:?}", diff);
+}
+
+#[test]
+fn test_changes_additions_and_removals_are_detected() {
+    let changed = fixtures_definition().description("Fixtures for a gameweek");
+    let added = CreateCommand::new("fdr").description("Fixture difficulty");
+    let mut stale = fixtures_registered();
+    stale["name"] = json!("old_command");
+
+    let diff = diff_commands(
+        &[local(changed), local(added)],
+        &[fixtures_registered(), stale],
+    );
+    assert_eq!(diff.added, vec!["fdr"]);
+    assert_eq!(diff.changed, vec!["fixtures"]);
+    assert_eq!(diff.removed, vec!["old_command"]);
+}
+
+#[test]
+fn test_registration_mode_parsing() {
+    assert_eq!(
+        RegistrationMode::parse("global", "1").unwrap(),
+        RegistrationMode::Global
+    );
+    assert_eq!(
+        RegistrationMode::parse("Guilds", "10, 20").unwrap(),
+        RegistrationMode::Guilds(vec![GuildId::new(10), GuildId::new(20)])
+    );
+    assert!(RegistrationMode::parse("guilds", "abc").is_err());
+    assert!(RegistrationMode::parse("everywhere", "").is_err());
+}
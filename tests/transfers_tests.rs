@@ -0,0 +1,164 @@
+use chrono::{Duration, Utc};
+use fplbot::fpl::models::history::ChipPlay;
+use fplbot::fpl::models::picks::EntryHistory;
+use fplbot::fpl::models::transfers::ManagerTransfer;
+use fplbot::fpl::squad::Position;
+use fplbot::fpl::transfers::{
+    apply_pending_transfers, free_transfers, selling_price, suggest, Transfer, TransferPlayer,
+    HIT_POINTS,
+};
+
+fn gameweek(event: i32, event_transfers: i32) -> EntryHistory {
+    EntryHistory {
+        event,
+        points: 50,
+        total_points: 50 * event,
+        rank: None,
+        overall_rank: None,
+        bank: 0,
+        value: 1000,
+        event_transfers,
+        event_transfers_cost: 0,
+        points_on_bench: 0,
+    }
+}
+
+fn player(element: i32, position: Position, team: i32, price: i32, points: f64) -> TransferPlayer {
+    TransferPlayer {
+        element,
+        position,
+        team,
+        price,
+        points: vec![points],
+    }
+}
+
+#[test]
+fn test_selling_prices_and_free_transfers_follow_fpl_rules() {
+    // half of a rise is kept, rounded down, and falls are passed on in full
+    assert_eq!(selling_price(50, 53), 51);
+    assert_eq!(selling_price(50, 54), 52);
+    assert_eq!(selling_price(50, 48), 48);
+
+    let chips = vec![ChipPlay {
+        name: "wildcard".to_string(),
+        time: Utc::now(),
+        event: 4,
+    }];
+    // one free transfer per gameweek after the first, banked up to five
+    let history: Vec<EntryHistory> = (1..=3).map(|gw| gameweek(gw, 0)).collect();
+    assert_eq!(free_transfers(&history, &[], 4, 0), 3);
+    let history: Vec<EntryHistory> = (1..=8).map(|gw| gameweek(gw, 0)).collect();
+    assert_eq!(free_transfers(&history, &[], 9, 0), 5);
+    // GW1 and wildcard transfers are free, a hit empties the bank
+    let history = vec![
+        gameweek(1, 15),
+        gameweek(2, 0),
+        gameweek(3, 3),
+        gameweek(4, 9),
+    ];
+    assert_eq!(free_transfers(&history, &[], 5, 0), 1);
+    assert_eq!(free_transfers(&history, &chips, 5, 0), 2);
+    let history = vec![
+        gameweek(1, 0),
+        gameweek(2, 0),
+        gameweek(3, 0),
+        gameweek(4, 9),
+    ];
+    assert_eq!(free_transfers(&history, &chips, 5, 0), 4);
+}
+
+#[test]
+fn test_transfers_made_for_the_next_deadline_use_up_free_ones() {
+    let history: Vec<EntryHistory> = (1..=3).map(|gw| gameweek(gw, 0)).collect();
+    assert_eq!(free_transfers(&history, &[], 4, 0), 3);
+    assert_eq!(free_transfers(&history, &[], 4, 2), 1);
+    assert_eq!(free_transfers(&history, &[], 4, 5), 0);
+
+    // a wildcard already played for the next gameweek makes them free
+    let wildcard = vec![ChipPlay {
+        name: "wildcard".to_string(),
+        time: Utc::now(),
+        event: 4,
+    }];
+    assert_eq!(free_transfers(&history, &wildcard, 4, 8), 3);
+}
+
+#[test]
+fn test_pending_transfers_update_squad_and_bank() {
+    let transfer =
+        |out: i32, out_cost: i32, into: i32, in_cost: i32, event: i32, age: i64| ManagerTransfer {
+            element_in: into,
+            element_in_cost: in_cost,
+            element_out: out,
+            element_out_cost: out_cost,
+            entry: 1,
+            event,
+            time: Utc::now() - Duration::minutes(age),
+        };
+    let transfers = vec![
+        // newest first, as FPL lists them: 20 was bought and sold again
+        transfer(20, 62, 30, 55, 4, 5),
+        transfer(2, 60, 20, 62, 4, 10),
+        // already in the picks
+        transfer(9, 45, 1, 50, 3, 600),
+    ];
+
+    let mut squad = vec![1, 2, 3];
+    let bank = apply_pending_transfers(&mut squad, 10, &transfers, 4);
+    assert_eq!(squad, vec![1, 30, 3]);
+    assert_eq!(bank, 10 + 60 - 62 + 62 - 55);
+}
+
+#[test]
+fn test_suggestions_respect_budget_and_club_limits() {
+    let squad: Vec<TransferPlayer> = (1..=15)
+        .map(|element| {
+            let position = match element {
+                1..=2 => Position::Goalkeeper,
+                3..=7 => Position::Defender,
+                8..=12 => Position::Midfielder,
+                _ => Position::Forward,
+            };
+            // three defenders share a club
+            let team = if (3..=5).contains(&element) {
+                1
+            } else {
+                element + 10
+            };
+            player(element, position, team, 50, 1.0)
+        })
+        .collect();
+    let pool = vec![
+        // too expensive to buy without a downgrade elsewhere
+        player(101, Position::Forward, 30, 100, 10.0),
+        // only fits by selling one of the club's three defenders
+        player(102, Position::Defender, 1, 50, 8.0),
+        player(103, Position::Midfielder, 40, 10, 2.0),
+    ];
+
+    let plans = suggest(&squad, &pool, 10, 1, 2, 3);
+    // eleven starters at a point each, plus the captain
+    assert_eq!(plans[0].points, 12.0);
+    assert!(plans[0].transfers.is_empty());
+
+    let singles: Vec<_> = plans.iter().filter(|p| p.transfers.len() == 1).collect();
+    assert_eq!(
+        singles[0].transfers,
+        vec![Transfer {
+            element_out: 3,
+            element_in: 102
+        }]
+    );
+    assert!(singles
+        .iter()
+        .all(|p| p.transfers[0].element_in != 101 && p.hit == 0));
+
+    let double = plans.iter().find(|p| p.transfers.len() == 2).unwrap();
+    let mut bought: Vec<i32> = double.transfers.iter().map(|t| t.element_in).collect();
+    bought.sort();
+    assert_eq!(bought, vec![101, 103]);
+    assert_eq!(double.hit, HIT_POINTS);
+    assert_eq!(double.bank, 0);
+    assert_eq!(double.net_points(), 31.0 - 4.0);
+}